@@ -1,25 +1,57 @@
 use super::{
-    utils::{generate_fr, SALT},
-    Error, SecretKey,
+    utils::{decode_hex, generate_fr, strip_format_version, try_generate_fr, FORMAT_VERSION, SALT},
+    AccumulatorError, Deletion, MembershipWitness, SecretKey,
 };
 use crate::window_mul;
-use bls12_381_plus::{G1Affine, G1Projective, Scalar};
+use bls12_381_plus::{elliptic_curve::hash2curve::ExpandMsgXof, G1Affine, G1Projective, Scalar};
+use alloc::{format, string::String, vec::Vec};
 use core::{
+    cmp::Ordering,
     convert::TryFrom,
     fmt::{self, Formatter},
+    hash::{Hash, Hasher},
+    str::FromStr,
 };
 use group::GroupEncoding;
 use rand_core::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
-use std::hash::{Hash, Hasher};
+use digest::{ExtendableOutput, Update, XofReader};
+use sha3::Shake256;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::Zeroize;
 
 /// An element in the accumulator
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+/// 
+/// NOTE: `Element` is `Copy`, hence it cannot be wiped on drop: holders should call `zeroize` on the copies of their secret element.
+/// Equality, both `==` and `ct_eq`, compares the underlying scalars in constant time (see `MembershipWitness` for the other operations).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Zeroize)]
 pub struct Element(pub Scalar);
 
+/// Hashing and ordering follow the canonical encoding `canonical_bytes`, hence they are consistent with equality 
+/// and elements can be used as keys of hash and ordered sets, e.g., to deduplicate deletions.
 impl Hash for Element {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0.to_be_bytes().hash(state)
+        self.canonical_bytes().hash(state)
+    }
+}
+
+/// Elements are ordered lexicographically by their canonical little-endian encoding `canonical_bytes`,
+/// which is a total order consistent with equality, but not the order of the scalar values.
+impl Ord for Element {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.canonical_bytes().cmp(&other.canonical_bytes())
+    }
+}
+
+impl PartialOrd for Element {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl ConstantTimeEq for Element {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
     }
 }
 
@@ -31,45 +63,113 @@ impl fmt::Display for Element {
 
 
 impl TryFrom<&[u8; 32]> for Element {
-    type Error = Error;
+    type Error = AccumulatorError;
 
     fn try_from(value: &[u8; 32]) -> Result<Self, Self::Error> {
         let s = Scalar::from_be_bytes(value);
         if s.is_some().unwrap_u8() == 1u8 {
             Ok(Self(s.unwrap()))
         } else {
-            Err(Error {
-                message: String::from("incorrect byte sequence"),
-                code: 1,
-            })
+            Err(AccumulatorError::BadEncoding)
         }
     }
 }
 
 impl Element {
     pub const BYTES: usize = 32;
+
+    /// Version of the derivation of `Element::hash_with_domain`, which is part of the hashed input, 
+    /// so that a future derivation yields distinct elements instead of silently colliding with the current one.
+    pub const DOMAIN_HASH_VERSION: u8 = 1;
     
     /// Return the multiplicative identity element
     pub fn one() -> Self {
         Self(Scalar::ONE)
     }
 
-    /// Return the byte representation
+    /// Return the byte representation, the canonical big-endian encoding of the scalar fully reduced modulo r
+    /// (see `canonical_bytes` for the little-endian encoding)
     pub fn to_bytes(&self) -> [u8; Self::BYTES] {
         self.0.to_be_bytes()
     }
 
-    /// Construct an element by hashing the specified bytes
+    /// Reconstructs an element from the bytes returned by `to_bytes`, e.g., when received in a `Deletion` over the wire.
+    /// 
+    /// Returns `AccumulatorError::BadEncoding` if the input is not 32 bytes long or is a non-canonical encoding,
+    /// i.e., a value greater than or equal to the field modulus.
+    pub fn try_from_bytes(input: &[u8]) -> Result<Self, AccumulatorError> {
+        let input = <&[u8; Self::BYTES]>::try_from(input)
+            .map_err(|_| AccumulatorError::BadEncoding)?;
+        Self::try_from(input)
+    }
+
+    /// Returns the canonical little-endian encoding of the scalar, for interoperability with encoders
+    /// (e.g., `ff::PrimeField::to_repr` or other languages) that use the little-endian convention.
+    /// 
+    /// The encoding is the 32-byte little-endian representation of the scalar fully reduced modulo r,
+    /// the order of the BLS12-381 scalar field, i.e., the byte-reversal of `to_bytes`.
+    pub fn canonical_bytes(&self) -> [u8; Self::BYTES] {
+        self.0.to_le_bytes()
+    }
+
+    /// Reconstructs an element from the bytes returned by `canonical_bytes`.
+    /// 
+    /// Returns `AccumulatorError::BadEncoding` if the input is a non-canonical encoding,
+    /// i.e., a value greater than or equal to the field modulus.
+    pub fn from_canonical_bytes(input: &[u8; Self::BYTES]) -> Result<Self, AccumulatorError> {
+        Option::from(Scalar::from_le_bytes(input))
+            .map(Self)
+            .ok_or(AccumulatorError::BadEncoding)
+    }
+
+    /// Returns a short identifier of this element, the first 8 bytes of the SHAKE256 hash of its canonical encoding,
+    /// e.g., to use as a database index or shard key.
+    /// 
+    /// NOTE: collisions can be found with about 2^32 elements, hence the identifier is not collision-resistant 
+    /// against adversarial elements and must not replace the comparison of the full elements.
+    pub fn short_id(&self) -> [u8; 8] {
+        let mut hasher = Shake256::default();
+        hasher.update(b"ELEMENT_SHORT_ID");
+        hasher.update(&self.to_bytes());
+        let mut id = [0u8; 8];
+        hasher.finalize_xof().read(&mut id);
+        id
+    }
+
+    /// Construct an element by hashing the specified bytes with the default backend `DefaultHashToScalar`,
+    /// i.e., the 64-byte SHAKE256 output of `SALT || d` reduced modulo r, without domain separation.
+    /// 
+    /// NOTE: this derivation is kept unchanged so that existing elements (and their accumulators and witnesses) remain valid,
+    /// new credential types should prefer `hash_with_domain`.
     pub fn hash(d: &[u8]) -> Self {
-        Self(generate_fr(SALT, Some(d), rand_core::OsRng {}))
+        Self::hash_with::<DefaultHashToScalar>(d)
+    }
+
+    /// Construct an element by hashing the message `msg` with the hash-to-scalar backend `H`,
+    /// e.g., to derive the same elements as an existing credential system.
+    pub fn hash_with<H: HashToScalar>(msg: &[u8]) -> Self {
+        Self(H::hash_to_scalar(msg))
+    }
+
+    /// Construct an element by hashing the message `msg` under the caller-supplied `domain`,
+    /// e.g., to namespace the pseudonyms of different credential types.
+    /// 
+    /// The element is the 64-byte SHAKE256 output of `SALT || DOMAIN_HASH_VERSION || u64_be(len(domain)) || domain || msg` reduced modulo r,
+    /// hence it is a uniform scalar, and the length prefix makes the encoding of distinct (domain, message) pairs distinct.
+    /// The elements differ from those of `hash`, even for an empty domain.
+    pub fn hash_with_domain(domain: &[u8], msg: &[u8]) -> Self {
+        let mut hasher = ElementHasher::with_domain(domain);
+        hasher.update(msg);
+        hasher.finalize()
     }
 
     
     /// Compute an element from a Merlin Transcript
+    #[cfg(feature = "proofs")]
     pub fn from_transcript(label: &'static [u8], transcript: &mut merlin::Transcript) -> Self {
         let mut okm = [0u8; 64];
         transcript.challenge_bytes(label, &mut okm);
-        Self::hash(&okm)
+        Self(generate_fr(SALT, Some(&okm), rand_core::OsRng {}))
     }
 
 
@@ -79,8 +179,88 @@ impl Element {
     }
 }
 
+#[cfg(any(test, feature = "testing"))]
+impl Element {
+    /// Returns `n` distinct elements, the hashes (see `Element::hash`) of the little-endian indices 0, ..., n-1, 
+    /// so that test and benchmark fixtures are reproducible.
+    /// 
+    /// Only available with the `testing` feature.
+    pub fn sample_n(n: usize) -> Vec<Element> {
+        (0..n as u64).map(|i| Self::hash(&i.to_le_bytes())).collect()
+    }
+}
+
+/// Hash-to-scalar construction deriving elements from messages, see `Element::hash_with`.
+/// 
+/// Implementations must be deterministic and map messages to (close to) uniform scalars, 
+/// e.g., a hash-to-field suite of RFC 9380 matching another implementation.
+pub trait HashToScalar {
+    /// Hashes the message `msg` to a scalar.
+    fn hash_to_scalar(msg: &[u8]) -> Scalar;
+}
+
+/// Default backend of `Element::hash`: the 64-byte SHAKE256 output of `SALT || msg` reduced modulo r, as in `generate_fr`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultHashToScalar;
+
+impl HashToScalar for DefaultHashToScalar {
+    fn hash_to_scalar(msg: &[u8]) -> Scalar {
+        generate_fr(SALT, Some(msg), rand_core::OsRng {})
+    }
+}
+
+/// Incremental version of `Element::hash` and `Element::hash_with_domain`, to derive an element from a large input streamed in pieces
+/// instead of buffered in a single slice.
+/// 
+/// The element only depends on the concatenation of the pieces passed to `update`, 
+/// hence it is the same as the one-shot hash on the whole input.
+#[derive(Clone, Debug)]
+pub struct ElementHasher(Shake256);
+
+impl Default for ElementHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ElementHasher {
+    /// Starts hashing a message without domain separation, as in `Element::hash`.
+    pub fn new() -> Self {
+        let mut hasher = Shake256::default();
+        hasher.update(SALT);
+        Self(hasher)
+    }
+
+    /// Starts hashing a message under the caller-supplied `domain`, as in `Element::hash_with_domain`.
+    pub fn with_domain(domain: &[u8]) -> Self {
+        let mut hasher = Shake256::default();
+        hasher.update(SALT);
+        hasher.update(&[Element::DOMAIN_HASH_VERSION]);
+        hasher.update(&(domain.len() as u64).to_be_bytes());
+        hasher.update(domain);
+        Self(hasher)
+    }
+
+    /// Appends `data` to the message.
+    pub fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
 
-/// A coefficent for updating witnesses
+    /// Returns the element derived from the whole message.
+    pub fn finalize(self) -> Element {
+        let mut okm = [0u8; 64];
+        self.0.finalize_xof().read(&mut okm);
+        Element(Scalar::from_bytes_wide(&okm))
+    }
+}
+
+/// A coefficent for updating witnesses, i.e., a point \omega_i*V of the batch update polynomial \omega(x) = ∑ \omega_i*V x^i.
+/// 
+/// Coefficients are public and can be distributed by any channel (e.g., a CDN) together with the ordered deletions.
+/// They are computed with the trapdoor by `Accumulator::update_assign`, or by scaling the scalars of 
+/// `SecretKey::create_coefficients` by the accumulator value BEFORE the deletions.
+/// NOTE: coefficients are only valid together with the deletions they were computed for, 
+/// and for witnesses of the accumulator value they were scaled by.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Coefficient(pub G1Projective);
 
@@ -102,24 +282,27 @@ impl From<G1Projective> for Coefficient {
     }
 }
 
+impl Hash for Coefficient {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_bytes().hash(state)
+    }
+}
+
 impl TryFrom<&[u8; 48]> for Coefficient {
-    type Error = Error;
+    type Error = AccumulatorError;
 
     fn try_from(value: &[u8; 48]) -> Result<Self, Self::Error> {
         let pt = G1Affine::from_compressed(value).map(G1Projective::from);
         if pt.is_some().unwrap_u8() == 1 {
             Ok(Self(pt.unwrap()))
         } else {
-            Err(Error {
-                message: String::from("incorrect byte sequence"),
-                code: 1,
-            })
+            Err(AccumulatorError::BadEncoding)
         }
     }
 }
 
 impl Coefficient {
-    const BYTES: usize = 48;
+    pub const BYTES: usize = 48;
 
     /// The byte representation of this coefficient
     pub fn to_bytes(&self) -> [u8; Self::BYTES] {
@@ -127,13 +310,110 @@ impl Coefficient {
         d.copy_from_slice(self.0.to_bytes().as_ref());
         d
     }
+
+    /// Checks that this coefficient is a point of the prime-order subgroup of G1.
+    /// 
+    /// Coefficients decoded with `try_from` or deserialized are already checked, 
+    /// but coefficients built from an arbitrary `G1Projective` are not: a point outside the subgroup 
+    /// would let the sender of an update manipulate the updated witnesses.
+    /// Returns `AccumulatorError::InvalidCoefficient` otherwise.
+    pub fn validate(&self) -> Result<(), AccumulatorError> {
+        let p = G1Affine::from(self.0);
+        if !bool::from(p.is_on_curve() & p.is_torsion_free()) {
+            return Err(AccumulatorError::InvalidCoefficient);
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over the update coefficients returned by `Accumulator::update_assign_streaming`,
+/// which computes each coefficient c_i*V only when it is yielded.
+/// 
+/// NOTE: it holds the secret scalars c_i (see `SecretKey::create_coefficients`), which are zeroized on drop.
+pub struct CoefficientStream {
+    v: G1Projective,
+    scalars: Vec<Element>,
+    next: usize,
+}
+
+impl Iterator for CoefficientStream {
+    type Item = Coefficient;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.scalars.get(self.next)?;
+        self.next += 1;
+        Some(Coefficient(self.v * c.0))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.scalars.len() - self.next;
+        (n, Some(n))
+    }
 }
 
+impl ExactSizeIterator for CoefficientStream {}
+
+impl Drop for CoefficientStream {
+    fn drop(&mut self) {
+        self.scalars.zeroize();
+    }
+}
+
+/// Summary of the difference between two accumulator values, returned by `Accumulator::diff` for debugging,
+/// e.g., to compare the accumulator targeted by a holder's update with the issuer's current one.
+/// 
+/// Accumulator values carry no ordering, hence the update epochs of both values are only reported 
+/// when given by the caller (see `Accumulator::diff_epochs` and `Issuer::diff_accumulator`).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct AccumulatorDiff {
+    pub changed: bool,
+    pub from_id: Scalar,
+    pub to_id: Scalar,
+    /// The update epochs of the compared values, if known.
+    pub epochs: Option<(u64, u64)>,
+}
+
+impl AccumulatorDiff {
+    /// Returns how many update epochs apart the compared values are, if their epochs are known.
+    pub fn epochs_apart(&self) -> Option<u64> {
+        self.epochs.map(|(from, to)| from.abs_diff(to))
+    }
+}
+
+impl fmt::Display for AccumulatorDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.changed {
+            write!(f, "accumulator changed: id {:x} -> {:x}", self.from_id, self.to_id)?;
+        } else {
+            write!(f, "accumulator unchanged: id {:x}", self.from_id)?;
+        }
+        match self.epochs {
+            Some((from, to)) => write!(f, ", epoch {} -> {} ({} apart)", from, to, from.abs_diff(to)),
+            None => Ok(()),
+        }
+    }
+}
 
 /// Represents a Positive Bilinear Accumulator.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(not(feature = "serde_human"), derive(Serialize, Deserialize))]
 pub struct Accumulator(pub G1Projective);
 
+/// With `serde_human`, human-readable formats encode the accumulator as the hex string of `to_bytes`.
+#[cfg(feature = "serde_human")]
+impl Serialize for Accumulator {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_human(&self.to_bytes(), &self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde_human")]
+impl<'de> Deserialize<'de> for Accumulator {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_human::<_, _, _, { Self::BYTES }>(deserializer, |bytes| Self::try_from(bytes), Self)
+    }
+}
+
 impl fmt::Display for Accumulator {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "Accumulator {{ {} }}", self.0)
@@ -152,18 +432,28 @@ impl From<G1Projective> for Accumulator {
     }
 }
 
+impl FromStr for Accumulator {
+    type Err = AccumulatorError;
+
+    /// Same as `Accumulator::try_from_hex`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_hex(s)
+    }
+}
+
 impl TryFrom<&[u8; 48]> for Accumulator {
-    type Error = Error;
+    type Error = AccumulatorError;
 
     fn try_from(value: &[u8; 48]) -> Result<Self, Self::Error> {
         let pt = G1Affine::from_compressed(value).map(G1Projective::from);
         if pt.is_some().unwrap_u8() == 1 {
-            Ok(Self(pt.unwrap()))
+            let acc = Self(pt.unwrap());
+            if acc.is_degenerate() {
+                return Err(AccumulatorError::DegenerateAccumulator);
+            }
+            Ok(acc)
         } else {
-            Err(Error {
-                message: String::from("incorrect byte sequence"),
-                code: 1,
-            })
+            Err(AccumulatorError::BadEncoding)
         }
     }
 }
@@ -176,14 +466,61 @@ impl Default for Accumulator {
 
 impl Accumulator {
     pub const BYTES: usize = 48;
+    /// Length in bytes of the versioned encoding returned by `to_versioned_bytes`.
+    pub const VERSIONED_BYTES: usize = Self::BYTES + 1;
 
     /// Creates a new random accumulator 
     /// as in https://ieeexplore.ieee.org/abstract/document/9505229 Section IV.
+    /// 
+    /// Panics if `rng` fails, use `try_random` where the RNG may be unavailable (e.g., early boot or sandboxes).
     pub fn random(rng: impl RngCore + CryptoRng) -> Self {
         let s = generate_fr(SALT, None, rng);
         Self(G1Projective::GENERATOR * s)
     }
 
+    /// Same as `random`, but returns `AccumulatorError::RngFailure` if `rng` fails instead of panicking.
+    pub fn try_random(rng: impl RngCore + CryptoRng) -> Result<Self, AccumulatorError> {
+        let s = try_generate_fr(SALT, None, rng)?;
+        Ok(Self(G1Projective::GENERATOR * s))
+    }
+
+    /// Deterministically derives an accumulator from the public `seed` (e.g., a ceremony transcript) 
+    /// and domain separation tag `dst`, using the G1 hash-to-curve suite with SHAKE256 and SSWU.
+    /// 
+    /// Since nobody knows the discrete logarithm of the resulting point, it is a nothing-up-my-sleeve initial value.
+    pub fn hash_to_curve(seed: &[u8], dst: &[u8]) -> Self {
+        Self(G1Projective::hash::<ExpandMsgXof<Shake256>>(seed, dst))
+    }
+
+    /// Deterministically derives an accumulator from `seed`, for cross-implementation test vectors and reproducible tests.
+    /// 
+    /// NOTE: test and interop only, the same seed always yields the same accumulator. 
+    /// Use `random` in production, or `hash_to_curve` with an application-specific tag for a public ceremony.
+    pub fn from_seed(seed: &[u8]) -> Self {
+        Self::hash_to_curve(seed, b"ACCUMULATOR_FROM_SEED")
+    }
+
+    /// Using the trapdoor `key`, returns a new accumulator with the value `y` added back, 
+    /// together with a valid witness for `y` against the new accumulator, i.e., the current accumulator value.
+    /// 
+    /// NOTE: the accumulator value does not reveal its elements, hence the caller must ensure `y` is not already present
+    /// (e.g., as done by `Issuer::reinstate`), otherwise `y` is accumulated twice.
+    /// The witnesses of the other elements must be updated with `MembershipWitness::add_update_assign`.
+    pub fn add(&self, key: &SecretKey, y: Element) -> (Accumulator, MembershipWitness) {
+        let mut a = *self;
+        let wit = a.add_assign(key, y);
+        (a, wit)
+    }
+
+    /// Using the trapdoor `key`, adds back the value `y` to the accumulator 
+    /// and returns a valid witness for `y` against the updated accumulator (see `add`).
+    pub fn add_assign(&mut self, key: &SecretKey, y: Element) -> MembershipWitness {
+        let wit = MembershipWitness::from(self.0);
+        // V' = V*(𝛼+y)
+        self.0 *= key.0 + y.0;
+        wit
+    }
+
     pub fn remove(&self, key: &SecretKey, deletion: Element) -> Accumulator{
         self.clone().remove_assign(key, deletion)
     }
@@ -193,6 +530,23 @@ impl Accumulator {
         *self
     }
 
+    /// Using the trapdoor `key`, removes the values in `deletions` one at a time and returns, in order, 
+    /// the `Deletion` of each step with the intermediate accumulator value, 
+    /// i.e., the sequential update form consumed by `MembershipWitness::update_assign`.
+    /// 
+    /// The inverses are computed with a single field inversion (see `SecretKey::batch_inverses`).
+    pub fn remove_batch(&mut self, key: &SecretKey, deletions: &[Element]) -> Vec<Deletion> {
+        // V_i = V_(i-1)*(𝛼+y_i)^-1
+        key.batch_inverses(deletions)
+            .into_iter()
+            .zip(deletions)
+            .map(|(inv, &y)| {
+                self.0 *= inv.0;
+                Deletion(*self, y)
+            })
+            .collect()
+    }
+
     /// Using the trapdoor `key`, returns a new accumulator without the values in `deletions`. 
     /// Does not compute the update coefficients.
     pub fn remove_elements(&self, key: &SecretKey, deletions: &[Element]) -> Accumulator {
@@ -239,6 +593,24 @@ impl Accumulator {
         coefficients.into_iter().map(|c| Coefficient(c)).collect()
     }
 
+    /// Same as `update_assign`, but returns the update coefficients lazily, e.g., to stream them to disk or network
+    /// instead of holding all of them in memory.
+    /// 
+    /// Only the secret scalars of the coefficients are computed upfront (32 bytes per deletion). Each coefficient costs 
+    /// one point multiplication when it is yielded, and is equal to the one returned by `update_assign`.
+    pub fn update_assign_streaming(
+        &mut self,
+        key: &SecretKey,
+        deletions: &[Element],
+    ) -> CoefficientStream {
+        let d = key.batch_deletions(deletions);
+        let stream = CoefficientStream { v: self.0, scalars: key.create_coefficients(deletions), next: 0 };
+
+        // V' = V*((𝛼+y_1)*...*(𝛼+y_n))^-1
+        self.0 *= d.0;
+        stream
+    }
+
     /// Performs a batch deletion as described on page 11, section 5 in
     /// https://eprint.iacr.org/2020/777.pdf. Unoptimized version, maintained only for testing
     fn _update_assign(
@@ -256,17 +628,83 @@ impl Accumulator {
         coefficients
     }
 
+    /// Returns true if the accumulator is the identity point, against which no witness verifies.
+    /// 
+    /// Deletions multiply the accumulator by the non-zero scalar ((𝛼+y_1)*...*(𝛼+y_n))^-1,
+    /// hence a non-degenerate accumulator never becomes degenerate, even after removing all its members.
+    pub fn is_degenerate(&self) -> bool {
+        bool::from(self.0.is_identity())
+    }
+
     /// Generate accumulator id
     pub fn get_id(&self) -> Scalar{
         return generate_fr(SALT, Some(&self.to_bytes()), rand_core::OsRng{})
     }
 
+    /// Returns the accumulator id (see `get_id`) as the canonical lowercase hex string of its big-endian bytes,
+    /// e.g., to log and compare the current accumulator across systems.
+    pub fn id_hex(&self) -> String {
+        format!("{:x}", self.get_id())
+    }
+
+    /// Returns the accumulator value as the canonical lowercase hex string of its compressed encoding (see `to_bytes`),
+    /// which can be parsed back with `try_from_hex` or `from_str`.
+    pub fn value_hex(&self) -> String {
+        format!("{:x}", G1Affine::from(self.0))
+    }
+
+    /// Compares the accumulator with `other`, returning whether the values differ and the ids of both (see `get_id`),
+    /// with a human-readable summary as `Display`.
+    pub fn diff(&self, other: &Accumulator) -> AccumulatorDiff {
+        AccumulatorDiff { changed: self != other, from_id: self.get_id(), to_id: other.get_id(), epochs: None }
+    }
+
+    /// Same as `diff`, but also reports the update epochs `epoch` of this accumulator and `other_epoch` of `other`,
+    /// e.g., the epoch recorded by a holder with its witness and the issuer's current one.
+    pub fn diff_epochs(&self, epoch: u64, other: &Accumulator, other_epoch: u64) -> AccumulatorDiff {
+        AccumulatorDiff { epochs: Some((epoch, other_epoch)), ..self.diff(other) }
+    }
+
+    /// Parses an accumulator value from its hex string returned by `value_hex`, in either case.
+    /// 
+    /// Returns `AccumulatorError::BadEncoding` if the string is not the hex of a compressed point,
+    /// and `AccumulatorError::DegenerateAccumulator` if the point is the identity.
+    pub fn try_from_hex(hex: &str) -> Result<Self, AccumulatorError> {
+        Self::try_from(&decode_hex::<{ Self::BYTES }>(hex)?)
+    }
+
+    /// Parses an accumulator id from its hex string returned by `id_hex`, in either case, 
+    /// e.g., to compare it with the id of a proof's accumulator.
+    /// 
+    /// Returns `AccumulatorError::BadEncoding` if the string is not the hex of a canonical scalar.
+    pub fn id_from_hex(hex: &str) -> Result<Scalar, AccumulatorError> {
+        Option::from(Scalar::from_be_bytes(&decode_hex::<32>(hex)?)).ok_or(AccumulatorError::BadEncoding)
+    }
+
     /// Convert accumulator to bytes
     pub fn to_bytes(&self) -> [u8; Self::BYTES] {
         let mut d = [0u8; Self::BYTES];
         d.copy_from_slice(self.0.to_bytes().as_ref());
         d
     }
+
+    /// Returns the encoding `FORMAT_VERSION || to_bytes()`, to be used when persisting the accumulator.
+    pub fn to_versioned_bytes(&self) -> [u8; Self::VERSIONED_BYTES] {
+        let mut d = [0u8; Self::VERSIONED_BYTES];
+        d[0] = FORMAT_VERSION;
+        d[1..].copy_from_slice(&self.to_bytes());
+        d
+    }
+
+    /// Restores an accumulator persisted with `to_versioned_bytes`.
+    /// 
+    /// Returns `AccumulatorError::IncompatibleVersion` if the version byte is not in `SUPPORTED_FORMAT_VERSIONS`,
+    /// and `AccumulatorError::BadEncoding` or `AccumulatorError::DegenerateAccumulator` if the payload is malformed.
+    pub fn from_versioned_bytes(bytes: &[u8]) -> Result<Self, AccumulatorError> {
+        let bytes = <&[u8; Self::BYTES]>::try_from(strip_format_version(bytes)?)
+            .map_err(|_| AccumulatorError::BadEncoding)?;
+        Self::try_from(bytes)
+    }
 }
 
 
@@ -277,7 +715,7 @@ mod tests {
     use group::{ff::{Field, PrimeField}, Group};
     use rand::rngs::OsRng;
     use std::time::SystemTime;
-    use crate::{Deletion, MembershipWitness, PublicKey};
+    use crate::{Deletion, PublicKey};
 
     use super::*;
 
@@ -290,7 +728,7 @@ mod tests {
 
         // Get witness for random element
         let el  = Element::random();
-        let mut wit = MembershipWitness::new(&el, acc, &key);
+        let wit = MembershipWitness::new(&el, acc, &key).unwrap();
         
         // Revoke first element
         let t = Instant::now();
@@ -302,6 +740,296 @@ mod tests {
         println!("Time for deleting single element: {:?}", t);
     }
 
+    // Fixed encodings of known elements in the canonical big-endian order
+    #[test]
+    fn acc_element_bytes_vectors_test(){
+        let mut one = [0u8; Element::BYTES];
+        one[31] = 1;
+        let mut small = [0u8; Element::BYTES];
+        small[24..].copy_from_slice(&[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+        // r - 1, with r the order of the BLS12-381 scalar field
+        let minus_one: [u8; Element::BYTES] = [
+            0x73, 0xed, 0xa7, 0x53, 0x29, 0x9d, 0x7d, 0x48, 0x33, 0x39, 0xd8, 0x08, 0x09, 0xa1, 0xd8, 0x05,
+            0x53, 0xbd, 0xa4, 0x02, 0xff, 0xfe, 0x5b, 0xfe, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let vectors = [
+            (Element(Scalar::ZERO), [0u8; Element::BYTES]),
+            (Element::one(), one),
+            (Element(Scalar::from(0x0102030405060708u64)), small),
+            (Element(-Scalar::ONE), minus_one),
+        ];
+
+        // Check encoding matches exactly and round-trips from the fixed bytes
+        for (el, bytes) in vectors {
+            assert_eq!(el.to_bytes(), bytes);
+            assert_eq!(Element::try_from(&bytes).expect("Cannot parse element"), el);
+        }
+
+        // Check the field order r itself is rejected as non-canonical
+        let mut r = minus_one;
+        r[31] = 0x01;
+        assert!(matches!(Element::try_from(&r), Err(AccumulatorError::BadEncoding)));
+    }
+
+    // Fixed encodings of known elements in the canonical little-endian order
+    #[test]
+    fn acc_element_canonical_bytes_vectors_test(){
+        let mut one = [0u8; Element::BYTES];
+        one[0] = 1;
+        let mut small = [0u8; Element::BYTES];
+        small[..8].copy_from_slice(&[0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01]);
+        // r - 1, with r the order of the BLS12-381 scalar field
+        let minus_one: [u8; Element::BYTES] = [
+            0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xfe, 0x5b, 0xfe, 0xff, 0x02, 0xa4, 0xbd, 0x53,
+            0x05, 0xd8, 0xa1, 0x09, 0x08, 0xd8, 0x39, 0x33, 0x48, 0x7d, 0x9d, 0x29, 0x53, 0xa7, 0xed, 0x73,
+        ];
+        let vectors = [
+            (Element(Scalar::ZERO), [0u8; Element::BYTES]),
+            (Element::one(), one),
+            (Element(Scalar::from(0x0102030405060708u64)), small),
+            (Element(-Scalar::ONE), minus_one),
+        ];
+
+        // Check encoding matches exactly, is the reversed big-endian encoding and round-trips from the fixed bytes
+        for (el, bytes) in vectors {
+            assert_eq!(el.canonical_bytes(), bytes);
+            let mut be = el.to_bytes();
+            be.reverse();
+            assert_eq!(be, bytes);
+            assert_eq!(Element::from_canonical_bytes(&bytes).expect("Cannot parse element"), el);
+        }
+
+        // Check the field order r itself and the maximal value are rejected as non-canonical
+        let mut r = minus_one;
+        r[0] = 0x01;
+        assert!(matches!(Element::from_canonical_bytes(&r), Err(AccumulatorError::BadEncoding)));
+        assert!(matches!(Element::from_canonical_bytes(&[0xFF; Element::BYTES]), Err(AccumulatorError::BadEncoding)));
+    }
+
+    // Validated parsing of untrusted element bytes
+    #[test]
+    fn acc_element_try_from_bytes_test(){
+        // Check a valid element round-trips
+        let el = Element::random();
+        assert_eq!(Element::try_from_bytes(&el.to_bytes()).expect("Cannot parse element"), el);
+
+        // Check non-canonical and wrongly sized buffers are rejected
+        assert!(matches!(Element::try_from_bytes(&[0xFF; Element::BYTES]), Err(AccumulatorError::BadEncoding)));
+        assert!(matches!(Element::try_from_bytes(&el.to_bytes()[1..]), Err(AccumulatorError::BadEncoding)));
+        assert!(matches!(Element::try_from_bytes(&[0u8; Element::BYTES + 1]), Err(AccumulatorError::BadEncoding)));
+    }
+
+    // Short identifiers are stable, while equality is decided on the full element
+    #[test]
+    fn acc_element_short_id_test(){
+        // Check the identifier of a fixed element does not change
+        assert_eq!(Element::one().short_id(), [0xf2, 0x55, 0x58, 0x2f, 0x1d, 0x53, 0x73, 0xac]);
+
+        // Check the identifier is stable across serialization round trips
+        let el = Element::random();
+        let from_bytes = Element::try_from(&el.to_bytes()).expect("Cannot parse element");
+        let from_bincode: Element = bincode::deserialize(&bincode::serialize(&el).expect("Serialization error"))
+            .expect("Deserialization error");
+        for other in [from_bytes, from_bincode] {
+            assert_eq!(other, el);
+            assert_eq!(other.short_id(), el.short_id());
+        }
+
+        // Check distinct elements are told apart by equality
+        let other = Element(el.0 + Scalar::ONE);
+        assert_ne!(other, el);
+        assert_ne!(other.short_id(), el.short_id());
+    }
+
+    // Deterministic accumulator
+    #[test]
+    fn acc_hash_to_curve_test(){
+        const DST: &[u8] = b"ACC_TEST_DST";
+        let acc = Accumulator::hash_to_curve(b"ceremony transcript", DST);
+
+        // Check determinism and domain separation
+        assert_eq!(acc, Accumulator::hash_to_curve(b"ceremony transcript", DST));
+        assert_ne!(acc, Accumulator::hash_to_curve(b"ceremony transcript", b"OTHER_DST"));
+        assert_ne!(acc, Accumulator::hash_to_curve(b"other transcript", DST));
+        assert!(!acc.is_degenerate());
+
+        // Check witnesses verify against it
+        let key = SecretKey::new(None);
+        let el = Element::random();
+        assert!(MembershipWitness::new(&el, acc, &key).unwrap().verify(el, PublicKey::from(&key), acc));
+    }
+
+    // Domain-separated hashing
+    #[test]
+    fn acc_element_hash_domain_test(){
+        let msg = b"holder";
+
+        // Same message under two domains yields distinct elements
+        assert_ne!(Element::hash_with_domain(b"CREDENTIAL_A", msg), Element::hash_with_domain(b"CREDENTIAL_B", msg));
+        assert_eq!(Element::hash_with_domain(b"CREDENTIAL_A", msg), Element::hash_with_domain(b"CREDENTIAL_A", msg));
+        assert_ne!(Element::hash(msg), Element::hash_with_domain(&[], msg));
+
+        // The default hash keeps the derivation of existing elements
+        assert_eq!(Element::hash(msg), Element(generate_fr(SALT, Some(msg), rand_core::OsRng {})));
+        assert_eq!(Element::hash(msg).to_bytes(), decode_hex::<32>("4976f06d0c3d3fea5475eb6d796f53c3523d8268d66b2ce86105ef97023e7774").unwrap());
+
+        // Moving bytes between domain and message changes the element
+        assert_ne!(Element::hash_with_domain(b"AB", b"C"), Element::hash_with_domain(b"A", b"BC"));
+    }
+
+    // Hashing and ordering of elements as set keys
+    #[test]
+    fn acc_element_hash_ord_test(){
+        use std::collections::{BTreeSet, HashSet, hash_map::DefaultHasher};
+
+        // Equal elements collapse, including clones and deserialized copies
+        let elements: Vec<Element> = (0..5).map(|i: u32| Element::hash(&i.to_be_bytes())).collect();
+        let copy: Element = bincode::deserialize(&bincode::serialize(&elements[0]).unwrap()).unwrap();
+        let mut set: HashSet<Element> = elements.iter().copied().collect();
+        assert!(!set.insert(copy) && !set.insert(elements[1]));
+        assert_eq!(set.len(), 5);
+        let hash = |e: &Element| { let mut h = DefaultHasher::new(); e.hash(&mut h); h.finish() };
+        assert_eq!(hash(&elements[0]), hash(&copy));
+        assert_eq!(hash(&elements[0]), hash(&Element::try_from_bytes(&elements[0].to_bytes()).unwrap()));
+
+        // Ordering agrees with equality and with the little-endian canonical encoding
+        let mut dup = [elements.as_slice(), elements.as_slice()].concat();
+        dup.sort();
+        dup.dedup();
+        assert_eq!(dup.len(), 5);
+        assert_eq!(dup, elements.iter().copied().collect::<BTreeSet<_>>().into_iter().collect::<Vec<_>>());
+        assert!(dup.windows(2).all(|w| w[0].canonical_bytes() < w[1].canonical_bytes()));
+        assert!(Element(Scalar::ONE) < Element(Scalar::from(2u64)) && Element(Scalar::from(256u64)) < Element(Scalar::from(2u64)));
+        assert_eq!(Element::one().cmp(&Element::one()), Ordering::Equal);
+    }
+
+    #[test]
+    fn acc_element_sample_n_test(){
+        let elements = Element::sample_n(100);
+        assert_eq!(elements.len(), 100);
+        assert_eq!(elements, Element::sample_n(100));
+        assert_eq!(elements.iter().collect::<std::collections::BTreeSet<_>>().len(), 100);
+        assert!(Element::sample_n(0).is_empty());
+    }
+
+    // Streamed hashing
+    #[test]
+    fn acc_element_hasher_test(){
+        let msg: Vec<u8> = (0..10_000u32).map(|i| i as u8).collect();
+
+        // Streaming the message in pieces of any size matches the one-shot hash
+        for piece in [1, 7, 64, 1000, msg.len()] {
+            let mut hasher = ElementHasher::new();
+            msg.chunks(piece).for_each(|chunk| hasher.update(chunk));
+            assert_eq!(hasher.finalize(), Element::hash(&msg));
+        }
+        let mut hasher = ElementHasher::with_domain(b"DOCUMENT");
+        hasher.update(&msg[..10]);
+        hasher.update(&[]);
+        hasher.update(&msg[10..]);
+        assert_eq!(hasher.finalize(), Element::hash_with_domain(b"DOCUMENT", &msg));
+        assert_eq!(ElementHasher::new().finalize(), Element::hash(&[]));
+    }
+
+    // Versioned persistence format
+    #[test]
+    fn acc_versioned_bytes_test(){
+        // Check a current-version payload loads fine
+        let acc = Accumulator::random(rand_core::OsRng {});
+        let bytes = acc.to_versioned_bytes();
+        assert_eq!(bytes[0], crate::FORMAT_VERSION);
+        assert_eq!(Accumulator::from_versioned_bytes(&bytes).expect("Cannot load accumulator"), acc);
+
+        // Check a hand-crafted old-version payload is rejected
+        let mut old = [0u8; Accumulator::VERSIONED_BYTES];
+        old[1..].copy_from_slice(&acc.to_bytes());
+        assert_eq!(Accumulator::from_versioned_bytes(&old), Err(AccumulatorError::IncompatibleVersion(0)));
+        assert_eq!(Accumulator::from_versioned_bytes(&[]), Err(AccumulatorError::BadEncoding));
+        assert_eq!(Accumulator::from_versioned_bytes(&bytes[..Accumulator::BYTES]), Err(AccumulatorError::BadEncoding));
+    }
+
+    // Seeded accumulator for test vectors
+    #[test]
+    fn acc_from_seed_test(){
+        let acc = Accumulator::from_seed(b"test vector seed");
+
+        // Same seed yields the same accumulator and id, different seeds differ
+        assert_eq!(acc, Accumulator::from_seed(b"test vector seed"));
+        assert_eq!(acc.get_id(), Accumulator::from_seed(b"test vector seed").get_id());
+        assert_ne!(acc.get_id(), Accumulator::from_seed(b"other seed").get_id());
+        assert_ne!(acc, Accumulator::hash_to_curve(b"test vector seed", b"ACC_TEST_DST"));
+        assert!(!acc.is_degenerate());
+    }
+
+    // Add, remove and re-add the same element
+    #[test]
+    fn acc_add_test(){
+        let key = SecretKey::new(None);
+        let pk = PublicKey::from(&key);
+        let acc = Accumulator::random(rand_core::OsRng{});
+        let y = Element::hash(b"test");
+
+        let other = Element::hash(b"other");
+        let mut other_wit = MembershipWitness::new(&other, acc, &key).unwrap();
+
+        // Added element has a valid witness and other witnesses can be updated publicly
+        let old_acc = acc;
+        let (acc, wit) = acc.add(&key, y);
+        assert!(wit.verify(y, pk, acc));
+        assert!(!other_wit.verify(other, pk, acc));
+        other_wit.add_update_assign(other, old_acc, y);
+        assert!(other_wit.verify(other, pk, acc));
+
+        // Removed element has no valid witness
+        let removed = acc.remove(&key, y);
+        assert_eq!(removed, Accumulator::from(wit.0));
+        assert!(!wit.verify(y, pk, removed));
+
+        // Re-added element gets a fresh valid witness
+        let mut readded = removed;
+        let wit = readded.add_assign(&key, y);
+        assert_eq!(readded, acc);
+        assert!(wit.verify(y, pk, readded));
+        assert!(!wit.verify(y, pk, removed));
+    }
+
+    // Degenerate accumulator
+    #[test]
+    fn acc_degenerate_test(){
+        // Check random accumulator is healthy and identity is degenerate
+        assert!(!Accumulator::random(rand_core::OsRng{}).is_degenerate());
+        let acc = Accumulator(G1Projective::IDENTITY);
+        assert!(acc.is_degenerate());
+
+        // Check degenerate accumulator cannot be decoded
+        assert!(matches!(Accumulator::try_from(&acc.to_bytes()), Err(AccumulatorError::DegenerateAccumulator)));
+        assert!(Accumulator::try_from(&Accumulator::default().to_bytes()).is_ok());
+    }
+
+    #[test]
+    fn acc_hex_test(){
+        // Check the hex strings are canonical lowercase hex of the value and id
+        let acc = Accumulator::random(rand_core::OsRng{});
+        let (value, id) = (acc.value_hex(), acc.id_hex());
+        assert_eq!(value.len(), 2 * Accumulator::BYTES);
+        assert_eq!(id.len(), 64);
+        assert!(value.chars().chain(id.chars()).all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c)));
+
+        // Check parsing reproduces an equal value and id, in either case
+        let parsed: Accumulator = value.parse().unwrap();
+        assert_eq!(parsed, acc);
+        assert_eq!(parsed.get_id(), acc.get_id());
+        assert_eq!(Accumulator::try_from_hex(&value.to_uppercase()), Ok(acc));
+        assert_eq!(Accumulator::id_from_hex(&id), Ok(acc.get_id()));
+
+        // Check malformed strings are rejected
+        assert_eq!(Accumulator::try_from_hex(&value[2..]), Err(AccumulatorError::BadEncoding));
+        assert_eq!(Accumulator::try_from_hex(&value.replace(&value[..1], "g")), Err(AccumulatorError::BadEncoding));
+        assert_eq!(Accumulator::id_from_hex(&"ff".repeat(32)), Err(AccumulatorError::BadEncoding));
+        let identity = Accumulator(G1Projective::IDENTITY).value_hex();
+        assert_eq!(Accumulator::try_from_hex(&identity), Err(AccumulatorError::DegenerateAccumulator));
+    }
+
     // Batch removals
     #[test]
     fn acc_batch_remove_test(){
@@ -314,7 +1042,7 @@ mod tests {
         let (mut deletions, mut witnesses) = (Vec::with_capacity(BATCH_DELETIONS), Vec::with_capacity(BATCH_DELETIONS));
         (0..BATCH_DELETIONS).for_each(|i| {
             let el = Element::hash(format!("Element {i}").as_bytes());
-            deletions.push(el); witnesses.push(MembershipWitness::new(&el, acc, &key));
+            deletions.push(el); witnesses.push(MembershipWitness::new(&el, acc, &key).unwrap());
         });
         
         // Revoke all elements
@@ -329,6 +1057,21 @@ mod tests {
 
 
 
+    #[test]
+    fn acc_update_streaming_test(){
+        let (key, acc) = (SecretKey::new(None), Accumulator::random(rand_core::OsRng{}));
+        let deletions = Element::sample_n(100);
+
+        // The streamed coefficients and accumulator are equal to the eager ones
+        let (mut eager, mut lazy) = (acc, acc);
+        let coefficients = eager.update_assign(&key, &deletions);
+        let stream = lazy.update_assign_streaming(&key, &deletions);
+        assert_eq!(lazy, eager);
+        assert_eq!(stream.len(), deletions.len());
+        assert_eq!(stream.collect::<Vec<_>>(), coefficients);
+        assert_eq!(lazy.update_assign_streaming(&key, &[]).next(), None);
+    }
+
     //Batch Update
     #[test]
     fn acc_batch_update_test() {
@@ -363,5 +1106,108 @@ mod tests {
         println!("Time to compute {BATCH_DELETIONS} updates with windowed multiplication: {:?}", t1);
     }
 
-   
+    #[test]
+    fn acc_try_random_test() {
+        // RNG shim failing on every call
+        struct FailingRng;
+        impl rand_core::RngCore for FailingRng {
+            fn next_u32(&mut self) -> u32 { panic!("RNG failure") }
+            fn next_u64(&mut self) -> u64 { panic!("RNG failure") }
+            fn fill_bytes(&mut self, _dest: &mut [u8]) { panic!("RNG failure") }
+            fn try_fill_bytes(&mut self, _dest: &mut [u8]) -> Result<(), rand_core::Error> {
+                Err(rand_core::Error::from(core::num::NonZeroU32::new(rand_core::Error::CUSTOM_START).unwrap()))
+            }
+        }
+        impl rand_core::CryptoRng for FailingRng {}
+
+        // RNG failures are returned by the try_ paths
+        assert_eq!(Accumulator::try_random(FailingRng).err(), Some(AccumulatorError::RngFailure));
+        let acc = Accumulator::try_random(rand_core::OsRng {}).unwrap();
+        assert_ne!(acc, Accumulator::default());
+
+        #[cfg(feature = "proofs")]
+        {
+            let key = SecretKey::new(None);
+            let y = Element::random();
+            let wit = MembershipWitness::new(&y, acc, &key).unwrap();
+            let pp = crate::proof::ProofParamsPublic::new(&acc, &PublicKey::from(&key));
+            let priv_params = crate::proof::ProofParamsPrivate::new(y, &wit);
+            let res = crate::proof::ProofCommitting::try_new_with_rng(&pp, &priv_params, &mut FailingRng);
+            assert_eq!(res.err(), Some(AccumulatorError::RngFailure));
+            assert!(crate::proof::ProofCommitting::try_new_with_rng(&pp, &priv_params, &mut rand_core::OsRng {}).is_ok());
+        }
+    }
+
+    #[test]
+    fn acc_hash_with_test() {
+        // Trivial backend reading the first 8 bytes of the message as a little-endian integer
+        struct Truncate;
+        impl HashToScalar for Truncate {
+            fn hash_to_scalar(msg: &[u8]) -> Scalar {
+                let mut bytes = [0u8; 8];
+                let len = msg.len().min(8);
+                bytes[..len].copy_from_slice(&msg[..len]);
+                Scalar::from(u64::from_le_bytes(bytes))
+            }
+        }
+
+        // The alternate backend is deterministic and differs from the default
+        let msg = b"holder";
+        assert_eq!(Element::hash_with::<Truncate>(msg), Element::hash_with::<Truncate>(msg));
+        assert_eq!(Element::hash_with::<Truncate>(&[7]), Element(Scalar::from(7u64)));
+        assert_ne!(Element::hash_with::<Truncate>(msg), Element::hash(msg));
+
+        // The default backend is unchanged
+        assert_eq!(Element::hash(msg), Element::hash_with::<DefaultHashToScalar>(msg));
+    }
+
+    #[test]
+    fn acc_diff_test() {
+        let (key, acc) = (SecretKey::new(None), Accumulator::random(rand_core::OsRng{}));
+
+        // Identical accumulators
+        let diff = acc.diff(&acc.clone());
+        assert!(!diff.changed);
+        assert_eq!(diff.from_id, diff.to_id);
+        assert_eq!(diff.to_string(), format!("accumulator unchanged: id {}", acc.id_hex()));
+
+        // Accumulator after a revocation
+        let revoked = acc.remove(&key, Element::random());
+        let diff = acc.diff(&revoked);
+        assert!(diff.changed);
+        assert_eq!((diff.from_id, diff.to_id), (acc.get_id(), revoked.get_id()));
+        assert_eq!(diff.to_string(), format!("accumulator changed: id {} -> {}", acc.id_hex(), revoked.id_hex()));
+        assert_eq!(diff.epochs_apart(), None);
+
+        // Epochs are reported when known
+        let diff = acc.diff_epochs(3, &revoked, 5);
+        assert!(diff.changed);
+        assert_eq!((diff.epochs, diff.epochs_apart()), (Some((3, 5)), Some(2)));
+        assert_eq!(diff.to_string(), format!("accumulator changed: id {} -> {}, epoch 3 -> 5 (2 apart)", acc.id_hex(), revoked.id_hex()));
+        assert_eq!(acc.diff_epochs(4, &acc, 4).epochs_apart(), Some(0));
+    }
+
+    #[test]
+    fn acc_remove_batch_test() {
+        let (key, acc) = (SecretKey::new(None), Accumulator::random(rand_core::OsRng{}));
+        let pub_key = PublicKey::from(&key);
+        let y = Element::random();
+        let elements: Vec<Element> = (0..10).map(|_| Element::random()).collect();
+
+        // Remove the elements manually and with remove_batch
+        let mut manual_acc = acc;
+        let manual: Vec<Deletion> = elements.iter().map(|&d| Deletion(manual_acc.remove_assign(&key, d), d)).collect();
+        let mut batch_acc = acc;
+        let deletions = batch_acc.remove_batch(&key, &elements);
+        assert_eq!(deletions, manual);
+        assert_eq!(batch_acc, manual_acc);
+
+        // The deletions drive a sequential update to the same witness
+        let mut wit = MembershipWitness::new(&y, acc, &key).unwrap();
+        let mut manual_wit = wit;
+        wit.update_assign(y, &deletions).unwrap();
+        manual_wit.update_assign(y, &manual).unwrap();
+        assert_eq!(wit, manual_wit);
+        assert!(wit.verify(y, pub_key, batch_acc));
+    }
 }
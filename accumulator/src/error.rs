@@ -1,3 +1,225 @@
+use alloc::{format, string::{String, ToString}};
+
+/// Errors returned by the accumulator and its entities.
+/// 
+/// The enum is `#[non_exhaustive]` so that new errors are not breaking changes, 
+/// hence a `match` outside this crate must include a wildcard arm:
+/// 
+/// ```
+/// use accumulator::AccumulatorError;
+/// 
+/// fn is_retryable(err: &AccumulatorError) -> bool {
+///     match err {
+///         AccumulatorError::RngFailure | AccumulatorError::Io(_) => true,
+///         AccumulatorError::Revoked => false,
+///         _ => false,
+///     }
+/// }
+/// assert!(is_retryable(&AccumulatorError::RngFailure));
+/// ```
+/// 
+/// Without it the match does not compile:
+/// 
+/// ```compile_fail,E0004
+/// use accumulator::AccumulatorError;
+/// 
+/// fn is_revoked(err: &AccumulatorError) -> bool {
+///     match err {
+///         AccumulatorError::Revoked | AccumulatorError::RevokedAt(_) => true,
+///         AccumulatorError::NonInvertible | AccumulatorError::PolynomialEval | AccumulatorError::BadEncoding
+///         | AccumulatorError::EmptyInput | AccumulatorError::DegenerateAccumulator | AccumulatorError::AccumulatorIdMismatch
+///         | AccumulatorError::BatchVerificationFailed | AccumulatorError::ProofSelfCheckFailed | AccumulatorError::EqualToBlocklisted
+///         | AccumulatorError::PublicKeyMismatch | AccumulatorError::NotMember | AccumulatorError::DuplicatePseudonym
+///         | AccumulatorError::LengthMismatch | AccumulatorError::DuplicateRevocation(_) | AccumulatorError::UnknownPseudonym(_)
+///         | AccumulatorError::ProofTooLarge | AccumulatorError::RateLimited | AccumulatorError::IncompatibleVersion(_)
+///         | AccumulatorError::ElementIsMember | AccumulatorError::ThresholdNotMet | AccumulatorError::ConflictingUpdates
+///         | AccumulatorError::InvalidParams | AccumulatorError::Aborted | AccumulatorError::StaleWitness
+///         | AccumulatorError::InvalidCoefficient | AccumulatorError::InvalidSecretKey | AccumulatorError::MissingField(_)
+///         | AccumulatorError::UnknownElement | AccumulatorError::InvalidTransition(_) | AccumulatorError::RngFailure
+///         | AccumulatorError::UnexpectedEpoch(_) | AccumulatorError::BatchTooLarge(_) | AccumulatorError::Io(_) => false,
+///     }
+/// }
+/// ```
+/// 
+/// Likewise, the public byte encodings reserve a version byte (see `FORMAT_VERSION` and the `to_versioned_bytes` methods),
+/// so that a later format can be told apart from the current one instead of being misparsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AccumulatorError {
+    /// The element was deleted by the update being applied.
+    Revoked,
+    /// A required field inversion does not exist.
+    NonInvertible,
+    /// The update polynomial could not be evaluated, e.g., because it is empty.
+    PolynomialEval,
+    /// The input bytes are not a valid encoding.
+    BadEncoding,
+    /// An input list that must not be empty is empty.
+    EmptyInput,
+    /// The accumulator value is the identity point.
+    DegenerateAccumulator,
+    /// The accumulator id does not match the expected one.
+    AccumulatorIdMismatch,
+    /// At least one of the proofs in a batch does not verify.
+    BatchVerificationFailed,
+    /// A freshly generated proof does not verify locally.
+    ProofSelfCheckFailed,
+    /// The element is equal to the blocklisted value.
+    EqualToBlocklisted,
+    /// The public parameters do not share the same public key.
+    PublicKeyMismatch,
+    /// The holder's witness is not valid for any of the accumulators.
+    NotMember,
+    /// The same pseudonym is added twice.
+    DuplicatePseudonym,
+    /// The batch update coefficients do not match the number of deletions.
+    LengthMismatch,
+    /// The pseudonym appears more than once in the same revocation batch.
+    DuplicateRevocation(String),
+    /// The pseudonym is not associated to any accumulated element, e.g., because it was already revoked.
+    UnknownPseudonym(String),
+    /// The proof payload exceeds the maximum size accepted by the verifier.
+    ProofTooLarge,
+    /// The source exceeded the number of verifications allowed by the verifier.
+    RateLimited,
+    /// The persisted format version, i.e., the first byte, is not supported by this version of the crate.
+    IncompatibleVersion(u8),
+    /// The element is a member of the accumulator, hence has no non-membership witness.
+    ElementIsMember,
+    /// Fewer valid acknowledgements than the threshold of the verifiers committee.
+    ThresholdNotMet,
+    /// Two updates disagree on the same accumulator transition, or do not overlap contiguously.
+    ConflictingUpdates,
+    /// The public parameters contain a point outside the prime-order subgroup, the identity public key, or non-standard generators.
+    InvalidParams,
+    /// The operation was stopped by the caller, e.g., through a progress callback.
+    Aborted,
+    /// The witness is not valid for the accumulator, e.g., because some updates were not applied.
+    StaleWitness,
+    /// An update coefficient is not a point of the prime-order subgroup.
+    InvalidCoefficient,
+    /// The secret key is zero, or its seed is empty or all-zero.
+    InvalidSecretKey,
+    /// A required input was not provided, with its name.
+    MissingField(&'static str),
+    /// The element is not associated to any pseudonym, e.g., because it was never added or was already revoked.
+    UnknownElement,
+    /// The accumulator transition of the audit record at the given index does not verify.
+    InvalidTransition(usize),
+    /// The random number generator failed, e.g., the operating system's RNG is not available yet.
+    RngFailure,
+    /// The update of the given update epoch is missing or out of order in a chain of updates.
+    UnexpectedEpoch(u64),
+    /// The element is deleted by the update at the given index of a chain of updates.
+    RevokedAt(usize),
+    /// The batch exceeds the configured maximum number of deletions, the caller must split it.
+    BatchTooLarge(usize),
+    /// An I/O error, with its message.
+    Io(String),
+}
+
+impl AccumulatorError {
+    /// Returns the integer code used by the legacy `Error` struct.
+    pub fn code(&self) -> usize {
+        match self {
+            Self::NonInvertible | Self::BadEncoding => 1,
+            Self::PolynomialEval | Self::Io(_) => 2,
+            Self::EmptyInput => 3,
+            Self::BatchVerificationFailed => 4,
+            Self::DegenerateAccumulator => 5,
+            Self::AccumulatorIdMismatch => 6,
+            Self::ProofSelfCheckFailed => 7,
+            Self::Revoked => 8,
+            Self::EqualToBlocklisted => 9,
+            Self::PublicKeyMismatch => 10,
+            Self::NotMember => 11,
+            Self::DuplicatePseudonym => 12,
+            Self::LengthMismatch => 13,
+            Self::DuplicateRevocation(_) => 14,
+            Self::UnknownPseudonym(_) => 15,
+            Self::ProofTooLarge => 16,
+            Self::RateLimited => 17,
+            Self::IncompatibleVersion(_) => 18,
+            Self::ElementIsMember => 19,
+            Self::ThresholdNotMet => 20,
+            Self::ConflictingUpdates => 21,
+            Self::InvalidParams => 22,
+            Self::Aborted => 23,
+            Self::StaleWitness => 24,
+            Self::InvalidCoefficient => 25,
+            Self::InvalidSecretKey => 26,
+            Self::MissingField(_) => 27,
+            Self::UnknownElement => 28,
+            Self::InvalidTransition(_) => 29,
+            Self::RngFailure => 30,
+            Self::UnexpectedEpoch(_) => 31,
+            Self::RevokedAt(_) => 32,
+            Self::BatchTooLarge(_) => 33,
+        }
+    }
+
+    fn message(&self) -> String {
+        let message = match self {
+            Self::Revoked => "element was revoked",
+            Self::NonInvertible => "no inverse exists",
+            Self::PolynomialEval => "polynomial could not be evaluated",
+            Self::BadEncoding => "incorrect byte sequence",
+            Self::EmptyInput => "input vector is empty",
+            Self::DegenerateAccumulator => "degenerate accumulator",
+            Self::AccumulatorIdMismatch => "accumulator id mismatch",
+            Self::BatchVerificationFailed => "batch verification failed",
+            Self::ProofSelfCheckFailed => "proof self-check failed",
+            Self::EqualToBlocklisted => "element is equal to the blocklisted value",
+            Self::PublicKeyMismatch => "public key mismatch",
+            Self::NotMember => "element is not a member of any accumulator",
+            Self::DuplicatePseudonym => "duplicate pseudonym",
+            Self::LengthMismatch => "number of coefficients does not match number of deletions",
+            Self::DuplicateRevocation(pseudo) => return format!("pseudonym {pseudo} is revoked twice in the same batch"),
+            Self::UnknownPseudonym(pseudo) => return format!("pseudonym {pseudo} is not associated to any element"),
+            Self::ProofTooLarge => "proof payload is too large",
+            Self::RateLimited => "too many verification requests",
+            Self::IncompatibleVersion(version) => return format!("format version {version} is not supported"),
+            Self::ElementIsMember => "element is a member of the accumulator",
+            Self::ThresholdNotMet => "not enough valid acknowledgements",
+            Self::ConflictingUpdates => "updates are inconsistent",
+            Self::InvalidParams => "malformed public parameters",
+            Self::Aborted => "operation aborted",
+            Self::StaleWitness => "witness is not valid for the accumulator",
+            Self::InvalidCoefficient => "update coefficient is not in the prime-order subgroup",
+            Self::InvalidSecretKey => "degenerate secret key or seed",
+            Self::MissingField(field) => return format!("missing {field}"),
+            Self::UnknownElement => "element is not associated to any pseudonym",
+            Self::InvalidTransition(index) => return format!("audit record {index} does not match the accumulator transition"),
+            Self::RngFailure => "the random number generator failed",
+            Self::UnexpectedEpoch(epoch) => return format!("the update of epoch {epoch} is missing or out of order"),
+            Self::RevokedAt(index) => return format!("element revoked by update {index}"),
+            Self::BatchTooLarge(max) => return format!("batch exceeds the maximum of {max} deletions"),
+            Self::Io(message) => message,
+        };
+        message.to_string()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for AccumulatorError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err.to_string())
+    }
+}
+
+impl core::fmt::Display for AccumulatorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Accumulator error {}: {}", self.code(), self.message())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AccumulatorError {}
+
+/// Untyped error with an integer `code`.
+///
+/// NOTE: kept for compatibility only, fallible functions return `AccumulatorError`,
+/// which converts into `Error` preserving the code and message. It will be removed in the next release.
 #[derive(Clone, Debug)]
 pub struct Error {
     pub message: String,
@@ -13,6 +235,13 @@ impl Error {
     }
 }
 
+impl From<AccumulatorError> for Error {
+    fn from(err: AccumulatorError) -> Self {
+        Self::from_msg(err.code(), &err.message())
+    }
+}
+
+#[cfg(feature = "std")]
 impl From<std::io::Error> for Error {
     fn from(err: std::io::Error) -> Self {
         Self {
@@ -22,12 +251,13 @@ impl From<std::io::Error> for Error {
     }
 }
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "Accumulator error {}: {}", self.code, self.message)
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         None
@@ -1,18 +1,21 @@
 use crate::SALT;
 
 use super::{
-    utils::{generate_fr, Polynomial},
-    Element, Error,
+    utils::{generate_fr, strip_format_version, Polynomial, FORMAT_VERSION},
+    Element, AccumulatorError,
 };
 use bls12_381_plus::{G2Affine, G2Projective, Scalar};
+use alloc::{vec, vec::Vec};
 use core::convert::TryFrom;
-use group::GroupEncoding;
+use group::{ff::Field, GroupEncoding};
 use serde::{Deserialize, Serialize};
-use zeroize::Zeroize;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Represents alpha (secret key) 
-#[derive(Clone, Debug, Zeroize, Serialize, Deserialize)]
-#[zeroize(drop)]
+/// 
+/// The underlying scalar is wiped when the key is dropped, including for clones.
+/// NOTE: byte representations returned by `to_bytes` or `From<SecretKey>` are plain copies that the caller must clear.
+#[derive(Clone, Debug, Zeroize, ZeroizeOnDrop, Serialize, Deserialize)]
 pub struct SecretKey(pub Scalar);
 
 
@@ -23,17 +26,14 @@ impl From<SecretKey> for [u8; 32] {
 }
 
 impl TryFrom<&[u8; 32]> for SecretKey {
-    type Error = Error;
+    type Error = AccumulatorError;
 
     fn try_from(bytes: &[u8; 32]) -> Result<Self, Self::Error> {
         let res = Scalar::from_be_bytes(bytes);
         if res.is_some().unwrap_u8() == 1u8 {
             Ok(Self(res.unwrap()))
         } else {
-            Err(Error {
-                message: String::from("invalid byte sequence"),
-                code: 1,
-            })
+            Err(AccumulatorError::BadEncoding)
         }
     }
 }
@@ -50,6 +50,57 @@ impl SecretKey {
         ))
     }
 
+    /// Same as `new`, but rejects a degenerate caller-supplied `seed`, i.e., empty or all-zero, 
+    /// which is not a secret, and checks the derived key with `validate`.
+    /// 
+    /// Returns `AccumulatorError::InvalidSecretKey` in both cases.
+    pub fn try_new(seed: Option<&[u8]>) -> Result<Self, AccumulatorError> {
+        if seed.is_some_and(|s| s.iter().all(|&b| b == 0)) {
+            return Err(AccumulatorError::InvalidSecretKey);
+        }
+        let key = Self::new(seed);
+        key.validate()?;
+        Ok(key)
+    }
+
+    /// Checks that this secret key is usable, i.e., non-zero: with a zero key, the public key is the identity
+    /// and the removal of the element 0 is not invertible.
+    /// 
+    /// Returns `AccumulatorError::InvalidSecretKey` otherwise.
+    pub fn validate(&self) -> Result<(), AccumulatorError> {
+        if bool::from(self.0.is_zero()) {
+            return Err(AccumulatorError::InvalidSecretKey);
+        }
+        Ok(())
+    }
+
+    /// Returns the public key associated to this secret key, same as `PublicKey::from(&self)`.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey::from(self)
+    }
+
+    /// Returns true if `pk` is the public key associated to this secret key, e.g., after importing a key pair from a backup.
+    /// 
+    /// The public point is recomputed and compared in constant time (the equality of `G2Projective` is based on `ct_eq`).
+    pub fn matches_public_key(&self, pk: &PublicKey) -> bool {
+        self.public_key().0 == pk.0
+    }
+
+    /// Derives from this key an independent secret key for the usage `domain`, e.g., to sign messages 
+    /// without exposing the trapdoor α itself to a signing oracle.
+    /// 
+    /// The derived key is the 64-byte SHAKE256 output of `SALT || u64_be(len(domain)) || domain || α` reduced modulo r,
+    /// hence keys derived for distinct domains are unrelated, and none of them reveals α.
+    pub fn derive(&self, domain: &[u8]) -> Self {
+        let mut ikm = Vec::with_capacity(8 + domain.len() + Self::BYTES);
+        ikm.extend_from_slice(&(domain.len() as u64).to_be_bytes());
+        ikm.extend_from_slice(domain);
+        ikm.extend_from_slice(&self.0.to_be_bytes());
+        let key = Self(generate_fr(SALT, Some(&ikm), rand_core::OsRng {}));
+        ikm.zeroize();
+        key
+    }
+
     /// Takes a list of additions `y_1, ..., y_n` and returns `(y_1+alpha)*...*(y_n+alpha)`
     fn batch_additions(&self, additions: &[Element]) -> Element {
         Element(
@@ -65,8 +116,33 @@ impl SecretKey {
         Element(self.batch_additions(deletions).0.invert().unwrap())
     }
 
+    /// Takes a list `elements` and returns the list of inverses [(𝛼+y_1)^-1, ..., (𝛼+y_n)^-1],
+    /// using Montgomery's trick to compute all of them with a single field inversion.
+    pub fn batch_inverses(&self, elements: &[Element]) -> Vec<Element> {
+        // Prefix products (𝛼+y_1)*...*(𝛼+y_{i-1})
+        let mut prefix = Vec::with_capacity(elements.len());
+        let mut prod = Scalar::ONE;
+        elements.iter().for_each(|y| {
+            prefix.push(prod);
+            prod *= y.0 + self.0;
+        });
+
+        // Walk back from ((𝛼+y_1)*...*(𝛼+y_n))^-1
+        let mut inv = prod.invert().unwrap();
+        let mut inverses = vec![Element(Scalar::ONE); elements.len()];
+        for i in (0..elements.len()).rev() {
+            inverses[i] = Element(inv * prefix[i]);
+            inv *= elements[i].0 + self.0;
+        }
+        inverses
+    }
+
     /// Create the coefficients for the batch update polynomial Omega, 
     /// as in Section 3 of  <https://eprint.iacr.org/2020/777.pdf>. 
+    /// 
+    /// The returned scalars are secret-dependent but reveal nothing once multiplied by the accumulator value V 
+    /// before the deletions, which gives the public `Coefficient`s (as done by `Accumulator::update_assign`).
+    /// NOTE: never publish the scalars themselves, since the first one is (yD_1 + α)^-1 and reveals α.
     pub fn create_coefficients(
         &self,
         deletions: &[Element],
@@ -157,9 +233,25 @@ impl SecretKey {
 
 /// Represents \overline{Q} = \overline{P}*\alpha (public key) on page 6 in
 /// <https://eprint.iacr.org/2020/777.pdf>
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(not(feature = "serde_human"), derive(Serialize, Deserialize))]
 pub struct PublicKey(pub G2Projective);
 
+/// With `serde_human`, human-readable formats encode the public key as the hex string of `to_bytes`.
+#[cfg(feature = "serde_human")]
+impl Serialize for PublicKey {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_human(&self.to_bytes(), &self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde_human")]
+impl<'de> Deserialize<'de> for PublicKey {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_human::<_, _, _, { Self::BYTES }>(deserializer, |bytes| Self::try_from(bytes), Self)
+    }
+}
+
 impl core::fmt::Display for PublicKey {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "PublicKey {{ {} }}", self.0)
@@ -180,6 +272,8 @@ impl From<G2Projective> for PublicKey {
 
 impl PublicKey {
     pub const BYTES: usize = 96;
+    /// Length in bytes of the versioned encoding returned by `to_versioned_bytes`.
+    pub const VERSIONED_BYTES: usize = Self::BYTES + 1;
 
     /// Return the byte representation for this public key
     pub fn to_bytes(&self) -> [u8; Self::BYTES] {
@@ -187,6 +281,24 @@ impl PublicKey {
         d.copy_from_slice(self.0.to_bytes().as_ref());
         d
     }
+
+    /// Returns the encoding `FORMAT_VERSION || to_bytes()`, to be used when persisting or publishing the public key.
+    pub fn to_versioned_bytes(&self) -> [u8; Self::VERSIONED_BYTES] {
+        let mut d = [0u8; Self::VERSIONED_BYTES];
+        d[0] = FORMAT_VERSION;
+        d[1..].copy_from_slice(&self.to_bytes());
+        d
+    }
+
+    /// Restores a public key encoded with `to_versioned_bytes`.
+    /// 
+    /// Returns `AccumulatorError::IncompatibleVersion` if the version byte is not in `SUPPORTED_FORMAT_VERSIONS`,
+    /// and `AccumulatorError::BadEncoding` if the payload is malformed.
+    pub fn from_versioned_bytes(bytes: &[u8]) -> Result<Self, AccumulatorError> {
+        let bytes = <&[u8; Self::BYTES]>::try_from(strip_format_version(bytes)?)
+            .map_err(|_| AccumulatorError::BadEncoding)?;
+        Self::try_from(bytes)
+    }
 }
 
 impl From<&SecretKey> for PublicKey {
@@ -196,17 +308,14 @@ impl From<&SecretKey> for PublicKey {
 }
 
 impl TryFrom<&[u8; 96]> for PublicKey {
-    type Error = Error;
+    type Error = AccumulatorError;
 
     fn try_from(bytes: &[u8; 96]) -> Result<Self, Self::Error> {
         let res = G2Affine::from_compressed(bytes).map(G2Projective::from);
         if res.is_some().unwrap_u8() == 1u8 {
             Ok(Self(res.unwrap()))
         } else {
-            Err(Error {
-                message: String::from("invalid byte sequence"),
-                code: 1,
-            })
+            Err(AccumulatorError::BadEncoding)
         }
     }
 }
@@ -219,6 +328,61 @@ mod tests {
     use bls12_381_plus::G1Projective;
     use group::ff::Field;
 
+    #[test]
+    fn key_zeroize_on_drop_test() {
+        // Place a key in a controlled buffer
+        let mut buf = core::mem::MaybeUninit::<SecretKey>::uninit();
+        buf.write(SecretKey::new(Some(b"secret")));
+        let secret = unsafe { buf.assume_init_ref() }.to_bytes();
+        assert_ne!(secret, [0u8; SecretKey::BYTES]);
+
+        // Drop the key in place and check the secret bytes are no longer present
+        unsafe { core::ptr::drop_in_place(buf.as_mut_ptr()) };
+        let bytes: [u8; core::mem::size_of::<SecretKey>()] = unsafe { core::mem::transmute_copy(&buf) };
+        assert_eq!(bytes, [0u8; core::mem::size_of::<SecretKey>()]);
+    }
+
+    #[test]
+    fn key_public_key_test() {
+        let sk = SecretKey::new(Some(b"test"));
+        assert_eq!(sk.public_key(), PublicKey::from(&sk));
+    }
+
+    #[test]
+    fn key_matches_public_key_test() {
+        let sk = SecretKey::new(Some(b"test"));
+        assert!(sk.matches_public_key(&PublicKey::from(&sk)));
+        assert!(!sk.matches_public_key(&PublicKey::from(&SecretKey::new(Some(b"other")))));
+        assert!(!sk.matches_public_key(&PublicKey(-PublicKey::from(&sk).0)));
+    }
+
+    #[test]
+    fn key_versioned_bytes_test() {
+        let pk = PublicKey::from(&SecretKey::new(Some(b"test")));
+        let bytes = pk.to_versioned_bytes();
+        assert_eq!(bytes[0], crate::FORMAT_VERSION);
+        assert_eq!(PublicKey::from_versioned_bytes(&bytes), Ok(pk));
+
+        // Unknown versions and truncated payloads are rejected
+        let mut old = bytes;
+        old[0] = 0;
+        assert_eq!(PublicKey::from_versioned_bytes(&old), Err(AccumulatorError::IncompatibleVersion(0)));
+        assert_eq!(PublicKey::from_versioned_bytes(&bytes[..PublicKey::BYTES]), Err(AccumulatorError::BadEncoding));
+    }
+
+    #[test]
+    fn key_derive_test() {
+        let sk = SecretKey::new(Some(b"test"));
+
+        // Derivation is deterministic, and distinct domains or keys give distinct keys
+        let derived = sk.derive(b"domain");
+        assert_eq!(derived.0, sk.derive(b"domain").0);
+        assert_ne!(derived.0, sk.derive(b"other").0);
+        assert_ne!(derived.0, sk.0);
+        assert_ne!(derived.0, SecretKey::new(Some(b"other")).derive(b"domain").0);
+        assert!(!derived.matches_public_key(&sk.public_key()));
+    }
+
     #[test]
     fn key_batch_test() {
         // Init parameters
@@ -234,6 +398,18 @@ mod tests {
         assert_eq!(res, Scalar::ONE);
     }
 
+    #[test]
+    fn key_batch_inverses_test() {
+        // Init parameters
+        let key = SecretKey::new(None);
+        let data: Vec<Element> = (0..10).map(|i| Element::hash(format!("value{i}").as_bytes())).collect();
+
+        // Check each batch inverse equals the individual inverse
+        let inverses = key.batch_inverses(data.as_slice());
+        data.iter().zip(inverses).for_each(|(y, inv)| assert_eq!(inv, key.batch_deletions(&[*y])));
+        assert!(key.batch_inverses(&[]).is_empty());
+    }
+
     #[test]
     fn key_coefficient_test() {
 
@@ -1,7 +1,19 @@
+//! Dynamic universal accumulator over BLS12-381.
+//!
+//! The crate is `no_std` compatible (it only needs `alloc`) when built with
+//! `default-features = false`; the `std` feature is enabled by default.
+//! The membership proofs are behind the `proofs` feature, also enabled by default,
+//! so that consumers only updating witnesses can build without them.
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
+
+extern crate alloc;
+
 pub mod utils;
 pub mod accumulator;
 pub mod error;
 pub mod key;
+pub mod prelude;
+#[cfg(feature = "proofs")]
 pub mod proof;
 pub mod witness;
 
@@ -9,6 +21,7 @@ pub mod witness;
 pub use accumulator::*;
 pub use error::*;
 pub use key::*;
+#[cfg(feature = "proofs")]
 pub use proof::*;
 pub use witness::*;
 pub use utils::*;
@@ -0,0 +1,8 @@
+//! Re-exports of the types needed by most users of the accumulator, to import with `use accumulator::prelude::*`.
+
+pub use crate::accumulator::{Accumulator, Coefficient, Element, HashToScalar};
+pub use crate::error::AccumulatorError;
+pub use crate::key::{PublicKey, SecretKey};
+#[cfg(feature = "proofs")]
+pub use crate::proof::{Proof, ProofParamsPublic};
+pub use crate::witness::{Deletion, MembershipWitness, UpdateCursor};
@@ -1,12 +1,16 @@
 use crate::{
-    accumulator::{Accumulator, Element}, generate_fr, key::PublicKey, witness::MembershipWitness, SALT, Error
+    accumulator::{Accumulator, Element}, generate_fr, try_generate_fr, hash_to_g1, utils::{pairing_check, pairing_sum, pairing_sum_prepared, strip_format_version, to_affine_pair, PreparedG2, FORMAT_VERSION}, key::{PublicKey, SecretKey}, witness::{MembershipWitness, NonMembershipWitness}, SALT, AccumulatorError
 };
-use bls12_381_plus::{G1Affine, G1Projective, G2Projective, Gt, Scalar};
-use group::{Curve, Group, GroupEncoding};
+use bls12_381_plus::{G1Affine, G1Projective, G2Affine, G2Projective, Gt, Scalar};
+use group::{ff::Field, Curve, Group, GroupEncoding};
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
-use core::fmt::{self, Formatter};
+use alloc::vec::Vec;
+use core::{convert::TryFrom, fmt::{self, Formatter}};
+use digest::{ExtendableOutput, Update, XofReader};
 use merlin::Transcript;
+use rand_core::{CryptoRng, RngCore};
+use sha3::Shake256;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /* 
 Use the efficient BBS+ zero-knowledge proof described in section 5.2 of <https://link.springer.com/chapter/10.1007/978-3-031-30589-4_24>
@@ -20,6 +24,8 @@ this is equivalent to e(C, yP~ + Q~) = e(V, P~), as per Section 2 in <https://ep
 */
 
 pub const PROOF_LABEL: &[u8;16] = b"Membership Proof";
+/// Label of the verifier-supplied context (e.g., a nonce or session id) absorbed into the transcript of context-bound proofs
+pub const CONTEXT_LABEL: &[u8] = b"Verifier Context";
 
 /// Represents proof public parameters as in Section 5.2 of <https://link.springer.com/chapter/10.1007/978-3-031-30589-4_24>
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -57,6 +63,24 @@ impl ProofParamsPublic {
         }
     }
 
+    /// Checks that these params are well-formed, as built by `new` from a valid accumulator and public key.
+    /// 
+    /// Returns `AccumulatorError::InvalidParams` if the accumulator or the public key is not in the prime-order subgroup,
+    /// if the public key is the identity, or if the generators are not the standard ones,
+    /// and `AccumulatorError::DegenerateAccumulator` if the accumulator is the identity.
+    pub fn validate(&self) -> Result<(), AccumulatorError> {
+        let (c_m, x_2) = (self.c_m.to_affine(), self.x_2.to_affine());
+        let in_subgroup = c_m.is_on_curve() & c_m.is_torsion_free() & x_2.is_on_curve() & x_2.is_torsion_free();
+        let generators = self.g_1 == G1Projective::GENERATOR && self.g_2 == G2Projective::GENERATOR;
+        if !bool::from(in_subgroup) || bool::from(x_2.is_identity()) || !generators {
+            return Err(AccumulatorError::InvalidParams);
+        }
+        if bool::from(c_m.is_identity()) {
+            return Err(AccumulatorError::DegenerateAccumulator);
+        }
+        Ok(())
+    }
+
     /// Updates public parameters with input accumulator
     pub fn update_accumulator(&mut self, acc: Accumulator){
         self.c_m = acc.0;
@@ -79,11 +103,94 @@ impl ProofParamsPublic {
         transcript.append_message(b"Proof Param X2", self.x_2.to_bytes().as_ref());
         transcript.append_message(b"Proof Param G2", self.g_2.to_bytes().as_ref());
     }
-    
+
+    /// Returns a short fingerprint of these params, the first 16 bytes of the SHAKE256 hash of the public key and the generators,
+    /// e.g., for a verifier to pin the issuer's params on first use and compare them on reconnection.
+    /// 
+    /// The accumulator is excluded, hence the fingerprint is stable across epochs and only changes on key rotation,
+    /// which should trigger a re-verification of the issuer's params.
+    pub fn fingerprint(&self) -> [u8; 16] {
+        let mut hasher = Shake256::default();
+        hasher.update(b"PROOF_PARAMS_FINGERPRINT");
+        hasher.update(self.g_1.to_bytes().as_ref());
+        hasher.update(self.x_2.to_bytes().as_ref());
+        hasher.update(self.g_2.to_bytes().as_ref());
+        let mut fingerprint = [0u8; 16];
+        hasher.finalize_xof().read(&mut fingerprint);
+        fingerprint
+    }
+
+    /// Computes the delta from `prev` to these params, which only contains the accumulator if it changed,
+    /// together with the fingerprint of the public key it applies to.
+    /// 
+    /// Returns `AccumulatorError::PublicKeyMismatch` if the public keys (or generators) differ, i.e., on key rotation,
+    /// in which case the full params must be sent.
+    pub fn delta_from(&self, prev: &ProofParamsPublic) -> Result<ParamsDelta, AccumulatorError> {
+        let fingerprint = self.fingerprint();
+        if fingerprint != prev.fingerprint() {
+            return Err(AccumulatorError::PublicKeyMismatch);
+        }
+        let c_m = if self.c_m != prev.c_m { Some(self.c_m) } else { None };
+        Ok(ParamsDelta { fingerprint, c_m })
+    }
+
+    /// Applies in place the delta `delta`, computed with `delta_from` against params with the same public key.
+    /// 
+    /// Returns `AccumulatorError::PublicKeyMismatch`, leaving these params unmodified, if the delta is for another public key.
+    pub fn apply_delta(&mut self, delta: &ParamsDelta) -> Result<(), AccumulatorError> {
+        if delta.fingerprint != self.fingerprint() {
+            return Err(AccumulatorError::PublicKeyMismatch);
+        }
+        self.c_m = delta.c_m.unwrap_or(self.c_m);
+        Ok(())
+    }
+}
+
+/// Represents the change of the accumulator between two versions of `ProofParamsPublic` with the same public key,
+/// which is not re-transmitted: only its fingerprint (see `ProofParamsPublic::fingerprint`) is, to detect key rotations.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ParamsDelta {
+    pub fingerprint: [u8; 16],
+    pub c_m: Option<G1Projective>,
+}
+
+impl ParamsDelta {
+    /// Returns true if the accumulator did not change.
+    pub fn is_empty(&self) -> bool {
+        self.c_m.is_none()
+    }
+}
+
+/// Represents proof public parameters with the G2 points prepared for the pairing computations,
+/// so that repeated verifications against the same parameters skip the preparation.
+#[derive(Debug, Clone)]
+pub struct ProofParamsPrepared {
+    params: ProofParamsPublic,
+    x_2: PreparedG2,
+    g_2_neg: PreparedG2,
+}
+
+impl ProofParamsPrepared {
+    /// Prepare the G2 points X_2 and -g_2 of the input public parameters
+    pub fn new(params: ProofParamsPublic) -> Self {
+        Self {
+            params,
+            x_2: PreparedG2::from(params.x_2.to_affine()),
+            g_2_neg: PreparedG2::from(-params.g_2.to_affine()),
+        }
+    }
+
+    /// Get the public parameters used to build the prepared params
+    pub fn get_params(&self) -> ProofParamsPublic {
+        self.params
+    }
 }
 
 /// Represents proof private parameters as in Section 5.2 of <https://link.springer.com/chapter/10.1007/978-3-031-30589-4_24> 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// 
+/// The copied element and witness are wiped when the params are dropped, including for clones.
+/// NOTE: the element and witness passed to `new` are plain copies, e.g., the ones kept by the holder, which are not wiped.
+#[derive(Debug, Clone, Eq, PartialEq, Zeroize, ZeroizeOnDrop)]
 pub struct ProofParamsPrivate{ 
     a: G1Projective,
     e: Scalar,
@@ -113,22 +220,72 @@ pub struct ProofCommitting {
 impl ProofCommitting {
     /// Create a new membership proof committing phase
     pub fn new(params_pub: &ProofParamsPublic, params_priv: &ProofParamsPrivate) -> Self {
-        
-        let mut rng = rand_core::OsRng {};
-        
-        // Randomly select r
-        let r = generate_fr(SALT, None, &mut rng);
+        Self::new_with_rng(params_pub, params_priv, &mut rand_core::OsRng {})
+    }
 
-        
+    /// Same as `new`, but draws the blindings from `rng` instead of the operating system's RNG,
+    /// e.g., a seeded RNG to reproduce proofs in tests, or an RNG hedged with a nonce in production.
+    /// The same RNG state and inputs produce the same proof.
+    /// 
+    /// Panics if `rng` fails, see `try_new_with_rng`.
+    pub fn new_with_rng(params_pub: &ProofParamsPublic, params_priv: &ProofParamsPrivate, rng: &mut (impl RngCore + CryptoRng)) -> Self {
+        Self::try_new_with_rng(params_pub, params_priv, rng).expect("random number generator failed")
+    }
+
+    /// Same as `new_with_rng`, but returns `AccumulatorError::RngFailure` if `rng` fails instead of panicking.
+    pub fn try_new_with_rng(params_pub: &ProofParamsPublic, params_priv: &ProofParamsPrivate, rng: &mut (impl RngCore + CryptoRng)) -> Result<Self, AccumulatorError> {
+        // Randomly select r, alpha, beta
+        let r = try_generate_fr(SALT, None, &mut *rng)?;
+        let alpha = try_generate_fr(SALT, None, &mut *rng)?;
+        let beta = try_generate_fr(SALT, None, &mut *rng)?;
+        Ok(Self::with_blindings(params_pub, params_priv, r, alpha, beta))
+    }
+
+    /// Create a new membership proof committing phase whose blindings are derived from `seed`,
+    /// so that the same seed and inputs reproduce the same proof, e.g., to regenerate a proof during an audit
+    /// from the holder's secret inputs and a recorded nonce.
+    /// 
+    /// The blindings are squeezed from a Merlin transcript absorbing the public parameters, the private parameters and `seed`,
+    /// hence reusing a seed with different inputs does not reuse blindings.
+    /// NOTE: everyone knowing `seed` and the private parameters can recompute the blindings,
+    /// hence `seed` must be kept as secret as the witness, and a fresh seed used for each proof that must be unlinkable.
+    pub fn new_deterministic(params_pub: &ProofParamsPublic, params_priv: &ProofParamsPrivate, seed: &[u8]) -> Self {
+        let mut transcript = Transcript::new(b"Proof Blindings");
+        params_pub.add_to_transcript(&mut transcript);
+        transcript.append_message(b"A", params_priv.a.to_bytes().as_ref());
+        transcript.append_message(b"e", &params_priv.e.to_be_bytes());
+        transcript.append_message(b"Seed", seed);
+
+        let mut blinding = |label: &'static [u8]| {
+            let mut okm = [0u8; 64];
+            transcript.challenge_bytes(label, &mut okm);
+            Scalar::from_bytes_wide(&okm)
+        };
+        let (r, alpha, beta) = (blinding(b"r"), blinding(b"alpha"), blinding(b"beta"));
+        Self::with_blindings(params_pub, params_priv, r, alpha, beta)
+    }
+
+    /// Create the committing phase of a single proof that all the elements in `members`, given with their witnesses, are accumulated.
+    /// 
+    /// Each element is committed with fresh blindings as in `new`, and the resulting `MultiProof` answers one challenge for all of them.
+    /// Returns `AccumulatorError::EmptyInput` if `members` is empty.
+    /// NOTE: the validity of the witnesses is not checked, a stale witness produces a proof that does not verify.
+    pub fn new_multi(params_pub: &ProofParamsPublic, members: &[(Element, MembershipWitness)]) -> Result<MultiProofCommitting, AccumulatorError> {
+        if members.is_empty() {
+            return Err(AccumulatorError::EmptyInput);
+        }
+        let commitments = members.iter()
+            .map(|(y, wit)| Self::new(params_pub, &ProofParamsPrivate::new(*y, wit)))
+            .collect();
+        Ok(MultiProofCommitting { commitments })
+    }
+
+    fn with_blindings(params_pub: &ProofParamsPublic, params_priv: &ProofParamsPrivate, r: Scalar, alpha: Scalar, beta: Scalar) -> Self {
         //A_bar = A*r
         let a_bar = params_priv.a * r;
         
         //B_bar = (C_m-e*A)*r
         let b_bar = (params_pub.c_m - params_priv.a * params_priv.e)*r;
-        
-        // Randomly select alpha, beta
-        let alpha = generate_fr(SALT, None, &mut rng);
-        let beta = generate_fr(SALT, None, &mut rng);
 
         // U = alpha*C_m + beta*A_bar
         let u =  alpha * params_pub.c_m + beta * a_bar;
@@ -171,6 +328,7 @@ impl ProofCommitting {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(not(feature = "serde_human"), derive(Serialize, Deserialize))]
 pub struct Proof {
     /// A ZKP membership proof
     a_bar: G1Projective,
@@ -180,8 +338,72 @@ pub struct Proof {
     challenge_hash: Scalar
 }
 
+/// Fields of a `Proof`, with the derived encoding used by binary formats under `serde_human`.
+#[cfg(feature = "serde_human")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Proof")]
+struct ProofFields {
+    a_bar: G1Projective,
+    b_bar: G1Projective,
+    s: Scalar,
+    t: Scalar,
+    challenge_hash: Scalar
+}
+
+/// With `serde_human`, human-readable formats encode the proof as the hex string of `to_bytes`, 
+/// instead of an object with a hex string per field.
+#[cfg(feature = "serde_human")]
+impl Serialize for Proof {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let fields = ProofFields { a_bar: self.a_bar, b_bar: self.b_bar, s: self.s, t: self.t, challenge_hash: self.challenge_hash };
+        crate::utils::serialize_human(&self.to_bytes(), &fields, serializer)
+    }
+}
+
+#[cfg(feature = "serde_human")]
+impl<'de> Deserialize<'de> for Proof {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_human::<_, _, _, { Self::BYTES }>(deserializer, Self::from_bytes, |f: ProofFields| {
+            Self { a_bar: f.a_bar, b_bar: f.b_bar, s: f.s, t: f.t, challenge_hash: f.challenge_hash }
+        })
+    }
+}
+
 impl Proof {
-    const BYTES: usize = 192;
+    pub const BYTES: usize = 192;
+    /// Length of the compact encoding A_bar || B_bar || s || t || c returned by `to_bytes`, 
+    /// which drops the commitment U since the verifier reconstructs it (see `finalize`).
+    pub const SERIALIZED_LEN: usize = Self::BYTES;
+    /// Length of the full encoding A_bar || B_bar || U || s || t || c, which also contains the commitment U (see `to_bytes_with`).
+    pub const FULL_SERIALIZED_LEN: usize = Self::BYTES + 48;
+    /// Length of the versioned encoding `FORMAT_VERSION || to_bytes()` returned by `to_versioned_bytes`.
+    pub const VERSIONED_BYTES: usize = Self::BYTES + 1;
+
+    /// Returns the length of the compact (`SERIALIZED_LEN`) or full (`FULL_SERIALIZED_LEN`) encoding of a proof.
+    pub const fn byte_len(compact: bool) -> usize {
+        if compact { Self::SERIALIZED_LEN } else { Self::FULL_SERIALIZED_LEN }
+    }
+
+    /// Creates a membership proof for the element `y` with witness `w` against the public parameters `pp`,
+    /// drawing the blindings from `rng` and deriving the challenge from a transcript labeled with `PROOF_LABEL`,
+    /// hence it verifies as the proofs created by `Holder::proof_membership`.
+    /// 
+    /// Panics if `rng` fails, see `try_create_with_transcript`.
+    pub fn create(pp: &ProofParamsPublic, y: Element, w: &MembershipWitness, rng: &mut (impl RngCore + CryptoRng)) -> Self {
+        let mut transcript = Transcript::new(PROOF_LABEL);
+        pp.add_to_transcript(&mut transcript);
+        Self::try_create_with_transcript(pp, &ProofParamsPrivate::new(y, w), transcript, rng).expect("random number generator failed")
+    }
+
+    /// Creates a membership proof whose challenge is derived from `transcript`, which must already contain the public parameters
+    /// (see `ProofParamsPublic::add_to_transcript`) and any context, drawing the blindings from `rng`.
+    /// 
+    /// Returns `AccumulatorError::RngFailure` if `rng` fails.
+    pub fn try_create_with_transcript(pp: &ProofParamsPublic, params_priv: &ProofParamsPrivate, mut transcript: Transcript, rng: &mut (impl RngCore + CryptoRng)) -> Result<Self, AccumulatorError> {
+        let pc = ProofCommitting::try_new_with_rng(pp, params_priv, rng)?;
+        pc.get_bytes_for_challenge(&mut transcript);
+        Ok(pc.gen_proof(Element::from_transcript(PROOF_LABEL, &mut transcript)))
+    }
 
     /// Generate the structure that can be used in the challenge hash
     /// returns a struct to avoid recomputing
@@ -191,11 +413,37 @@ impl Proof {
     ) -> ProofFinal {
 
         // e(A_bar, X_2) - e(B_bar, g_2) = 0
-        let pair_final = pair(self.a_bar, params.x_2)-pair(self.b_bar, params.g_2);
-        
+        let (a_bar, b_bar) = to_affine_pair(&self.a_bar, &self.b_bar);
+        let pair_final = pairing_sum(&[(a_bar, params.x_2.to_affine()), (b_bar, -params.g_2.to_affine())]);
+        self.finalize_with_pairing(params, pair_final)
+    }
+
+    /// Same as `finalize`, but evaluates the pairings with a single Miller loop
+    /// using the prepared parameters `prepared`.
+    pub fn finalize_prepared(
+        &self,
+        prepared: &ProofParamsPrepared,
+    ) -> ProofFinal {
+
+        // e(A_bar, X_2) - e(B_bar, g_2) = 0
+        let (a_bar, b_bar) = to_affine_pair(&self.a_bar, &self.b_bar);
+        let pair_final = pairing_sum_prepared(&[(a_bar, &prepared.x_2), (b_bar, &prepared.g_2_neg)]);
+        self.finalize_with_pairing(&prepared.params, pair_final)
+    }
+
+    /// Check the challenge of this proof against the public parameters `params`, without checking the pairing equation.
+    fn verify_challenge(&self, params: &ProofParamsPublic) -> bool {
+        let mut transcript = Transcript::new(PROOF_LABEL);
+        params.add_to_transcript(&mut transcript);
+        self.finalize_with_pairing(params, Gt::IDENTITY).get_bytes_for_challenge(&mut transcript);
+        Element::from_transcript(PROOF_LABEL, &mut transcript).0 == self.challenge_hash
+    }
+
+    fn finalize_with_pairing(&self, params: &ProofParamsPublic, pair_final: Gt) -> ProofFinal {
         // Reconstruct U = s*C_m + t*A_bar - c*B_bar
         let u = self.s*params.c_m + self.t*self.a_bar - self.challenge_hash * self.b_bar;
         ProofFinal {
+            degenerate: params.get_accumulator().is_degenerate(),
             pair_final: pair_final,
             a_bar: self.a_bar,
             b_bar: self.b_bar,
@@ -204,7 +452,11 @@ impl Proof {
         }
     }
 
-    /// Get the byte representation of the proof
+    /// Get the byte representation of the proof: A_bar || B_bar || s || t || c, 
+    /// with compressed G1 points and big-endian scalars, independent of any serde framing.
+    /// 
+    /// A_bar and B_bar are encoded exactly as, and in the same order as, they are absorbed into the transcript
+    /// by `ProofCommitting::get_bytes_for_challenge`, while U is recomputed by the verifier from the other components.
     pub fn to_bytes(&self) -> [u8; Self::BYTES] {
         let mut result = [0u8; Self::BYTES];
         result[..48].copy_from_slice(&self.a_bar.to_affine().to_compressed());
@@ -215,26 +467,97 @@ impl Proof {
         result
     }
 
+    /// Get the compact (same as `to_bytes`) or full byte representation of the proof for the public parameters `params`, 
+    /// with `byte_len(compact)` bytes.
+    /// 
+    /// The full representation A_bar || B_bar || U || s || t || c also contains the commitment U, recomputed with `params`,
+    /// e.g., for interoperability with verifiers expecting all the components absorbed into the transcript.
+    pub fn to_bytes_with(&self, params: &ProofParamsPublic, compact: bool) -> Vec<u8> {
+        let bytes = self.to_bytes();
+        if compact {
+            return bytes.to_vec();
+        }
+        let u = self.finalize_with_pairing(params, Gt::IDENTITY).u;
+        [&bytes[..96], &u.to_affine().to_compressed(), &bytes[96..]].concat()
+    }
+
+    /// Parses a byte sequence produced by `to_bytes_with` with the same `params` and `compact` flag.
+    /// 
+    /// Returns `AccumulatorError::BadEncoding` if the length is not `byte_len(compact)`, a component is malformed as in `try_from_bytes`,
+    /// or the commitment U of a full representation differs from the one reconstructed from the other components.
+    pub fn try_from_bytes_with(input: &[u8], params: &ProofParamsPublic, compact: bool) -> Result<Self, AccumulatorError> {
+        if compact {
+            return Self::try_from_bytes(input);
+        }
+        if input.len() != Self::FULL_SERIALIZED_LEN {
+            return Err(AccumulatorError::BadEncoding);
+        }
+        let proof = Self::try_from_bytes(&[&input[..96], &input[144..]].concat())?;
+        let u = proof.finalize_with_pairing(params, Gt::IDENTITY).u;
+        if input[96..144] != u.to_affine().to_compressed() {
+            return Err(AccumulatorError::BadEncoding);
+        }
+        Ok(proof)
+    }
+
+    /// Convert a byte sequence of exactly `Proof::BYTES` bytes to a proof, as produced by `to_bytes`.
+    /// 
+    /// Points are checked to be valid compressed G1 points in the prime order subgroup.
+    pub fn try_from_bytes(input: &[u8]) -> Result<Self, AccumulatorError> {
+        let input = <&[u8; Self::BYTES]>::try_from(input)
+            .map_err(|_| AccumulatorError::BadEncoding)?;
+        Self::from_bytes(input)
+    }
+
+    /// Returns the encoding `FORMAT_VERSION || to_bytes()`, to be used when the proof is sent to verifiers 
+    /// that may not share the same release of this crate.
+    pub fn to_versioned_bytes(&self) -> [u8; Self::VERSIONED_BYTES] {
+        let mut result = [0u8; Self::VERSIONED_BYTES];
+        result[0] = FORMAT_VERSION;
+        result[1..].copy_from_slice(&self.to_bytes());
+        result
+    }
+
+    /// Restores a proof encoded with `to_versioned_bytes`.
+    /// 
+    /// Returns `AccumulatorError::IncompatibleVersion` if the version byte is not in `SUPPORTED_FORMAT_VERSIONS`,
+    /// and `AccumulatorError::BadEncoding` if the payload is malformed.
+    pub fn from_versioned_bytes(input: &[u8]) -> Result<Self, AccumulatorError> {
+        Self::try_from_bytes(strip_format_version(input)?)
+    }
+
+    /// Fast structural check of this proof, which does not evaluate any pairing.
+    /// 
+    /// Returns `AccumulatorError::BadEncoding` if A_bar or B_bar is the identity, 
+    /// for which the pairing equation holds trivially, or if the challenge is zero.
+    /// The identity check is also enforced by `ProofFinal::verify`, hence by every verification path.
+    pub fn validate_structure(&self) -> Result<(), AccumulatorError> {
+        if bool::from(self.a_bar.is_identity() | self.b_bar.is_identity() | self.challenge_hash.is_zero()) {
+            return Err(AccumulatorError::BadEncoding);
+        }
+        Ok(())
+    }
+
     /// Convert a byte representation to a proof
-    pub fn from_bytes(input: &[u8; Self::BYTES]) -> Result<Self, Error> {
-        let g1 = |b: &[u8]| -> Result<G1Projective, Error> {
+    pub fn from_bytes(input: &[u8; Self::BYTES]) -> Result<Self, AccumulatorError> {
+        let g1 = |b: &[u8]| -> Result<G1Projective, AccumulatorError> {
             let buf = <[u8; 48]>::try_from(b)
-                .map_err(|_| Error::from_msg(1, "Signature Serialization Error"))?;
+                .map_err(|_| AccumulatorError::BadEncoding)?;
             let pt = G1Affine::from_compressed(&buf).map(G1Projective::from);
             if pt.is_some().unwrap_u8() == 1 {
                 Ok(pt.unwrap())
             } else {
-                Err(Error::from_msg(1, "Signature Serialization Error"))
+                Err(AccumulatorError::BadEncoding)
             }
         };
-        let sc = |b: &[u8]| -> Result<Scalar, Error> {
+        let sc = |b: &[u8]| -> Result<Scalar, AccumulatorError> {
             let buf = <[u8; 32]>::try_from(b)
-                .map_err(|_| Error::from_msg(2, "Signature Serialization Error"))?;
+                .map_err(|_| AccumulatorError::BadEncoding)?;
             let pt = Scalar::from_be_bytes(&buf);
             if pt.is_some().unwrap_u8() == 1 {
                 Ok(pt.unwrap())
             } else {
-                Err(Error::from_msg(2, "Signature Serialization Error"))
+                Err(AccumulatorError::BadEncoding)
             }
         };
         Ok(Self {
@@ -249,9 +572,82 @@ impl Proof {
 }
 
 
+/// Committing phase of a membership proof for several elements of the same accumulator, created by `ProofCommitting::new_multi`.
+#[derive(Debug, Clone)]
+pub struct MultiProofCommitting {
+    commitments: Vec<ProofCommitting>,
+}
+
+impl MultiProofCommitting {
+    /// Return bytes that need to be hashed for generating challenge.
+    /// Count || A_bar_1 || B_bar_1 || U_1 || ... || A_bar_n || B_bar_n || U_n
+    pub fn get_bytes_for_challenge(&self, transcript: &mut Transcript) {
+        transcript.append_message(b"Count", &(self.commitments.len() as u64).to_be_bytes());
+        self.commitments.iter().for_each(|pc| pc.get_bytes_for_challenge(transcript));
+    }
+
+    /// Answers the challenge `challenge_hash`, shared by all the elements.
+    pub fn gen_proof(&self, challenge_hash: Element) -> MultiProof {
+        let members = self.commitments.iter()
+            .map(|pc| {
+                let proof = pc.gen_proof(challenge_hash);
+                (proof.a_bar, proof.b_bar, proof.s, proof.t)
+            })
+            .collect();
+        MultiProof { members, challenge_hash: challenge_hash.0 }
+    }
+}
+
+/// A membership proof for several elements of the same accumulator, with a single Fiat-Shamir challenge.
+/// 
+/// Each element i has its own (A_bar_i, B_bar_i) and responses (s_i, t_i), as in a `Proof`, and the challenge 
+/// is derived from the commitments of all the elements, hence the proof does not verify unless every element is accumulated.
+/// NOTE: the number of elements is revealed, and is bound to the challenge.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultiProof {
+    /// (A_bar_i, B_bar_i, s_i, t_i) of each element
+    members: Vec<(G1Projective, G1Projective, Scalar, Scalar)>,
+    challenge_hash: Scalar,
+}
+
+impl MultiProof {
+    /// Returns the number of elements proved.
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns true if the proof contains no element, in which case it never verifies.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Verifies this proof against the public parameters `params` and the `transcript` used for proving,
+    /// which must already contain the public parameters (and any context) absorbed before the commitments.
+    pub fn verify(&self, params: &ProofParamsPublic, transcript: &mut Transcript) -> bool {
+        if self.members.is_empty() || params.get_accumulator().is_degenerate() || self.members.iter().any(|(a_bar, _, _, _)| bool::from(a_bar.is_identity())) {
+            return false;
+        }
+
+        // Reconstruct U_i and the pairing equation of each element
+        let finals: Vec<ProofFinal> = self.members.iter()
+            .map(|(a_bar, b_bar, s, t)| Proof { a_bar: *a_bar, b_bar: *b_bar, s: *s, t: *t, challenge_hash: self.challenge_hash }.finalize(params))
+            .collect();
+        if finals.iter().any(|f| !bool::from(f.pair_final.is_identity())) {
+            return false;
+        }
+
+        transcript.append_message(b"Count", &(finals.len() as u64).to_be_bytes());
+        finals.iter().for_each(|f| f.get_bytes_for_challenge(transcript));
+        Element::from_transcript(PROOF_LABEL, transcript).0 == self.challenge_hash
+    }
+}
+
+
 /// The computed values after running MembershipProof.finalize
 #[derive(Debug, Copy, Clone)]
 pub struct ProofFinal {
+    /// Whether the accumulator of the parameters is the identity, for which no proof is valid
+    degenerate: bool,
     a_bar: G1Projective,
     b_bar: G1Projective,
     u: G1Projective,
@@ -269,7 +665,14 @@ impl ProofFinal {
         transcript.append_message(b"U", self.u.to_bytes().as_ref());
     }
 
+    /// Checks the pairing equation and the challenge of the finalized proof, recomputed from `transcript`.
+    /// 
+    /// Always fails if the proof was finalized against an identity (degenerate) accumulator, which is invalid for membership,
+    /// or if A_bar or B_bar is the identity, for which the pairing equation holds without any witness (see `Proof::validate_structure`).
     pub fn verify(&self, transcript: &mut Transcript) -> bool {
+        if self.degenerate || bool::from(self.a_bar.is_identity() | self.b_bar.is_identity()) {
+            return false;
+        }
         self.get_bytes_for_challenge(transcript);
         let computed_challenge = Element::from_transcript(PROOF_LABEL, transcript);
         return (self.pair_final.is_identity().unwrap_u8() == 1) && self.challenge_hash == computed_challenge.0;
@@ -277,6 +680,590 @@ impl ProofFinal {
 }
 
 
+/// A proof that the element y hidden in a membership proof is not equal to a public value b.
+/// 
+/// Given T = B_bar + b*A_bar = r*C_m + (b-y)*A_bar, the holder proves knowledge of (z_1, z_2) such that
+/// A_bar = z_1*T + z_2*C_m, with z_1 = (b-y)^-1 and z_2 = -r*(b-y)^-1, which is only possible if y != b.
+/// The proof must be created and verified on the same transcript right after the membership proof.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InequalityProof {
+    s_1: Scalar,
+    s_2: Scalar,
+    challenge_hash: Scalar,
+}
+
+impl Element {
+    /// Proves that this element is not equal to `other`, composed with the membership proof committed in `pc`
+    /// for this element and the public parameters `params_pub`.
+    /// 
+    /// Must be called on the transcript `transcript` after deriving the challenge of the membership proof.
+    /// Returns an error if this element is equal to `other`.
+    pub fn prove_not_equal(&self, other: Element, params_pub: &ProofParamsPublic, pc: &ProofCommitting, transcript: &mut Transcript) -> Result<InequalityProof, AccumulatorError> {
+        // (b-y)^-1
+        let t = (other.0 - self.0).invert();
+        if bool::from(t.is_none()) {
+            return Err(AccumulatorError::EqualToBlocklisted);
+        }
+        let (z_1, z_2) = (t.unwrap(), -pc.r * t.unwrap());
+        let t = pc.b_bar + pc.a_bar * other.0;
+
+        // Randomly select k_1, k_2 and compute R = k_1*T + k_2*C_m
+        let rng = rand_core::OsRng {};
+        let (k_1, k_2) = (generate_fr(SALT, None, rng), generate_fr(SALT, None, rng));
+        let r = t * k_1 + params_pub.c_m * k_2;
+
+        let challenge_hash = InequalityProof::challenge(other, r, transcript).0;
+        Ok(InequalityProof {
+            s_1: schnorr(k_1, z_1, challenge_hash),
+            s_2: schnorr(k_2, z_2, challenge_hash),
+            challenge_hash,
+        })
+    }
+}
+
+impl InequalityProof {
+    const LABEL: &'static [u8; 16] = b"Inequality Proof";
+
+    /// Verifies that the element hidden in the membership proof `proof` is not equal to `other`.
+    /// 
+    /// Must be called on the transcript `transcript` after verifying the membership proof.
+    pub fn verify(&self, proof: &Proof, params: &ProofParamsPublic, other: Element, transcript: &mut Transcript) -> bool {
+        // s_1*T + s_2*C_m - c*A_bar = R
+        let t = proof.b_bar + proof.a_bar * other.0;
+        let r = t * self.s_1 + params.c_m * self.s_2 - proof.a_bar * self.challenge_hash;
+        Self::challenge(other, r, transcript).0 == self.challenge_hash
+    }
+
+    /// Absorbs the blocklisted value and the commitment R, and derives the challenge
+    fn challenge(other: Element, r: G1Projective, transcript: &mut Transcript) -> Element {
+        transcript.append_message(b"Blocklisted b", &other.0.to_be_bytes());
+        transcript.append_message(b"R", r.to_bytes().as_ref());
+        Element::from_transcript(Self::LABEL, transcript)
+    }
+}
+
+
+/// A membership proof that only a designated verifier can check.
+/// 
+/// The challenge also binds the Diffie-Hellman key K = k*X_v = x_v*R, computed from an ephemeral point R = k*g_2 
+/// and the verifier's public key X_v = x_v*g_2, so that recomputing the challenge requires the verifier's secret key x_v.
+/// Moreover, B_bar is sent masked as B_bar + H(K), hence neither the pairing equation e(A_bar, X_2) = e(B_bar, g_2) 
+/// nor the Schnorr proof can be checked without x_v.
+/// 
+/// NOTE: the proof is transferable: the designated verifier can make it publicly checkable by revealing K, 
+/// which lets anyone unmask B_bar and recompute the challenge. Only the holder and the designated verifier 
+/// can check the proof as long as K is kept secret, but the proof is not deniable.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DesignatedProof {
+    proof: Proof,
+    r: G2Projective,
+}
+
+impl DesignatedProof {
+    /// Length of the encoding Proof || R returned by `to_bytes`.
+    pub const BYTES: usize = Proof::BYTES + 96;
+
+    /// Create a new membership proof for the private parameters `params_priv`, 
+    /// which can only be verified by the owner of the secret key associated with `verifier_pubkey`.
+    pub fn new(params_pub: &ProofParamsPublic, params_priv: &ProofParamsPrivate, verifier_pubkey: &PublicKey) -> Self {
+        // Randomly select k and compute R = k*g_2
+        let k = generate_fr(SALT, None, rand_core::OsRng {});
+        let r = G2Projective::GENERATOR * k;
+
+        let shared_key = verifier_pubkey.0 * k;
+
+        let mut transcript = Transcript::new(PROOF_LABEL);
+        params_pub.add_to_transcript(&mut transcript);
+        Self::add_shared_key_to_transcript(shared_key, &mut transcript);
+
+        let pc = ProofCommitting::new(params_pub, params_priv);
+        pc.get_bytes_for_challenge(&mut transcript);
+        let mut proof = pc.gen_proof(Element::from_transcript(PROOF_LABEL, &mut transcript));
+        proof.b_bar += Self::mask(shared_key);
+        Self { proof, r }
+    }
+
+    /// Verifies this proof against the public parameters `params` using the designated verifier's secret key `verifier_secret`.
+    pub fn verify(&self, params: &ProofParamsPublic, verifier_secret: &SecretKey) -> bool {
+        let shared_key = self.r * verifier_secret.0;
+
+        let mut transcript = Transcript::new(PROOF_LABEL);
+        params.add_to_transcript(&mut transcript);
+        Self::add_shared_key_to_transcript(shared_key, &mut transcript);
+
+        let mut proof = self.proof;
+        proof.b_bar -= Self::mask(shared_key);
+        proof.finalize(params).verify(&mut transcript)
+    }
+
+    /// Get the byte representation of the proof, i.e., the masked membership proof (see `Proof::to_bytes`) followed by the compressed R.
+    pub fn to_bytes(&self) -> [u8; Self::BYTES] {
+        let mut result = [0u8; Self::BYTES];
+        result[..Proof::BYTES].copy_from_slice(&self.proof.to_bytes());
+        result[Proof::BYTES..].copy_from_slice(&self.r.to_affine().to_compressed());
+        result
+    }
+
+    /// Convert a byte sequence of exactly `DesignatedProof::BYTES` bytes to a proof, as produced by `to_bytes`.
+    /// 
+    /// Returns `AccumulatorError::BadEncoding` if the proof is malformed as in `Proof::try_from_bytes`, 
+    /// or if R is not a valid compressed G2 point in the prime order subgroup.
+    pub fn try_from_bytes(input: &[u8]) -> Result<Self, AccumulatorError> {
+        if input.len() != Self::BYTES {
+            return Err(AccumulatorError::BadEncoding);
+        }
+        let proof = Proof::try_from_bytes(&input[..Proof::BYTES])?;
+        let r = <[u8; 96]>::try_from(&input[Proof::BYTES..]).map_err(|_| AccumulatorError::BadEncoding)?;
+        let r = Option::<G2Affine>::from(G2Affine::from_compressed(&r)).ok_or(AccumulatorError::BadEncoding)?;
+        Ok(Self { proof, r: G2Projective::from(r) })
+    }
+
+    /// Hash the shared key K to the point H(K) masking B_bar
+    fn mask(shared_key: G2Projective) -> G1Projective {
+        let mut data = b"Designated Mask".to_vec();
+        data.extend_from_slice(shared_key.to_bytes().as_ref());
+        hash_to_g1(data)
+    }
+
+    /// Add the shared key K to the transcript
+    fn add_shared_key_to_transcript(shared_key: G2Projective, transcript: &mut Transcript) {
+        transcript.append_message(b"Designated K", shared_key.to_bytes().as_ref());
+    }
+}
+
+
+/// A membership proof for at least one of several accumulators sharing the same public key,
+/// which does not reveal which one, widening the anonymity set to the union of their members.
+/// 
+/// Since the pairing equation e(A_bar, X_2) = e(B_bar, g_2) only depends on the public key, a single pair (A_bar, B_bar)
+/// is used, and B_bar = r*C_m_i - e*A_bar is proved for some i with an OR-composition of the Schnorr proofs:
+/// all branches but the real one are simulated, and their challenges c_i must sum to the transcript challenge.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnionProof {
+    a_bar: G1Projective,
+    b_bar: G1Projective,
+    /// Challenge and responses (c_i, s_i, t_i) of each accumulator
+    branches: Vec<(Scalar, Scalar, Scalar)>,
+}
+
+impl UnionProof {
+    const LABEL: &'static [u8; 11] = b"Union Proof";
+
+    /// Create a new membership proof in the union of the accumulators in `params`,
+    /// where the private parameters `params_priv` are valid for `params[index]`.
+    /// 
+    /// Returns an error if `index` is out of bounds or the accumulators do not share the same public key.
+    /// NOTE: the validity of `params_priv` is not checked, an invalid witness produces a proof that does not verify.
+    pub fn new(params: &[ProofParamsPublic], index: usize, params_priv: &ProofParamsPrivate) -> Result<Self, AccumulatorError> {
+        if index >= params.len() {
+            return Err(AccumulatorError::NotMember);
+        }
+        if params.iter().any(|pp| pp.x_2 != params[0].x_2 || pp.g_2 != params[0].g_2) {
+            return Err(AccumulatorError::PublicKeyMismatch);
+        }
+        let rng = rand_core::OsRng {};
+        let pc = ProofCommitting::new(&params[index], params_priv);
+
+        // Simulate all branches but the real one: U_i = s_i*C_m_i + t_i*A_bar - c_i*B_bar
+        let mut branches = Vec::with_capacity(params.len());
+        let mut u = Vec::with_capacity(params.len());
+        for (i, pp) in params.iter().enumerate() {
+            if i == index {
+                branches.push((Scalar::ZERO, Scalar::ZERO, Scalar::ZERO));
+                u.push(pc.u);
+            } else {
+                let (c, s, t) = (generate_fr(SALT, None, rng), generate_fr(SALT, None, rng), generate_fr(SALT, None, rng));
+                branches.push((c, s, t));
+                u.push(s * pp.c_m + t * pc.a_bar - c * pc.b_bar);
+            }
+        }
+
+        // The real challenge is c - ∑ c_i over the simulated branches
+        let challenge = Self::challenge(params, pc.a_bar, pc.b_bar, &u);
+        let c = branches.iter().fold(challenge, |c, (c_i, _, _)| c - c_i);
+        let real = pc.gen_proof(Element(c));
+        branches[index] = (c, real.s, real.t);
+
+        Ok(Self { a_bar: pc.a_bar, b_bar: pc.b_bar, branches })
+    }
+
+    /// Verifies this is a membership proof for at least one of the accumulators in `params`, in the same order used for proving.
+    pub fn verify(&self, params: &[ProofParamsPublic]) -> bool {
+        if params.is_empty() || params.len() != self.branches.len() || bool::from(self.a_bar.is_identity()) {
+            return false;
+        }
+        if params.iter().any(|pp| pp.x_2 != params[0].x_2 || pp.g_2 != params[0].g_2) {
+            return false;
+        }
+
+        // e(A_bar, X_2) - e(B_bar, g_2) = 0
+        let (a_bar, b_bar) = to_affine_pair(&self.a_bar, &self.b_bar);
+        if !pairing_check(&[(a_bar, params[0].x_2.to_affine()), (b_bar, -params[0].g_2.to_affine())]) {
+            return false;
+        }
+
+        // Reconstruct U_i = s_i*C_m_i + t_i*A_bar - c_i*B_bar and check ∑ c_i = c
+        let u: Vec<G1Projective> = params.iter().zip(&self.branches)
+            .map(|(pp, (c, s, t))| s * pp.c_m + t * self.a_bar - c * self.b_bar)
+            .collect();
+        let sum = self.branches.iter().fold(Scalar::ZERO, |sum, (c, _, _)| sum + c);
+        Self::challenge(params, self.a_bar, self.b_bar, &u) == sum
+    }
+
+    /// Absorbs all the public parameters, A_bar, B_bar and all commitments U_i, and derives the challenge
+    fn challenge(params: &[ProofParamsPublic], a_bar: G1Projective, b_bar: G1Projective, u: &[G1Projective]) -> Scalar {
+        let mut transcript = Transcript::new(Self::LABEL);
+        params.iter().for_each(|pp| pp.add_to_transcript(&mut transcript));
+        transcript.append_message(b"A_bar", a_bar.to_bytes().as_ref());
+        transcript.append_message(b"B_bar", b_bar.to_bytes().as_ref());
+        u.iter().for_each(|u| transcript.append_message(b"U", u.to_bytes().as_ref()));
+        Element::from_transcript(Self::LABEL, &mut transcript).0
+    }
+}
+
+/// Committing phase of a non-membership proof for a non-membership witness (C, d) of the element x.
+/// 
+/// Analogously to the membership proof, the holder reveals A_bar = r*C, D_bar = δ*P with δ = r*d and 
+/// B_bar = r*(V - d*P - x*C) = A_bar*α, and proves knowledge of (r, x, δ) such that 
+/// B_bar = r*V - x*A_bar - δ*P and D_bar = δ*P, as in the non-membership proof of <https://eprint.iacr.org/2020/777>.
+/// D_bar != 0 implies δ != 0, hence d != 0, i.e., the hidden witness is not a membership witness.
+/// 
+/// NOTE: the proof of knowledge of δ binds D_bar to the witness, since the prover does not know the discrete logarithm 
+/// of V with respect to the independent generator P; otherwise a member could absorb any multiple of V into D_bar.
+#[derive(Debug, Copy, Clone)]
+pub struct NonMembershipProofCommitting {
+    a_bar: G1Projective,
+    b_bar: G1Projective,
+    d_bar: G1Projective,
+    u: G1Projective,
+    u_d: G1Projective,
+    alpha: Scalar,
+    beta: Scalar,
+    gamma: Scalar,
+    r: Scalar,
+    x: Scalar,
+    delta: Scalar,
+}
+
+impl NonMembershipProofCommitting {
+    /// Create a new non-membership proof committing phase for the element `x` with non-membership witness `wit`.
+    pub fn new(params_pub: &ProofParamsPublic, x: Element, wit: &NonMembershipWitness) -> Self {
+        let rng = rand_core::OsRng {};
+        let (r, alpha, beta, gamma) = (generate_fr(SALT, None, rng), generate_fr(SALT, None, rng), generate_fr(SALT, None, rng), generate_fr(SALT, None, rng));
+
+        // A_bar = C*r, D_bar = δ*P with δ = d*r, B_bar = r*V - D_bar - x*A_bar
+        let a_bar = wit.c * r;
+        let delta = wit.d * r;
+        let d_bar = params_pub.g_1 * delta;
+        let b_bar = params_pub.c_m * r - d_bar - a_bar * x.0;
+
+        // U = alpha*V + beta*A_bar - gamma*P, U_d = gamma*P
+        let u_d = params_pub.g_1 * gamma;
+        let u = alpha * params_pub.c_m + beta * a_bar - u_d;
+        Self { a_bar, b_bar, d_bar, u, u_d, alpha, beta, gamma, r, x: x.0, delta }
+    }
+
+    /// Return bytes that need to be hashed for generating challenge.
+    /// A_bar || B_bar || D_bar || U || U_d
+    pub fn get_bytes_for_challenge(&self, transcript: &mut Transcript) {
+        transcript.append_message(b"A_bar", self.a_bar.to_bytes().as_ref());
+        transcript.append_message(b"B_bar", self.b_bar.to_bytes().as_ref());
+        transcript.append_message(b"D_bar", self.d_bar.to_bytes().as_ref());
+        transcript.append_message(b"U", self.u.to_bytes().as_ref());
+        transcript.append_message(b"U_d", self.u_d.to_bytes().as_ref());
+    }
+
+    pub fn gen_proof(&self, challenge_hash: Element) -> NonMembershipProof {
+        NonMembershipProof {
+            a_bar: self.a_bar,
+            b_bar: self.b_bar,
+            d_bar: self.d_bar,
+            // s = alpha + rc
+            s: schnorr(self.alpha, self.r, challenge_hash.0),
+            // t = beta - xc
+            t: schnorr(self.beta, -self.x, challenge_hash.0),
+            // w = gamma + δc
+            w: schnorr(self.gamma, self.delta, challenge_hash.0),
+            challenge_hash: challenge_hash.0,
+        }
+    }
+}
+
+/// A zero-knowledge proof that the prover knows an element x and a non-membership witness for x 
+/// against the accumulator in the public parameters, without revealing x (see `NonMembershipProofCommitting`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NonMembershipProof {
+    a_bar: G1Projective,
+    b_bar: G1Projective,
+    d_bar: G1Projective,
+    s: Scalar,
+    t: Scalar,
+    w: Scalar,
+    challenge_hash: Scalar,
+}
+
+impl NonMembershipProof {
+    const LABEL: &'static [u8] = b"Non-Membership Proof";
+
+    /// Creates a non-membership proof for the element `x` with non-membership witness `wit`, using the Fiat-Shamir transform.
+    pub fn new(params_pub: &ProofParamsPublic, x: Element, wit: &NonMembershipWitness) -> Self {
+        let mut transcript = Transcript::new(Self::LABEL);
+        params_pub.add_to_transcript(&mut transcript);
+        let pc = NonMembershipProofCommitting::new(params_pub, x, wit);
+        pc.get_bytes_for_challenge(&mut transcript);
+        pc.gen_proof(Element::from_transcript(Self::LABEL, &mut transcript))
+    }
+
+    /// Verifies this proof against the public parameters `params`.
+    pub fn verify(&self, params: &ProofParamsPublic) -> bool {
+        if bool::from(self.a_bar.is_identity() | self.d_bar.is_identity()) {
+            return false;
+        }
+
+        // e(A_bar, X_2) - e(B_bar, g_2) = 0
+        let valid_pairing = pairing_check(&[
+            (self.a_bar.to_affine(), params.x_2.to_affine()),
+            ((-self.b_bar).to_affine(), params.g_2.to_affine()),
+        ]);
+
+        // U = s*V + t*A_bar - w*P - c*B_bar, U_d = w*P - c*D_bar
+        let u_d = self.w * params.g_1 - self.challenge_hash * self.d_bar;
+        let u = self.s * params.c_m + self.t * self.a_bar - self.w * params.g_1 - self.challenge_hash * self.b_bar;
+        let pc = NonMembershipProofCommitting { 
+            a_bar: self.a_bar, b_bar: self.b_bar, d_bar: self.d_bar, u, u_d, 
+            alpha: Scalar::ZERO, beta: Scalar::ZERO, gamma: Scalar::ZERO, r: Scalar::ZERO, x: Scalar::ZERO, delta: Scalar::ZERO 
+        };
+        let mut transcript = Transcript::new(Self::LABEL);
+        params.add_to_transcript(&mut transcript);
+        pc.get_bytes_for_challenge(&mut transcript);
+        valid_pairing && Element::from_transcript(Self::LABEL, &mut transcript).0 == self.challenge_hash
+    }
+}
+
+/// Commitment to an ordered list of deleted elements, obtained by hashing them, which can be computed without any MSM.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeletionCommitment(pub Scalar);
+
+impl DeletionCommitment {
+    const LABEL: &'static [u8] = b"Deletion Commitment";
+
+    /// Computes the commitment to the list of deleted elements `deletions`.
+    pub fn new(deletions: &[Element]) -> Self {
+        let mut transcript = Transcript::new(Self::LABEL);
+        deletions.iter().for_each(|y| transcript.append_message(b"y_D", y.to_bytes().as_ref()));
+        Self(Element::from_transcript(Self::LABEL, &mut transcript).0)
+    }
+}
+
+/// Hiding commitment to a set of revoked elements, e.g., to publish each revocation batch in a transparency log 
+/// without disclosing the elements, computed as the hash of a random blinding and of the elements sorted by their encoding.
+/// 
+/// The issuer keeps the `RevokedSetOpening` returned by `commit`, which opens the commitment for audit.
+/// NOTE: unlike `DeletionCommitment`, the order of the elements does not matter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevokedSetCommitment(pub Scalar);
+
+/// Opening of a `RevokedSetCommitment`: the committed elements, sorted by their encoding, and the blinding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevokedSetOpening {
+    elements: Vec<Element>,
+    blinding: Scalar,
+}
+
+impl RevokedSetOpening {
+    /// Returns the committed elements, sorted by their encoding.
+    pub fn get_elements(&self) -> &[Element] {
+        &self.elements
+    }
+}
+
+impl RevokedSetCommitment {
+    const LABEL: &'static [u8] = b"Revoked Set Commitment";
+
+    /// Commits to the set of revoked elements `elements` with a fresh random blinding, returning the commitment and its opening.
+    pub fn commit(elements: &[Element]) -> (Self, RevokedSetOpening) {
+        let mut elements = elements.to_vec();
+        elements.sort_unstable_by_key(|y| y.to_bytes());
+        elements.dedup();
+        let opening = RevokedSetOpening { elements, blinding: Element::random().0 };
+        (Self::compute(&opening), opening)
+    }
+
+    /// Returns true if `opening` opens this commitment, i.e., the committed set is exactly `opening.get_elements()`.
+    pub fn verify(&self, opening: &RevokedSetOpening) -> bool {
+        opening.elements.windows(2).all(|w| w[0].to_bytes() < w[1].to_bytes()) && Self::compute(opening) == *self
+    }
+
+    fn compute(opening: &RevokedSetOpening) -> Self {
+        let mut transcript = Transcript::new(Self::LABEL);
+        transcript.append_message(b"Blinding", opening.blinding.to_be_bytes().as_ref());
+        opening.elements.iter().for_each(|y| transcript.append_message(b"y_D", y.to_bytes().as_ref()));
+        Self(Element::from_transcript(Self::LABEL, &mut transcript).0)
+    }
+}
+
+/// Proof that the accumulator V' derives from V by a batch deletion, i.e., V' = 1 / dD(𝛼) * V with dD(𝛼) = ∏ 1..m (yD_i + 𝛼),
+/// verifiable with 4 pairings independently of the number m of deletions.
+/// 
+/// The proof contains D~ = dD(𝛼)P~, checked with e(V', D~) = e(V, P~), and the BLS signature σ = 𝛼_T*H(V, V', D~, cD) 
+/// under the transition key 𝛼_T, checked with e(σ, P~) = e(H, 𝛼_T*P~), which binds D~ to the deletion commitment cD.
+/// NOTE: D~ cannot be recomputed from the deletions without the trapdoor, hence the issuer's signature is needed to bind them.
+/// The transition key is derived from the trapdoor with `SecretKey::derive(TransitionProof::KEY_DOMAIN)`, so that 𝛼 itself 
+/// never signs, and proofs verify under the public key returned by `TransitionProof::verification_key`, NOT the accumulator public key.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransitionProof {
+    d: G2Projective,
+    sig: G1Projective,
+}
+
+impl TransitionProof {
+    const LABEL: &'static [u8] = b"Transition Proof";
+    /// Domain of the transition key derived from the trapdoor (see `SecretKey::derive`).
+    pub const KEY_DOMAIN: &'static [u8] = b"Transition Signing Key";
+
+    /// Returns the public key 𝛼_T*P~ verifying the transition proofs created with the trapdoor `key`,
+    /// to be published by the issuer together with the accumulator public key.
+    pub fn verification_key(key: &SecretKey) -> PublicKey {
+        key.derive(Self::KEY_DOMAIN).public_key()
+    }
+
+    /// Using the trapdoor `key`, proves that `new` is obtained by deleting `deletions` from `old`,
+    /// signing with the transition key derived from `key`.
+    /// 
+    /// Returns `AccumulatorError::ProofSelfCheckFailed` if `new` does not derive from `old`, 
+    /// so that the issuer never signs an invalid transition.
+    pub fn new(key: &SecretKey, old: Accumulator, new: Accumulator, deletions: &[Element]) -> Result<Self, AccumulatorError> {
+        // V = dD(𝛼) * V'
+        let d = deletions.iter().fold(Scalar::ONE, |d, y| d * (y.0 + key.0));
+        if new.0 * d != old.0 {
+            return Err(AccumulatorError::ProofSelfCheckFailed);
+        }
+        let d = G2Projective::GENERATOR * d;
+        let h = Self::message(old, new, d, &DeletionCommitment::new(deletions));
+        Ok(Self { d, sig: h * key.derive(Self::KEY_DOMAIN).0 })
+    }
+
+    /// Verifies that `new` derives from `old` by deleting the elements committed in `commitment`, 
+    /// using only the issuer's transition public key `pubkey` (see `TransitionProof::verification_key`).
+    pub fn verify(&self, old: Accumulator, new: Accumulator, pubkey: PublicKey, commitment: &DeletionCommitment) -> bool {
+        if new.is_degenerate() || bool::from(self.d.is_identity()) {
+            return false;
+        }
+        let h = Self::message(old, new, self.d, commitment);
+        let g2 = -G2Affine::generator();
+
+        // e(V', D~) - e(V, P~) == 0_{G_t}
+        let transition = pairing_check(&[
+            (new.0.to_affine(), self.d.to_affine()),
+            (old.0.to_affine(), g2),
+        ]);
+        // e(H, Q~) - e(σ, P~) == 0_{G_t}
+        let signature = pairing_check(&[
+            (h.to_affine(), pubkey.0.to_affine()),
+            (self.sig.to_affine(), g2),
+        ]);
+        transition & signature
+    }
+
+    /// Hashes V, V', D~ and the deletion commitment cD to G1
+    fn message(old: Accumulator, new: Accumulator, d: G2Projective, commitment: &DeletionCommitment) -> G1Projective {
+        let mut transcript = Transcript::new(Self::LABEL);
+        transcript.append_message(b"V", old.to_bytes().as_ref());
+        transcript.append_message(b"V'", new.to_bytes().as_ref());
+        transcript.append_message(b"D~", d.to_bytes().as_ref());
+        transcript.append_message(b"cD", commitment.0.to_be_bytes().as_ref());
+        let mut okm = [0u8; 64];
+        transcript.challenge_bytes(Self::LABEL, &mut okm);
+        hash_to_g1(okm)
+    }
+}
+
+/// Verifies all the membership proofs `proofs` against the public parameters `params`.
+/// 
+/// The pairing checks e(A_bar_i, X_2) = e(B_bar_i, g_2) are folded in a single Miller loop
+/// using a random linear combination e(∑ r_i*A_bar_i, X_2) = e(∑ r_i*B_bar_i, g_2), 
+/// while challenges and the structure (see `Proof::validate_structure`) are checked individually.
+/// 
+/// An empty slice is vacuously valid and returns `Ok(())`, 
+/// while a single proof is verified individually without random linear combination.
+pub fn batch_verify(params: &ProofParamsPublic, proofs: &[Proof]) -> Result<(), AccumulatorError> {
+    let batch_error = AccumulatorError::BatchVerificationFailed;
+    match proofs {
+        [] => return Ok(()),
+        [proof] => {
+            let mut transcript = Transcript::new(PROOF_LABEL);
+            params.add_to_transcript(&mut transcript);
+            return match proof.finalize(params).verify(&mut transcript) {
+                true => Ok(()),
+                false => Err(batch_error),
+            };
+        }
+        _ => {}
+    }
+
+    // Identity A_bar_i and B_bar_i vanish from the random linear combination, hence are rejected individually
+    if proofs.iter().any(|proof| proof.validate_structure().is_err() || !proof.verify_challenge(params)) {
+        return Err(batch_error);
+    }
+    match pairing_batch(params, &proofs.iter().collect::<Vec<_>>()) {
+        true => Ok(()),
+        false => Err(batch_error),
+    }
+}
+
+/// Verifies all the membership proofs `proofs` against the public parameters `params`, returning the validity of each proof.
+/// 
+/// The pairing checks of the proofs with a valid challenge are folded with a random linear combination as in `batch_verify`.
+/// If the folded check fails, the proofs are split in halves which are checked recursively, 
+/// so that a few invalid proofs cost O(log K) additional Miller loops each. 
+pub fn batch_verify_each(params: &ProofParamsPublic, proofs: &[Proof]) -> Vec<bool> {
+    let mut valid: Vec<bool> = proofs.iter().map(|proof| proof.validate_structure().is_ok() && proof.verify_challenge(params)).collect();
+    let candidates: Vec<usize> = (0..proofs.len()).filter(|&i| valid[i]).collect();
+    bisect_pairings(params, proofs, &candidates, &mut valid);
+    valid
+}
+
+/// Marks as invalid the proofs in `indices` failing the pairing check, bisecting the failing batches
+fn bisect_pairings(params: &ProofParamsPublic, proofs: &[Proof], indices: &[usize], valid: &mut [bool]) {
+    if indices.is_empty() || pairing_batch(params, &indices.iter().map(|&i| &proofs[i]).collect::<Vec<_>>()) {
+        return;
+    }
+    if let [i] = indices {
+        valid[*i] = false;
+        return;
+    }
+    let (left, right) = indices.split_at(indices.len() / 2);
+    bisect_pairings(params, proofs, left, valid);
+    bisect_pairings(params, proofs, right, valid);
+}
+
+/// Checks e(∑ r_i*A_bar_i, X_2) = e(∑ r_i*B_bar_i, g_2) with r_i sampled from a CSPRNG, 
+/// so that invalid proofs cannot cancel out. A single proof is checked without random linear combination.
+/// 
+/// Fails if any A_bar_i or B_bar_i is the identity, which would vanish from the combination (see `Proof::validate_structure`).
+fn pairing_batch(params: &ProofParamsPublic, proofs: &[&Proof]) -> bool {
+    if params.get_accumulator().is_degenerate() || proofs.iter().any(|proof| proof.validate_structure().is_err()) {
+        return false;
+    }
+    let (a_bar, b_bar) = match proofs {
+        [proof] => (proof.a_bar, proof.b_bar),
+        _ => {
+            let rng = rand_core::OsRng {};
+            proofs.iter().fold((G1Projective::IDENTITY, G1Projective::IDENTITY), |(a_bar, b_bar), proof| {
+                // Randomly select r_i
+                let r = generate_fr(SALT, None, rng);
+                (a_bar + proof.a_bar * r, b_bar + proof.b_bar * r)
+            })
+        }
+    };
+
+    // e(∑ r_i*A_bar_i, X_2) - e(∑ r_i*B_bar_i, g_2) = 0
+    pairing_check(&[
+        (a_bar.to_affine(), params.x_2.to_affine()),
+        (b_bar.to_affine(), -params.g_2.to_affine()),
+    ])
+}
+
 pub fn schnorr(r: Scalar, v: Scalar, challenge: Scalar) -> Scalar {
     v * challenge + r
 }
@@ -296,12 +1283,84 @@ mod tests {
 
 
     use std::{convert::TryFrom, time::Instant};
+    use bls12_381_plus::{G1Affine, G1Projective, G2Affine, G2Projective, Scalar};
+    use group::{ff::Field, Group, GroupEncoding};
 
     use crate::{
-        accumulator::Element, proof::Proof, witness::Deletion, Accumulator, MembershipWitness, ProofCommitting, ProofParamsPrivate, ProofParamsPublic, PROOF_LABEL, SecretKey, PublicKey
+        accumulator::Element, proof::{batch_verify, batch_verify_each, DeletionCommitment, DesignatedProof, InequalityProof, MultiProof, NonMembershipProof, NonMembershipProofCommitting, ParamsDelta, Proof, RevokedSetCommitment, TransitionProof, UnionProof}, witness::{Deletion, NonMembershipWitness}, Accumulator, MembershipWitness, ProofCommitting, ProofParamsPrepared, ProofParamsPrivate, ProofParamsPublic, PROOF_LABEL, SecretKey, PublicKey, AccumulatorError
     };
 
     
+    #[test]
+    fn proof_params_private_zeroize_on_drop() {
+        // Place the private params in a controlled buffer
+        let (key, acc) = (SecretKey::new(None), Accumulator::random(rand_core::OsRng{}));
+        let y = Element::random();
+        let mut buf = core::mem::MaybeUninit::<ProofParamsPrivate>::uninit();
+        buf.write(ProofParamsPrivate::new(y, &MembershipWitness::new(&y, acc, &key).unwrap()));
+        assert_eq!(unsafe { buf.assume_init_ref() }.e, y.0);
+
+        // Drop the params in place and check the element is cleared and the witness reset to the identity
+        unsafe { core::ptr::drop_in_place(buf.as_mut_ptr()) };
+        let bytes: [u8; core::mem::size_of::<ProofParamsPrivate>()] = unsafe { core::mem::transmute_copy(&buf) };
+        let cleared = ProofParamsPrivate { a: G1Projective::IDENTITY, e: Scalar::ZERO };
+        let expected: [u8; core::mem::size_of::<ProofParamsPrivate>()] = unsafe { core::mem::transmute_copy(&cleared) };
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn proof_revoked_set_commitment() {
+        let elements: Vec<Element> = (0..5).map(|_| Element::random()).collect();
+        let (commitment, opening) = RevokedSetCommitment::commit(&elements);
+        assert!(commitment.verify(&opening));
+        assert_eq!(opening.get_elements().len(), elements.len());
+        assert!(elements.iter().all(|y| opening.get_elements().contains(y)));
+
+        // Hiding: committing again to the same set gives a different commitment, which does not open with the first opening
+        let reversed: Vec<Element> = elements.iter().rev().copied().collect();
+        let (other, other_opening) = RevokedSetCommitment::commit(&reversed);
+        assert_ne!(other, commitment);
+        assert!(!other.verify(&opening));
+        assert_eq!(other_opening.get_elements(), opening.get_elements());
+
+        // Binding: openings to a different or unsorted set are rejected
+        let mut tampered = opening.clone();
+        tampered.elements.pop();
+        assert!(!commitment.verify(&tampered));
+        let mut unsorted = opening.clone();
+        unsorted.elements.swap(0, 1);
+        assert!(!commitment.verify(&unsorted));
+    }
+
+    #[test]
+    fn proof_params_validate() {
+        let key = SecretKey::new(None);
+        let pk = PublicKey::from(&key);
+        let acc = Accumulator::random(rand_core::OsRng {});
+        ProofParamsPublic::new(&acc, &pk).validate().expect("Valid params rejected");
+
+        // Tampered public key: identity, or a point on the curve outside the prime-order subgroup
+        let mut params = ProofParamsPublic::new(&acc, &pk);
+        params.x_2 = G2Projective::IDENTITY;
+        assert_eq!(params.validate(), Err(AccumulatorError::InvalidParams));
+        let mut bytes = [0u8; 96];
+        bytes[0] = 0x80;
+        let outside = (1..=u8::MAX).find_map(|i| {
+            bytes[95] = i;
+            Option::<G2Affine>::from(G2Affine::from_compressed_unchecked(&bytes)).filter(|p| !bool::from(p.is_torsion_free()))
+        }).expect("No point outside the subgroup");
+        params.x_2 = G2Projective::from(outside);
+        assert_eq!(params.validate(), Err(AccumulatorError::InvalidParams));
+
+        // Identity accumulator and non-standard generator
+        let mut params = ProofParamsPublic::new(&acc, &pk);
+        params.c_m = G1Projective::IDENTITY;
+        assert_eq!(params.validate(), Err(AccumulatorError::DegenerateAccumulator));
+        let mut params = ProofParamsPublic::new(&acc, &pk);
+        params.g_1 = G1Projective::GENERATOR.double();
+        assert_eq!(params.validate(), Err(AccumulatorError::InvalidParams));
+    }
+
     #[test]
     fn proof_test_fiat_shamir_succeed() {
         // Get public parameters 
@@ -311,7 +1370,7 @@ mod tests {
         
         // Generate witness and private params for accumulated element
         let id = Element::hash(b"test");
-        let wit = MembershipWitness::new(&id, acc, &key);
+        let wit = MembershipWitness::new(&id, acc, &key).unwrap();
         let params_priv = ProofParamsPrivate::new(id, &wit);
 
         // Add params to transcript
@@ -339,6 +1398,93 @@ mod tests {
         println!("Time to verify non-revocation proof: {:?}", t2);
     }
     
+    #[test]
+    fn proof_test_deterministic_blindings() {
+        // Get public parameters 
+        let (acc, key) = (Accumulator::random(rand_core::OsRng{}), SecretKey::new(None));
+        let params_pub = ProofParamsPublic::new(&acc, &PublicKey::from(&key));
+        
+        // Generate witness and private params for accumulated element
+        let id = Element::hash(b"test");
+        let wit = MembershipWitness::new(&id, acc, &key).unwrap();
+        let params_priv = ProofParamsPrivate::new(id, &wit);
+
+        let prove = |pc: ProofCommitting| {
+            let mut transcript = merlin::Transcript::new(PROOF_LABEL);
+            params_pub.add_to_transcript(&mut transcript);
+            pc.get_bytes_for_challenge(&mut transcript);
+            pc.gen_proof(Element::from_transcript(PROOF_LABEL, &mut transcript))
+        };
+
+        // Same seed reproduces the same proof, and both verify
+        let proof1 = prove(ProofCommitting::new_deterministic(&params_pub, &params_priv, b"audit nonce"));
+        let proof2 = prove(ProofCommitting::new_deterministic(&params_pub, &params_priv, b"audit nonce"));
+        assert_eq!(proof1.to_bytes(), proof2.to_bytes());
+        assert!(batch_verify(&params_pub, &[proof1, proof2]).is_ok());
+
+        // Different seeds or inputs diverge
+        let proof3 = prove(ProofCommitting::new_deterministic(&params_pub, &params_priv, b"other nonce"));
+        assert_ne!(proof1.to_bytes(), proof3.to_bytes());
+        assert!(batch_verify(&params_pub, &[proof3]).is_ok());
+        let other_id = Element::hash(b"other");
+        let other_priv = ProofParamsPrivate::new(other_id, &MembershipWitness::new(&other_id, acc, &key).unwrap());
+        let proof4 = prove(ProofCommitting::new_deterministic(&params_pub, &other_priv, b"audit nonce"));
+        assert_ne!(proof1.to_bytes(), proof4.to_bytes());
+    }
+
+    #[test]
+    fn proof_test_non_membership() {
+        // Get public parameters after revoking x
+        let (mut acc, key) = (Accumulator::random(rand_core::OsRng{}), SecretKey::new(None));
+        let pub_key = PublicKey::from(&key);
+        let x = Element::hash(b"revoked");
+        let mem_wit = MembershipWitness::new(&x, acc, &key).unwrap();
+        acc.remove_assign(&key, x);
+        let params_pub = ProofParamsPublic::new(&acc, &pub_key);
+
+        // Prove non-membership of the revoked element
+        let wit = NonMembershipWitness::new(&x, acc, &key).expect("Cannot create non-membership witness");
+        let t = Instant::now();
+        let proof = NonMembershipProof::new(&params_pub, x, &wit);
+        let t1 = t.elapsed();
+        let t = Instant::now();
+        assert!(proof.verify(&params_pub));
+        println!("Time to compute non-membership proof: {:?}, to verify it: {:?}", t1, t.elapsed());
+
+        // Check the proof fails with the wrong element, an old accumulator, or a membership witness
+        assert!(!NonMembershipProof::new(&params_pub, Element::hash(b"other"), &wit).verify(&params_pub));
+        assert!(!proof.verify(&ProofParamsPublic::new(&acc.remove(&key, Element::hash(b"other")), &pub_key)));
+        let fake = NonMembershipWitness { c: mem_wit.0, d: Scalar::ZERO };
+        assert!(!NonMembershipProof::new(&params_pub, x, &fake).verify(&params_pub));
+    }
+
+    // A member cannot turn its membership witness into a non-membership proof
+    #[test]
+    fn proof_test_non_membership_forgery() {
+        let (acc, key) = (Accumulator::random(rand_core::OsRng{}), SecretKey::new(None));
+        let params_pub = ProofParamsPublic::new(&acc, &PublicKey::from(&key));
+        let x = Element::hash(b"member");
+        let mem_wit = MembershipWitness::new(&x, acc, &key).unwrap();
+
+        // A_bar = r*C, B_bar = r*V - x*A_bar = A_bar*α, D_bar = (r' - r)*V, then prove knowledge of (r', x, δ) 
+        // for each guess of δ, which is the discrete logarithm of D_bar with respect to P
+        let (r, r_prime) = (Scalar::random(rand_core::OsRng{}), Scalar::random(rand_core::OsRng{}));
+        let a_bar = mem_wit.0 * r;
+        let b_bar = params_pub.c_m * r - a_bar * x.0;
+        let d_bar = params_pub.c_m * (r_prime - r);
+        for delta in [Scalar::ZERO, Scalar::ONE, r_prime - r] {
+            let (alpha, beta, gamma) = (Scalar::random(rand_core::OsRng{}), Scalar::random(rand_core::OsRng{}), Scalar::random(rand_core::OsRng{}));
+            let u_d = params_pub.g_1 * gamma;
+            let u = alpha * params_pub.c_m + beta * a_bar - u_d;
+            let pc = NonMembershipProofCommitting { a_bar, b_bar, d_bar, u, u_d, alpha, beta, gamma, r: r_prime, x: x.0, delta };
+            let mut transcript = merlin::Transcript::new(NonMembershipProof::LABEL);
+            params_pub.add_to_transcript(&mut transcript);
+            pc.get_bytes_for_challenge(&mut transcript);
+            let proof = pc.gen_proof(Element::from_transcript(NonMembershipProof::LABEL, &mut transcript));
+            assert!(!proof.verify(&params_pub));
+        }
+    }
+
     #[test]
     fn proof_fiat_shamir_fail() {
         // Get public parameters 
@@ -348,7 +1494,7 @@ mod tests {
         
         // Generate witness and private params for accumulated element
         let id = Element::hash(b"test");
-        let wit = MembershipWitness::new(&id, acc, &key);
+        let wit = MembershipWitness::new(&id, acc, &key).unwrap();
         let params_priv = ProofParamsPrivate::new(id, &wit);
 
         // Add params to transcript
@@ -380,7 +1526,219 @@ mod tests {
         println!("Time to verify non-revocation proof: {:?}", t2);
     }
 
+
+    #[test]
+    fn proof_test_prepared() {
+        // Get public parameters 
+        let (acc, key) = (Accumulator::random(rand_core::OsRng{}), SecretKey::new(None));
+        let pub_key = PublicKey::from(&key);       
+        let params_pub = ProofParamsPublic::new(&acc, &pub_key);
+        let params_prep = ProofParamsPrepared::new(params_pub);
+        
+        // Create non-interactive proof using fiat-shamir transform
+        let id = Element::hash(b"test");
+        let wit = MembershipWitness::new(&id, acc, &key).unwrap();
+        let params_priv = ProofParamsPrivate::new(id, &wit);
+        let mut transcript = merlin::Transcript::new(PROOF_LABEL);
+        params_pub.add_to_transcript(&mut transcript);
+        let pc = ProofCommitting::new(&params_pub, &params_priv);
+        pc.get_bytes_for_challenge(&mut transcript);
+        let proof = pc.gen_proof(Element::from_transcript(PROOF_LABEL, &mut transcript));
+
+        // Verify proof with prepared params
+        let t = Instant::now();
+        let mut transcript_ver = merlin::Transcript::new(PROOF_LABEL);
+        params_pub.add_to_transcript(&mut transcript_ver);
+        assert!(proof.finalize_prepared(&params_prep).verify(&mut transcript_ver));
+        println!("Time to verify non-revocation proof with prepared params: {:?}", t.elapsed());
+
+        // Check verification fails against prepared params of another public key
+        let other_prep = ProofParamsPrepared::new(ProofParamsPublic::new(&acc, &PublicKey::from(&SecretKey::new(None))));
+        let mut transcript_ver = merlin::Transcript::new(PROOF_LABEL);
+        params_pub.add_to_transcript(&mut transcript_ver);
+        assert!(!proof.finalize_prepared(&other_prep).verify(&mut transcript_ver));
+    }
+
+    // Create a membership proof for element `id` with witness `wit` 
+    fn prove(params_pub: &ProofParamsPublic, id: Element, wit: &MembershipWitness) -> Proof {
+        let params_priv = ProofParamsPrivate::new(id, wit);
+        let mut transcript = merlin::Transcript::new(PROOF_LABEL);
+        params_pub.add_to_transcript(&mut transcript);
+        let pc = ProofCommitting::new(params_pub, &params_priv);
+        pc.get_bytes_for_challenge(&mut transcript);
+        pc.gen_proof(Element::from_transcript(PROOF_LABEL, &mut transcript))
+    }
+
+    // Create a proof needing no witness: A_bar = B_bar = 0, U = s*V, with a valid challenge
+    fn forge_identity_proof(params_pub: &ProofParamsPublic) -> Proof {
+        let (s, t) = (Scalar::random(rand_core::OsRng{}), Scalar::random(rand_core::OsRng{}));
+        let mut transcript = merlin::Transcript::new(PROOF_LABEL);
+        params_pub.add_to_transcript(&mut transcript);
+        transcript.append_message(b"A_bar", G1Projective::IDENTITY.to_bytes().as_ref());
+        transcript.append_message(b"B_bar", G1Projective::IDENTITY.to_bytes().as_ref());
+        transcript.append_message(b"U", (s * params_pub.c_m).to_bytes().as_ref());
+        let challenge_hash = Element::from_transcript(PROOF_LABEL, &mut transcript).0;
+        Proof { a_bar: G1Projective::IDENTITY, b_bar: G1Projective::IDENTITY, s, t, challenge_hash }
+    }
+
+    #[test]
+    fn proof_test_identity_forgery() {
+        let (acc, key) = (Accumulator::random(rand_core::OsRng{}), SecretKey::new(None));
+        let params_pub = ProofParamsPublic::new(&acc, &PublicKey::from(&key));
+        let forged = forge_identity_proof(&params_pub);
+
+        // The forged proof has a valid challenge and pairing equation, but is rejected on every path
+        assert!(forged.verify_challenge(&params_pub));
+        assert!(bool::from(forged.finalize(&params_pub).pair_final.is_identity()));
+        assert_eq!(forged.validate_structure(), Err(AccumulatorError::BadEncoding));
+        let mut transcript = merlin::Transcript::new(PROOF_LABEL);
+        params_pub.add_to_transcript(&mut transcript);
+        assert!(!forged.finalize(&params_pub).verify(&mut transcript));
+        let mut transcript = merlin::Transcript::new(PROOF_LABEL);
+        params_pub.add_to_transcript(&mut transcript);
+        assert!(!forged.finalize_prepared(&ProofParamsPrepared::new(params_pub)).verify(&mut transcript));
+    }
+
+    #[test]
+    fn proof_test_designated() {
+        let (acc, key) = (Accumulator::random(rand_core::OsRng{}), SecretKey::new(None));
+        let params_pub = ProofParamsPublic::new(&acc, &PublicKey::from(&key));
+        let id = Element::hash(b"test");
+        let wit = MembershipWitness::new(&id, acc, &key).unwrap();
+        let (designated_sk, other_sk) = (SecretKey::new(None), SecretKey::new(None));
+        let proof = DesignatedProof::new(&params_pub, &ProofParamsPrivate::new(id, &wit), &PublicKey::from(&designated_sk));
+
+        // Check only the designated verifier accepts the proof
+        assert!(proof.verify(&params_pub, &designated_sk));
+        assert!(!proof.verify(&params_pub, &other_sk));
+
+        // Check the pairing equation of the transmitted (A_bar, B_bar) does not hold without unmasking B_bar
+        assert!(!bool::from(proof.proof.finalize(&params_pub).pair_final.is_identity()));
+        let mut unmasked = proof.proof;
+        unmasked.b_bar -= DesignatedProof::mask(proof.r * designated_sk.0);
+        assert!(bool::from(unmasked.finalize(&params_pub).pair_final.is_identity()));
+
+        // Check the proof round-trips through bytes and serde
+        let bytes = proof.to_bytes();
+        assert_eq!(DesignatedProof::try_from_bytes(&bytes), Ok(proof));
+        assert!(DesignatedProof::try_from_bytes(&bytes[1..]).is_err());
+        let mut bad = bytes;
+        bad[Proof::BYTES..].fill(0);
+        assert!(DesignatedProof::try_from_bytes(&bad).is_err());
+        let decoded: DesignatedProof = bincode::deserialize(&bincode::serialize(&proof).unwrap()).unwrap();
+        assert!(decoded.verify(&params_pub, &designated_sk));
+    }
+
+    #[test]
+    fn proof_test_batch_verify() {
+        const BATCH_SIZE: usize = 10;
+
+        // Get public parameters 
+        let (mut acc, key) = (Accumulator::random(rand_core::OsRng{}), SecretKey::new(None));
+        let pub_key = PublicKey::from(&key);       
+        let params_pub = ProofParamsPublic::new(&acc, &pub_key);
+
+        // Create proofs for BATCH_SIZE accumulated elements
+        let ids: Vec<Element> = (0..BATCH_SIZE).map(|i| Element::hash(format!("test {i}").as_bytes())).collect();
+        let wits: Vec<MembershipWitness> = ids.iter().map(|id| MembershipWitness::new(id, acc, &key).unwrap()).collect();
+        let proofs: Vec<Proof> = ids.iter().zip(&wits).map(|(id, wit)| prove(&params_pub, *id, wit)).collect();
+
+        // Check empty, single and full batches verify
+        assert!(batch_verify(&params_pub, &[]).is_ok());
+        assert!(batch_verify(&params_pub, &proofs[..1]).is_ok());
+        let t = Instant::now();
+        assert!(batch_verify(&params_pub, &proofs).is_ok());
+        println!("Time to batch verify {BATCH_SIZE} non-revocation proofs: {:?}", t.elapsed());
+
+        // Check a batch containing a proof needing no witness fails
+        let forged = [&proofs[..], &[forge_identity_proof(&params_pub)]].concat();
+        assert_eq!(batch_verify(&params_pub, &forged), Err(AccumulatorError::BatchVerificationFailed));
+
+        // Revoke first element and check single and full batches with its outdated witness fail
+        acc.remove_assign(&key, ids[0]);
+        let params_pub = ProofParamsPublic::new(&acc, &pub_key);
+        let mut proofs: Vec<Proof> = ids[1..].iter().map(|id| prove(&params_pub, *id, &MembershipWitness::new(id, acc, &key).unwrap())).collect();
+        let invalid = prove(&params_pub, ids[0], &wits[0]);
+        assert!(batch_verify(&params_pub, &proofs).is_ok());
+        assert!(batch_verify(&params_pub, &[invalid]).is_err());
+        proofs.push(invalid);
+        assert!(batch_verify(&params_pub, &proofs).is_err());
+
+        // Check per-proof validity only flags the outdated witness
+        let valid = batch_verify_each(&params_pub, &proofs);
+        assert_eq!(valid.iter().filter(|v| **v).count(), BATCH_SIZE - 1);
+        assert!(!valid[BATCH_SIZE - 1]);
+
+        // Check per-proof validity flags a proof needing no witness
+        proofs.push(forge_identity_proof(&params_pub));
+        let valid = batch_verify_each(&params_pub, &proofs);
+        assert_eq!(valid.iter().filter(|v| **v).count(), BATCH_SIZE - 1);
+        assert!(!valid[BATCH_SIZE]);
+    }
     
+    #[test]
+    fn proof_test_degenerate_accumulator() {
+        // Force an identity accumulator, for which the identity witness satisfies the pairing equation of any element
+        let (acc, key) = (Accumulator(G1Projective::IDENTITY), SecretKey::new(None));
+        let (pubkey, id) = (PublicKey::from(&key), Element::hash(b"test"));
+        let params_pub = ProofParamsPublic::new(&acc, &pubkey);
+        let wit = MembershipWitness::new(&id, acc, &key).unwrap();
+        assert!(bool::from(wit.0.is_identity()));
+
+        // Check all witness verifications fail
+        assert!(!wit.verify(id, pubkey, acc));
+        assert!(!crate::witness::PreparedWitnessVerifier::new(pubkey).verify(&wit, id, acc));
+        assert_eq!(MembershipWitness::batch_verify(&[(id, wit), (id, wit)], pubkey, acc), vec![0, 1]);
+        assert_eq!(wit.verify_timeline(id, pubkey, &[(0, acc)]), vec![(0, false)]);
+
+        // Check all proof verifications fail
+        let mut transcript = merlin::Transcript::new(PROOF_LABEL);
+        params_pub.add_to_transcript(&mut transcript);
+        let pc = ProofCommitting::new(&params_pub, &ProofParamsPrivate::new(id, &wit));
+        pc.get_bytes_for_challenge(&mut transcript);
+        let proof = pc.gen_proof(Element::from_transcript(PROOF_LABEL, &mut transcript));
+        let mut transcript = merlin::Transcript::new(PROOF_LABEL);
+        params_pub.add_to_transcript(&mut transcript);
+        assert!(!proof.finalize(&params_pub).verify(&mut transcript.clone()));
+        assert!(!proof.finalize_prepared(&ProofParamsPrepared::new(params_pub)).verify(&mut transcript));
+        assert_eq!(batch_verify(&params_pub, &[proof]), Err(AccumulatorError::BatchVerificationFailed));
+        assert_eq!(batch_verify(&params_pub, &[proof, proof]), Err(AccumulatorError::BatchVerificationFailed));
+        assert_eq!(batch_verify_each(&params_pub, &[proof, proof]), vec![false, false]);
+    }
+
+    #[test]
+    fn proof_test_not_equal() {
+        // Get public parameters and blocklist
+        let (acc, key) = (Accumulator::random(rand_core::OsRng{}), SecretKey::new(None));
+        let params_pub = ProofParamsPublic::new(&acc, &PublicKey::from(&key));
+        let blocklist: Vec<Element> = (0..3).map(|i| Element::hash(format!("blocked {i}").as_bytes())).collect();
+        
+        // Create membership proof followed by inequality proofs on the same transcript
+        let id = Element::hash(b"test");
+        let params_priv = ProofParamsPrivate::new(id, &MembershipWitness::new(&id, acc, &key).unwrap());
+        let mut transcript = merlin::Transcript::new(PROOF_LABEL);
+        params_pub.add_to_transcript(&mut transcript);
+        let pc = ProofCommitting::new(&params_pub, &params_priv);
+        pc.get_bytes_for_challenge(&mut transcript);
+        let proof = pc.gen_proof(Element::from_transcript(PROOF_LABEL, &mut transcript));
+        let ineq: Vec<InequalityProof> = blocklist.iter().map(|b| id.prove_not_equal(*b, &params_pub, &pc, &mut transcript).unwrap()).collect();
+
+        // Check inequality proofs survive serialization
+        let bytes = bincode::serialize(&ineq).expect("Serialization error!");
+        assert_eq!(bincode::deserialize::<Vec<InequalityProof>>(&bytes).expect("Deserialization error"), ineq);
+
+        // Check membership and inequality proofs verify on the same transcript
+        let mut transcript = merlin::Transcript::new(PROOF_LABEL);
+        params_pub.add_to_transcript(&mut transcript);
+        assert!(proof.finalize(&params_pub).verify(&mut transcript));
+        let mut check = transcript.clone();
+        assert!(ineq.iter().zip(&blocklist).all(|(p, b)| p.verify(&proof, &params_pub, *b, &mut check)));
+        
+        // Check inequality proof does not verify for another value and cannot be created for the holder's own element
+        assert!(!ineq[0].verify(&proof, &params_pub, blocklist[1], &mut transcript.clone()));
+        assert!(id.prove_not_equal(id, &params_pub, &pc, &mut transcript).is_err());
+    }
+
     #[test]
     fn proof_test_serialize(){
         // Get public parameters 
@@ -390,7 +1748,7 @@ mod tests {
          
         // Generate witness and private params for accumulated element
         let id = Element::hash(b"test");
-        let wit = MembershipWitness::new(&id, acc, &key);
+        let wit = MembershipWitness::new(&id, acc, &key).unwrap();
         let params_priv = ProofParamsPrivate::new(id, &wit);
  
         // Add params to transcript
@@ -409,6 +1767,300 @@ mod tests {
         assert!(proof == proof_from);        
         println!("Membership proof: {} bytes", proof_bytes.len());
 
+        // Versioned encoding
+        let versioned = proof.to_versioned_bytes();
+        assert_eq!(versioned[0], crate::FORMAT_VERSION);
+        assert_eq!(Proof::from_versioned_bytes(&versioned), Ok(proof));
+        let mut old = versioned;
+        old[0] = 0;
+        assert_eq!(Proof::from_versioned_bytes(&old), Err(AccumulatorError::IncompatibleVersion(0)));
+        assert_eq!(Proof::from_versioned_bytes(&versioned[..Proof::BYTES]), Err(AccumulatorError::BadEncoding));
+
+    }
+
+    #[test]
+    fn proof_test_compact_encoding(){
+        // Get public parameters and create proof for accumulated element
+        let (acc, key) = (Accumulator::random(rand_core::OsRng{}), SecretKey::new(None));
+        let params_pub = ProofParamsPublic::new(&acc, &PublicKey::from(&key));
+        let id = Element::hash(b"test");
+        let proof = prove(&params_pub, id, &MembershipWitness::new(&id, acc, &key).unwrap());
+
+        // Check both forms have the documented length and verify
+        let compact = proof.to_bytes_with(&params_pub, true);
+        let full = proof.to_bytes_with(&params_pub, false);
+        assert_eq!(compact.len(), Proof::SERIALIZED_LEN);
+        assert_eq!(compact, proof.to_bytes());
+        assert_eq!(full.len(), Proof::byte_len(false));
+        for (bytes, is_compact) in [(&compact, true), (&full, false)] {
+            let parsed = Proof::try_from_bytes_with(bytes, &params_pub, is_compact).expect("Cannot parse proof");
+            assert_eq!(parsed, proof);
+            let mut transcript = merlin::Transcript::new(PROOF_LABEL);
+            params_pub.add_to_transcript(&mut transcript);
+            assert!(parsed.finalize(&params_pub).verify(&mut transcript));
+        }
+
+        // Check a full form with an inconsistent commitment or of the wrong length is rejected
+        let mut invalid = full.clone();
+        invalid[96..144].copy_from_slice(&G1Affine::generator().to_compressed());
+        assert_eq!(Proof::try_from_bytes_with(&invalid, &params_pub, false), Err(AccumulatorError::BadEncoding));
+        assert_eq!(Proof::try_from_bytes_with(&compact, &params_pub, false), Err(AccumulatorError::BadEncoding));
+        assert_eq!(Proof::try_from_bytes_with(&full, &params_pub, true), Err(AccumulatorError::BadEncoding));
+    }
+
+    #[test]
+    fn proof_test_try_from_bytes(){
+        // Get public parameters and create proof for accumulated element
+        let (acc, key) = (Accumulator::random(rand_core::OsRng{}), SecretKey::new(None));
+        let params_pub = ProofParamsPublic::new(&acc, &PublicKey::from(&key));
+        let id = Element::hash(b"test");
+        let proof = prove(&params_pub, id, &MembershipWitness::new(&id, acc, &key).unwrap());
+
+        // Check round trip and serde still work
+        let bytes = proof.to_bytes();
+        let parsed = Proof::try_from_bytes(&bytes).expect("Cannot parse proof");
+        assert_eq!(parsed, proof);
+        assert_eq!(bincode::deserialize::<Proof>(&bincode::serialize(&proof).unwrap()).unwrap(), proof);
+        
+        // Check deserialized proof verifies
+        let mut transcript = merlin::Transcript::new(PROOF_LABEL);
+        params_pub.add_to_transcript(&mut transcript);
+        assert!(parsed.finalize(&params_pub).verify(&mut transcript));
+
+        // Check malformed inputs are rejected
+        assert!(Proof::try_from_bytes(&bytes[1..]).is_err());
+        assert!(Proof::try_from_bytes(&[bytes.as_slice(), &[0u8]].concat()).is_err());
+        let mut invalid = bytes;
+        invalid[..48].copy_from_slice(&[0xffu8; 48]);
+        assert!(Proof::try_from_bytes(&invalid).is_err());
     }
 
+    #[test]
+    fn proof_test_multi(){
+        // Setup accumulator with three elements, the third one is then deleted
+        let (mut acc, key) = (Accumulator::random(rand_core::OsRng{}), SecretKey::new(None));
+        let elems: Vec<Element> = (0..3).map(|_| Element::random()).collect();
+        let mut wits: Vec<MembershipWitness> = elems.iter().map(|y| MembershipWitness::new(y, acc, &key).unwrap()).collect();
+        let old_params = ProofParamsPublic::new(&acc, &PublicKey::from(&key));
+        let old_wit = wits[1];
+        let coefficients = acc.update_assign(&key, &elems[2..]);
+        wits.iter_mut().take(2).zip(&elems).for_each(|(w, y)| w.batch_update_assign(*y, &elems[2..], &coefficients).unwrap());
+        let params = ProofParamsPublic::new(&acc, &PublicKey::from(&key));
+
+        let prove = |params: &ProofParamsPublic, members: &[(Element, MembershipWitness)]| {
+            let mut transcript = merlin::Transcript::new(PROOF_LABEL);
+            params.add_to_transcript(&mut transcript);
+            let pc = ProofCommitting::new_multi(params, members).expect("Cannot create commitments");
+            pc.get_bytes_for_challenge(&mut transcript);
+            pc.gen_proof(Element::from_transcript(PROOF_LABEL, &mut transcript))
+        };
+        let verify = |params: &ProofParamsPublic, proof: &MultiProof| {
+            let mut transcript = merlin::Transcript::new(PROOF_LABEL);
+            params.add_to_transcript(&mut transcript);
+            proof.verify(params, &mut transcript)
+        };
+
+        // Two elements proved at once
+        let members = [(elems[0], wits[0]), (elems[1], wits[1])];
+        let proof = prove(&params, &members);
+        assert_eq!(proof.len(), 2);
+        assert!(verify(&params, &proof));
+        assert!(!verify(&old_params, &proof));
+        let roundtrip: MultiProof = bincode::deserialize(&bincode::serialize(&proof).unwrap()).unwrap();
+        assert!(verify(&params, &roundtrip));
+
+        // A stale witness or a deleted element makes the whole proof fail
+        assert!(!verify(&params, &prove(&params, &[(elems[0], wits[0]), (elems[1], old_wit)])));
+        assert!(!verify(&params, &prove(&params, &[(elems[0], wits[0]), (elems[2], wits[2])])));
+
+        // Dropping or swapping members breaks the challenge
+        let mut dropped = proof.clone();
+        dropped.members.pop();
+        assert!(!verify(&params, &dropped));
+        let mut swapped = proof.clone();
+        swapped.members.swap(0, 1);
+        assert!(!verify(&params, &swapped));
+
+        // Empty inputs
+        assert_eq!(ProofCommitting::new_multi(&params, &[]).unwrap_err(), AccumulatorError::EmptyInput);
+        assert!(!verify(&params, &MultiProof { members: Vec::new(), challenge_hash: proof.challenge_hash }));
+    }
+
+    #[test]
+    fn proof_test_serde_json_hex(){
+        let hex = |bytes: &[u8]| format!("\"{}\"", bytes.iter().map(|b| format!("{:02x}", b)).collect::<String>());
+
+        // Get public parameters and create proof for accumulated element
+        let (acc, key) = (Accumulator::random(rand_core::OsRng{}), SecretKey::new(None));
+        let pk = PublicKey::from(&key);
+        let params_pub = ProofParamsPublic::new(&acc, &pk);
+        let id = Element::hash(b"test");
+        let wit = MembershipWitness::new(&id, acc, &key).unwrap();
+        let proof = prove(&params_pub, id, &wit);
+
+        // Points are compressed hex strings
+        let (wit_json, acc_json, pk_json) = (serde_json::to_string(&wit).unwrap(), serde_json::to_string(&acc).unwrap(), serde_json::to_string(&pk).unwrap());
+        assert_eq!(wit_json, hex(&wit.to_bytes()));
+        assert_eq!(acc_json, hex(acc.0.to_bytes().as_ref()));
+        assert_eq!(pk_json, hex(&pk.to_bytes()));
+
+        // Proof fields are hex strings, with big-endian scalars as in `Proof::to_bytes`,
+        // concatenated in a single string with `serde_human`
+        let bytes = proof.to_bytes();
+        let proof_json = serde_json::to_string(&proof).unwrap();
+        #[cfg(not(feature = "serde_human"))]
+        assert_eq!(proof_json, format!(
+            "{{\"a_bar\":{},\"b_bar\":{},\"s\":{},\"t\":{},\"challenge_hash\":{}}}",
+            hex(&bytes[..48]), hex(&bytes[48..96]), hex(&bytes[96..128]), hex(&bytes[128..160]), hex(&bytes[160..])
+        ));
+        #[cfg(feature = "serde_human")]
+        assert_eq!(proof_json, hex(&bytes));
+
+        // Parsed values verify
+        let wit: MembershipWitness = serde_json::from_str(&wit_json).unwrap();
+        let acc: Accumulator = serde_json::from_str(&acc_json).unwrap();
+        let pk: PublicKey = serde_json::from_str(&pk_json).unwrap();
+        let parsed: Proof = serde_json::from_str(&proof_json).unwrap();
+        assert_eq!(parsed, proof);
+        assert!(wit.verify(id, pk, acc));
+        let params_pub = ProofParamsPublic::new(&acc, &pk);
+        let mut transcript = merlin::Transcript::new(PROOF_LABEL);
+        params_pub.add_to_transcript(&mut transcript);
+        assert!(parsed.finalize(&params_pub).verify(&mut transcript));
+
+        // Malformed points are rejected
+        assert!(serde_json::from_str::<MembershipWitness>(&hex(&[0xffu8; 48])).is_err());
+        assert!(serde_json::from_str::<MembershipWitness>(&hex(&wit.to_bytes()[1..])).is_err());
+
+        // Binary formats keep the derived encoding
+        let bin = bincode::serialize(&(wit, acc, pk, proof)).unwrap();
+        let fields = (proof.a_bar, proof.b_bar, proof.s, proof.t, proof.challenge_hash);
+        assert_eq!(bin, bincode::serialize(&(wit.0, acc.0, pk.0, fields)).unwrap());
+        assert_eq!(bincode::deserialize::<(MembershipWitness, Accumulator, PublicKey, Proof)>(&bin).unwrap(), (wit, acc, pk, proof));
+    }
+
+    #[test]
+    fn proof_test_serialize_transcript(){
+        // Get public parameters and create proof for accumulated element
+        let (acc, key) = (Accumulator::random(rand_core::OsRng{}), SecretKey::new(None));
+        let params_pub = ProofParamsPublic::new(&acc, &PublicKey::from(&key));
+        let id = Element::hash(b"test");
+        let params_priv = ProofParamsPrivate::new(id, &MembershipWitness::new(&id, acc, &key).unwrap());
+        let mut transcript = merlin::Transcript::new(PROOF_LABEL);
+        params_pub.add_to_transcript(&mut transcript);
+        let pc = ProofCommitting::new(&params_pub, &params_priv);
+        pc.get_bytes_for_challenge(&mut transcript);
+        let proof_bytes = pc.gen_proof(Element::from_transcript(PROOF_LABEL, &mut transcript)).to_bytes();
+
+        // Check serialized points match the transcript encoding and order
+        assert_eq!(&proof_bytes[..48], pc.a_bar.to_bytes().as_ref());
+        assert_eq!(&proof_bytes[48..96], pc.b_bar.to_bytes().as_ref());
+
+        // Check deserialized proof verifies after re-running the transcript
+        let proof = Proof::from_bytes(&proof_bytes).unwrap();
+        let mut transcript = merlin::Transcript::new(PROOF_LABEL);
+        params_pub.add_to_transcript(&mut transcript);
+        assert!(proof.finalize(&params_pub).verify(&mut transcript));
+    }
+
+    #[test]
+    fn proof_test_params_delta(){
+        // Get public parameters before and after a revocation
+        let (mut acc, key) = (Accumulator::random(rand_core::OsRng{}), SecretKey::new(None));
+        let prev = ProofParamsPublic::new(&acc, &PublicKey::from(&key));
+        acc.remove_assign(&key, Element::hash(b"test"));
+        let new = ProofParamsPublic::new(&acc, &PublicKey::from(&key));
+
+        // Check only the accumulator is transmitted
+        let delta = new.delta_from(&prev).unwrap();
+        assert_eq!(delta, ParamsDelta { fingerprint: prev.fingerprint(), c_m: Some(new.c_m) });
+        assert!(new.delta_from(&new).unwrap().is_empty());
+        let bytes = bincode::serialize(&delta).unwrap();
+        assert!(bytes.len() < bincode::serialize(&new).unwrap().len());
+
+        // Check applying the delta reconstructs the new params
+        let mut params = prev;
+        params.apply_delta(&bincode::deserialize(&bytes).unwrap()).unwrap();
+        assert_eq!(params, new);
+
+        // Check a rotated public key is rejected on both ends
+        let rotated = ProofParamsPublic::new(&acc, &PublicKey::from(&SecretKey::new(None)));
+        assert_eq!(rotated.delta_from(&prev), Err(AccumulatorError::PublicKeyMismatch));
+        let mut params = rotated;
+        assert_eq!(params.apply_delta(&delta), Err(AccumulatorError::PublicKeyMismatch));
+        assert_eq!(params, rotated);
+    }
+
+    #[test]
+    fn proof_test_params_fingerprint(){
+        // Get public parameters before and after a revocation
+        let (mut acc, key) = (Accumulator::random(rand_core::OsRng{}), SecretKey::new(None));
+        let prev = ProofParamsPublic::new(&acc, &PublicKey::from(&key));
+        acc.remove_assign(&key, Element::hash(b"test"));
+        let new = ProofParamsPublic::new(&acc, &PublicKey::from(&key));
+
+        // Check the fingerprint is stable across accumulator updates
+        assert_ne!(prev, new);
+        assert_eq!(prev.fingerprint(), new.fingerprint());
+
+        // Check the fingerprint changes on key rotation
+        let rotated = ProofParamsPublic::new(&acc, &PublicKey::from(&SecretKey::new(None)));
+        assert_ne!(rotated.fingerprint(), new.fingerprint());
+    }
+
+    #[test]
+    fn proof_test_union(){
+        // Get public parameters of two accumulators sharing the same key
+        let (acc, key) = (Accumulator::random(rand_core::OsRng{}), SecretKey::new(None));
+        let pk = PublicKey::from(&key);
+        let params = [ProofParamsPublic::new(&Accumulator::random(rand_core::OsRng{}), &pk), ProofParamsPublic::new(&acc, &pk)];
+        let id = Element::hash(b"test");
+        let params_priv = ProofParamsPrivate::new(id, &MembershipWitness::new(&id, acc, &key).unwrap());
+
+        // Check proof verifies and tampered challenges are rejected
+        let proof = UnionProof::new(&params, 1, &params_priv).expect("Cannot create union proof");
+        assert!(proof.verify(&params));
+        let mut tampered = proof.clone();
+        tampered.branches[0].0 += bls12_381_plus::Scalar::ONE;
+        assert!(!tampered.verify(&params));
+
+        // Check a proof for the wrong branch does not verify
+        assert!(!UnionProof::new(&params, 0, &params_priv).expect("Cannot create union proof").verify(&params));
+    }
+
+    #[test]
+    fn proof_test_transition(){
+        // Batch delete some elements
+        let (mut acc, key) = (Accumulator::random(rand_core::OsRng{}), SecretKey::new(None));
+        let pk = TransitionProof::verification_key(&key);
+        let deletions: Vec<Element> = (0..10).map(|_| Element::random()).collect();
+        let old = acc;
+        acc.update_assign(&key, &deletions);
+        let commitment = DeletionCommitment::new(&deletions);
+
+        // Check the transition proof verifies
+        let t = Instant::now();
+        let proof = TransitionProof::new(&key, old, acc, &deletions).expect("Cannot create transition proof");
+        println!("Time to create transition proof: {:?}", t.elapsed());
+        let t = Instant::now();
+        assert!(proof.verify(old, acc, pk, &commitment));
+        println!("Time to verify transition proof: {:?}", t.elapsed());
+
+        // Check the issuer refuses to prove a forged transition
+        let forged = Accumulator::random(rand_core::OsRng{});
+        assert!(TransitionProof::new(&key, old, forged, &deletions).is_err());
+        assert!(TransitionProof::new(&key, old, acc, &deletions[1..]).is_err());
+
+        // Check a forged new accumulator, a forged D~ for it, or a different commitment are rejected
+        assert!(!proof.verify(old, forged, pk, &commitment));
+        let t = Element::random().0;
+        let forged = Accumulator::from(old.0 * t.invert().unwrap());
+        let tampered = TransitionProof { d: bls12_381_plus::G2Projective::GENERATOR * t, sig: proof.sig };
+        assert!(!tampered.verify(old, forged, pk, &commitment));
+        assert!(!proof.verify(old, acc, pk, &DeletionCommitment::new(&deletions[1..])));
+        assert!(!proof.verify(old, acc, PublicKey::from(&SecretKey::new(None)), &commitment));
+
+        // Check the trapdoor itself does not sign, i.e., the proof does not verify under the accumulator public key
+        assert!(!proof.verify(old, acc, PublicKey::from(&key), &commitment));
+    }
 }
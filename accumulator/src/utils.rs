@@ -1,14 +1,23 @@
-use std::usize;
+use alloc::{vec, vec::Vec};
 use ark_ff::Zero;
-use bls12_381_plus::{elliptic_curve::hash2curve::ExpandMsgXof, G1Projective, Scalar};
+use bls12_381_plus::{elliptic_curve::hash2curve::ExpandMsgXof, G1Affine, G1Projective, G2Affine, G2Prepared, Gt, Scalar};
 use digest::{ExtendableOutput, Update, XofReader};
-use group::ff::{Field, PrimeField};
+use group::{ff::{Field, PrimeField}, Group};
 use rand_core::{CryptoRng, RngCore};
 use sha3::Shake256;
+use subtle::Choice;
+use crate::AccumulatorError;
 
 /// Similar to https://tools.ietf.org/html/draft-irtf-cfrg-bls-signature-04#section-2.3
 /// info is left blank
-pub fn generate_fr(salt: &[u8], ikm: Option<&[u8]>, mut rng: impl RngCore + CryptoRng) -> Scalar {
+/// 
+/// Panics if `rng` fails, see `try_generate_fr`.
+pub fn generate_fr(salt: &[u8], ikm: Option<&[u8]>, rng: impl RngCore + CryptoRng) -> Scalar {
+    try_generate_fr(salt, ikm, rng).expect("random number generator failed")
+}
+
+/// Same as `generate_fr`, but returns `AccumulatorError::RngFailure` if `rng` fails instead of panicking.
+pub fn try_generate_fr(salt: &[u8], ikm: Option<&[u8]>, mut rng: impl RngCore + CryptoRng) -> Result<Scalar, AccumulatorError> {
     let mut hasher = Shake256::default();
     match ikm {
         Some(v) => {
@@ -18,14 +27,14 @@ pub fn generate_fr(salt: &[u8], ikm: Option<&[u8]>, mut rng: impl RngCore + Cryp
         None => {
             hasher.update(salt);
             let mut arr = [0u8; 32];
-            rng.fill_bytes(&mut arr);
+            rng.try_fill_bytes(&mut arr).map_err(|_| AccumulatorError::RngFailure)?;
             hasher.update(&arr);
         }
     };
     let mut okm = [0u8; 64];
     let mut xof = hasher.finalize_xof();
     xof.read(&mut okm);
-    Scalar::from_bytes_wide(&okm)
+    Ok(Scalar::from_bytes_wide(&okm))
 }
 
 pub fn hash_to_g1<I: AsRef<[u8]>>(data: I) -> G1Projective {
@@ -39,7 +48,71 @@ pub fn hash_to_g1<I: AsRef<[u8]>>(data: I) -> G1Projective {
 /// Accumulator = ACC
 pub const SALT: &[u8] = b"KB-VB-ACC-HASH-SALT-";
 
-/// A Polynomial for Points
+/// Version of the persistence formats returned by `to_versioned_bytes`, stored as their first byte.
+pub const FORMAT_VERSION: u8 = 1;
+
+/// Format versions accepted by `from_versioned_bytes`.
+pub const SUPPORTED_FORMAT_VERSIONS: &[u8] = &[FORMAT_VERSION];
+
+/// Checks the version byte of the versioned payload `bytes` and returns the remaining bytes.
+pub(crate) fn strip_format_version(bytes: &[u8]) -> Result<&[u8], AccumulatorError> {
+    let (version, rest) = bytes.split_first().ok_or(AccumulatorError::BadEncoding)?;
+    if !SUPPORTED_FORMAT_VERSIONS.contains(version) {
+        return Err(AccumulatorError::IncompatibleVersion(*version));
+    }
+    Ok(rest)
+}
+
+/// Decodes the hex string `hex` of exactly `N` bytes, accepting both lowercase and uppercase digits.
+pub(crate) fn decode_hex<const N: usize>(hex: &str) -> Result<[u8; N], AccumulatorError> {
+    let hex = hex.as_bytes();
+    if hex.len() != 2 * N {
+        return Err(AccumulatorError::BadEncoding);
+    }
+    let digit = |c: u8| (c as char).to_digit(16).ok_or(AccumulatorError::BadEncoding);
+    let mut bytes = [0u8; N];
+    for (b, pair) in bytes.iter_mut().zip(hex.chunks_exact(2)) {
+        *b = (digit(pair[0])? * 16 + digit(pair[1])?) as u8;
+    }
+    Ok(bytes)
+}
+
+/// Returns the lowercase hex string of `bytes`.
+#[cfg(feature = "serde_human")]
+pub(crate) fn encode_hex(bytes: &[u8]) -> alloc::string::String {
+    bytes.iter().map(|b| alloc::format!("{:02x}", b)).collect()
+}
+
+/// Serializes a value as the hex string of its byte encoding `bytes` in human-readable formats (e.g., `serde_json`),
+/// and as `binary` otherwise, i.e., as the derived implementation (e.g., `bincode`).
+#[cfg(feature = "serde_human")]
+pub(crate) fn serialize_human<S: serde::Serializer, B: serde::Serialize>(bytes: &[u8], binary: &B, serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&encode_hex(bytes))
+    } else {
+        binary.serialize(serializer)
+    }
+}
+
+/// Deserializes a value serialized with `serialize_human`, decoding the hex string of `N` bytes with `from_bytes`
+/// in human-readable formats, and the derived encoding `B` with `from_binary` otherwise.
+#[cfg(feature = "serde_human")]
+pub(crate) fn deserialize_human<'de, D, B, T, const N: usize>(
+    deserializer: D,
+    from_bytes: impl FnOnce(&[u8; N]) -> Result<T, AccumulatorError>,
+    from_binary: impl FnOnce(B) -> T,
+) -> Result<T, D::Error> where D: serde::Deserializer<'de>, B: serde::Deserialize<'de> {
+    use serde::{de::Error, Deserialize};
+    if deserializer.is_human_readable() {
+        let hex = alloc::string::String::deserialize(deserializer)?;
+        decode_hex::<N>(&hex).and_then(|bytes| from_bytes(&bytes)).map_err(D::Error::custom)
+    } else {
+        B::deserialize(deserializer).map(from_binary)
+    }
+}
+
+/// A Polynomial for Points, e.g., the batch update polynomial \omega(x) with the `Coefficient`s of an update,
+/// which holders evaluate in their element with `msm` to update their witness (see `MembershipWitness::batch_update_assign`).
 #[derive(Default, Clone, Debug)]
 pub struct PolynomialG1(pub Vec<G1Projective>);
 
@@ -76,23 +149,10 @@ impl PolynomialG1 {
         Some(res)
     }
 
-    // Get best window size according to ark-ec implementation
-    fn get_window_size(&self) -> usize{
-        match self.0.len(){
-            size if size>=32 => (usize::ilog2(size) * 69 / 100) as usize + 2,
-            _ => 3
-        } 
-    }
-    
     /// Returns all the powers of 'x` needed for evaluation of the poly
     /// e.g., 1, x, x^2, ..., x^d
     fn compute_powers_for_eval(&self, x: &Scalar) -> Vec<Scalar>{
-        let mut ret = Vec::with_capacity(self.0.len());
-        ret.push(Scalar::ONE);
-        for i in 1..self.0.len(){
-            ret.push(x*ret[i-1])
-        }
-        ret
+        powers(x, self.0.len())
     }
 
 
@@ -120,85 +180,262 @@ impl PolynomialG1 {
 
         // Compute 1,x,...,x^d
         let scalars = self.compute_powers_for_eval(x);
-        
-        // Get an iterator with coefficients and scalars pairs (c_1, 1), (c_2,x), ..., (c_d, x^d) 
-        let scalars_and_coeff_iter = scalars.iter().zip(self.0.clone());
-        let c = self.get_window_size();
-
-        // Get indexes of msm windows
-        let num_bits = Scalar::BYTES*8 as usize;
-        let window_starts: Vec<_> = (0..num_bits).step_by(c).collect();
-        let zero = G1Projective::IDENTITY;
-        
-        // Each window is of size `c`.
-        // We divide up the bits 0..num_bits into windows of size `c`, and
-        // process each such window.
-        let window_sums: Vec<_> = window_starts.into_iter()
-            .map(|w_start| {
-                let mut res = zero;
-                // We don't need the "zero" bucket, so we only have 2^c - 1 buckets.
-                let mut buckets = vec![zero; (1 << c) - 1];
-                scalars_and_coeff_iter.clone().for_each(|(&scalar, base)| {                    
-
-                    let mut scalar = scalar.clone();
-                    // Extract the `c` bits correspondig to our window:
-                    // Right-shift by w_start to remove the lower bits
-                    shr_assign(&mut scalar, w_start);
-                    // Apply mod 2^{window size} to the result to remove the higher bits
-                    apply_modulo2(&mut scalar, c);
-
-                    // The remaining extracted bits form our index in the bucket
-                    let index = scalar_to_usize(&scalar);
-                    
-                    // If the scalar is non-zero, we update the corresponding
-                    // bucket.
-                    if index != 0 {
-                        buckets[index - 1] += base;
-                    }
-                    
-                });
-
-                // Compute sum_{i in 0..num_buckets} (sum_{j in i..num_buckets} bucket[j])
-                // This is computed below for b buckets, using 2b curve additions.
-                //
-                // We could first normalize `buckets` and then use mixed-addition
-                // here, but that's slower for the kinds of groups we care about
-                // (Short Weierstrass curves and Twisted Edwards curves).
-                // In the case of Short Weierstrass curves,
-                // mixed addition saves ~4 field multiplications per addition.
-                // However normalization (with the inversion batched) takes ~6
-                // field multiplications per element,
-                // hence batch normalization is a slowdown.
-
-                // `running_sum` = sum_{j in i..num_buckets} bucket[j],
-                // where we iterate backward from i = num_buckets to 0.
-                let mut running_sum = G1Projective::IDENTITY;
-                buckets.into_iter().rev().for_each(|b| {
-                    running_sum += &b;
-                    res += &running_sum;
-                });
-                res
-            })
-            .collect();
+        self.msm_with_powers(&scalars)
+    }
 
-        // We store the sum for the lowest window.
-        let lowest = *window_sums.first().unwrap();
-        
-        // We're traversing windows from high to low.
-        Some(lowest
-            + &window_sums[1..]
-                .iter()
-                .rev()
-                .fold(zero, |mut total, sum_i| {
-                    total += sum_i;
-                    for _ in 0..c {
-                        total = total.double();
-                    }
-                    total
-                }))
+    /// Same as `msm`, but uses the precomputed powers 1, x, ..., x^d' of the evaluation point `x`, with d' >= d 
+    /// (e.g., cached in an `ElementPowers`). If the polynomial is empty or not enough powers are given return `None`.
+    pub fn msm_with_powers(&self, powers: &[Scalar]) -> Option<G1Projective> {
+        if self.0.is_empty() || powers.len() < self.0.len() {
+            return None;
+        }
+        let scalars = &powers[..self.0.len()];
+
+        #[cfg(feature = "parallel")]
+        let res = msm_parallel(&self.0, scalars);
+        #[cfg(not(feature = "parallel"))]
+        let res = msm_serial(&self.0, scalars);
+        Some(res)
     }
 }
 
+/// Returns whether e(P_1, Q_1) + ... + e(P_n, Q_n) is the identity of G_t, i.e., checks a pairing equation moved to one side.
+/// 
+/// By default, the pairings share a single Miller loop and final exponentiation (`multi_miller_loop`).
+/// With the `pairing-fallback` feature, each pairing is instead evaluated on its own with `bls12_381_plus::pairing`
+/// and the results are added in G_t: this is slower, but avoids the multi-Miller-loop code path on targets where it misbehaves.
+/// Both backends give the same result on the same inputs.
+/// NOTE: the fallback is NOT an independent pairing implementation, both backends use the pairing of `bls12_381_plus`.
+pub fn pairing_check(terms: &[(G1Affine, G2Affine)]) -> bool {
+    bool::from(pairing_sum(terms).is_identity())
+}
+
+/// Returns e(P_1, Q_1) + ... + e(P_n, Q_n), with the backend selected as for `pairing_check`.
+pub fn pairing_sum(terms: &[(G1Affine, G2Affine)]) -> Gt {
+    #[cfg(feature = "pairing-fallback")]
+    let res = pairing_sum_separate(terms);
+    #[cfg(not(feature = "pairing-fallback"))]
+    let res = pairing_sum_multi_miller(terms);
+    res
+}
+
+/// A G2 point prepared once for the repeated pairings of `pairing_sum_prepared`: the Miller loop precomputation by default,
+/// or the affine point with the `pairing-fallback` feature, whose separate pairings do not use the precomputation.
+#[cfg(not(feature = "pairing-fallback"))]
+pub(crate) type PreparedG2 = G2Prepared;
+#[cfg(feature = "pairing-fallback")]
+pub(crate) type PreparedG2 = G2Affine;
+
+/// Same as `pairing_sum`, with the G2 points prepared with `PreparedG2::from`.
+pub(crate) fn pairing_sum_prepared(terms: &[(G1Affine, &PreparedG2)]) -> Gt {
+    #[cfg(feature = "pairing-fallback")]
+    let res = terms.iter().fold(Gt::IDENTITY, |acc, (p, q)| acc + bls12_381_plus::pairing(p, q));
+    #[cfg(not(feature = "pairing-fallback"))]
+    let res = bls12_381_plus::multi_miller_loop(&terms.iter().map(|(p, q)| (p, *q)).collect::<Vec<_>>()).final_exponentiation();
+    res
+}
+
+/// Same as `pairing_check`, with the G2 points prepared with `PreparedG2::from`.
+pub(crate) fn pairing_check_prepared(terms: &[(G1Affine, &PreparedG2)]) -> bool {
+    bool::from(pairing_sum_prepared(terms).is_identity())
+}
+
+/// Computes the sum of the pairings with a single Miller loop and final exponentiation.
+#[cfg_attr(feature = "pairing-fallback", allow(dead_code))]
+fn pairing_sum_multi_miller(terms: &[(G1Affine, G2Affine)]) -> Gt {
+    let prepared: Vec<_> = terms.iter().map(|(p, q)| (p, G2Prepared::from(*q))).collect();
+    let refs: Vec<_> = prepared.iter().map(|(p, q)| (*p, q)).collect();
+    bls12_381_plus::multi_miller_loop(&refs).final_exponentiation()
+}
+
+/// Computes the sum of the pairings one pairing at a time.
+#[cfg_attr(not(feature = "pairing-fallback"), allow(dead_code))]
+fn pairing_sum_separate(terms: &[(G1Affine, G2Affine)]) -> Gt {
+    terms.iter().fold(Gt::IDENTITY, |acc, (p, q)| acc + bls12_381_plus::pairing(p, q))
+}
+
+/// Returns the `n` powers 1, x, ..., x^(n-1), e.g., to evaluate a polynomial of degree n-1 at `x` with a multi-scalar multiplication.
+/// 
+/// With the `parallel` feature, the powers are computed as a segmented prefix product with rayon for large `n`,
+/// giving the same powers as the serial prefix product.
+pub fn powers(x: &Scalar, n: usize) -> Vec<Scalar> {
+    #[cfg(feature = "parallel")]
+    if n >= PARALLEL_POWERS_THRESHOLD {
+        return powers_parallel(x, n);
+    }
+    powers_serial(x, n)
+}
+
+/// Minimum number of powers computed in parallel, below which the serial prefix product is faster.
+#[cfg(feature = "parallel")]
+const PARALLEL_POWERS_THRESHOLD: usize = 1 << 12;
+
+/// Computes 1, x, ..., x^(n-1) as a serial prefix product.
+fn powers_serial(x: &Scalar, n: usize) -> Vec<Scalar> {
+    let mut ret = Vec::with_capacity(n);
+    let mut acc = Scalar::ONE;
+    for _ in 0..n {
+        ret.push(acc);
+        acc *= x;
+    }
+    ret
+}
+
+/// Computes 1, x, ..., x^(n-1) split in one segment of `s` consecutive powers per thread:
+/// the first power x^(k*s) of segment k is obtained by repeated multiplication by x^s,
+/// then each segment is filled in parallel by multiplying its first power by x.
+#[cfg(feature = "parallel")]
+fn powers_parallel(x: &Scalar, n: usize) -> Vec<Scalar> {
+    use rayon::prelude::*;
+    let chunk_size = n.div_ceil(rayon::current_num_threads()).max(1);
+    let step = x.pow_vartime(&[chunk_size as u64, 0, 0, 0]);
+
+    let mut ret = vec![Scalar::ZERO; n];
+    ret.par_chunks_mut(chunk_size)
+        .enumerate()
+        .for_each(|(k, chunk)| {
+            let mut acc = step.pow_vartime(&[k as u64, 0, 0, 0]);
+            for p in chunk.iter_mut() {
+                *p = acc;
+                acc *= x;
+            }
+        });
+    ret
+}
+
+// Get best window size according to ark-ec implementation
+fn get_window_size(size: usize) -> usize{
+    match size{
+        size if size>=32 => (usize::ilog2(size) * 69 / 100) as usize + 2,
+        _ => 3
+    } 
+}
+
+/// Multi-scalar multiplication ∑ scalars_i*bases_i, split in chunks computed in parallel with rayon.
+/// 
+/// The partial sums of the chunks are added together, so the result is identical to `msm_serial`.
+#[cfg(feature = "parallel")]
+fn msm_parallel(bases: &[G1Projective], scalars: &[Scalar]) -> G1Projective {
+    use rayon::prelude::*;
+    let chunk_size = bases.len().div_ceil(rayon::current_num_threads()).max(1);
+    bases.par_chunks(chunk_size)
+        .zip(scalars.par_chunks(chunk_size))
+        .map(|(bases, scalars)| msm_serial(bases, scalars))
+        .reduce(|| G1Projective::IDENTITY, |a, b| a + b)
+}
+
+/// Optimized implementation of multi-scalar multiplication ∑ scalars_i*bases_i adapted from ark-ec library. 
+fn msm_serial(bases: &[G1Projective], scalars: &[Scalar]) -> G1Projective {
+    
+    // Get an iterator with coefficients and scalars pairs (c_1, 1), (c_2,x), ..., (c_d, x^d) 
+    let scalars_and_coeff_iter = scalars.iter().zip(bases.iter().copied());
+    let c = get_window_size(bases.len());
+
+    // Get indexes of msm windows
+    let num_bits = Scalar::BYTES * 8;
+    let window_starts: Vec<_> = (0..num_bits).step_by(c).collect();
+    let zero = G1Projective::IDENTITY;
+    
+    // Each window is of size `c`.
+    // We divide up the bits 0..num_bits into windows of size `c`, and
+    // process each such window.
+    let window_sums: Vec<_> = window_starts.into_iter()
+        .map(|w_start| {
+            let mut res = zero;
+            // We don't need the "zero" bucket, so we only have 2^c - 1 buckets.
+            let mut buckets = vec![zero; (1 << c) - 1];
+            scalars_and_coeff_iter.clone().for_each(|(&scalar, base)| {                    
+
+                let mut scalar = scalar;
+                // Extract the `c` bits correspondig to our window:
+                // Right-shift by w_start to remove the lower bits
+                shr_assign(&mut scalar, w_start);
+                // Apply mod 2^{window size} to the result to remove the higher bits
+                apply_modulo2(&mut scalar, c);
+
+                // The remaining extracted bits form our index in the bucket
+                let index = scalar_to_usize(&scalar);
+                
+                // If the scalar is non-zero, we update the corresponding
+                // bucket.
+                if index != 0 {
+                    buckets[index - 1] += base;
+                }
+                
+            });
+
+            // Compute sum_{i in 0..num_buckets} (sum_{j in i..num_buckets} bucket[j])
+            // This is computed below for b buckets, using 2b curve additions.
+            //
+            // We could first normalize `buckets` and then use mixed-addition
+            // here, but that's slower for the kinds of groups we care about
+            // (Short Weierstrass curves and Twisted Edwards curves).
+            // In the case of Short Weierstrass curves,
+            // mixed addition saves ~4 field multiplications per addition.
+            // However normalization (with the inversion batched) takes ~6
+            // field multiplications per element,
+            // hence batch normalization is a slowdown.
+
+            // `running_sum` = sum_{j in i..num_buckets} bucket[j],
+            // where we iterate backward from i = num_buckets to 0.
+            let mut running_sum = G1Projective::IDENTITY;
+            buckets.into_iter().rev().for_each(|b| {
+                running_sum += &b;
+                res += &running_sum;
+            });
+            res
+        })
+        .collect();
+
+    // We store the sum for the lowest window.
+    let lowest = *window_sums.first().unwrap();
+    
+    // We're traversing windows from high to low.
+    lowest
+        + window_sums[1..]
+            .iter()
+            .rev()
+            .fold(zero, |mut total, sum_i| {
+                total += sum_i;
+                for _ in 0..c {
+                    total = total.double();
+                }
+                total
+            })
+}
+
+/// Inverts in place all the `values` with a single field inversion, using Montgomery's trick.
+/// 
+/// Returns whether all the values are invertible, without branching on the position of a zero value:
+/// if some value is zero, all the values are set to zero.
+pub fn batch_invert(values: &mut [Scalar]) -> Choice {
+    // Prefix products v_1*...*v_(i-1)
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut prod = Scalar::ONE;
+    for v in values.iter() {
+        prefix.push(prod);
+        prod *= v;
+    }
+
+    // Walk back from (v_1*...*v_n)^-1
+    let inv = prod.invert();
+    let invertible = inv.is_some();
+    let mut inv = inv.unwrap_or(Scalar::ZERO);
+    for (v, p) in values.iter_mut().zip(prefix).rev() {
+        let t = inv * p;
+        inv *= *v;
+        *v = t;
+    }
+    invertible
+}
+
+/// Converts the points `a` and `b` to affine form with a single field inversion.
+pub(crate) fn to_affine_pair(a: &G1Projective, b: &G1Projective) -> (G1Affine, G1Affine) {
+    let mut affine = [G1Affine::identity(); 2];
+    G1Projective::batch_normalize(&[*a, *b], &mut affine);
+    (affine[0], affine[1])
+}
+
 /// Given a point P and a vector of coefficients [coeff_1, ..., coeff_n] 
 /// efficiently compute the vector [coeff_1*P...coeff_n*P]
 pub fn window_mul(point: G1Projective, coefficients: Vec<Scalar>)-> Vec<G1Projective>{
@@ -343,6 +580,23 @@ impl core::ops::AddAssign for PolynomialG1 {
     }
 }
 
+/// Sums the coefficients position-wise, the shorter polynomial being padded with the identity.
+impl core::ops::Add for PolynomialG1 {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+/// Sums the polynomials position-wise, the empty polynomial being the sum of no polynomial.
+impl core::iter::Sum for PolynomialG1 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, p| acc + p)
+    }
+}
+
 impl core::ops::MulAssign<Scalar> for PolynomialG1 {
     fn mul_assign(&mut self, rhs: Scalar) {
         for i in 0..self.0.len() {
@@ -589,4 +843,131 @@ mod tests {
         
     }
 
+    #[test]
+    fn utils_test_poly_g1_sum(){
+        let random_poly = |d: usize| PolynomialG1((0..d).map(|_| G1Projective::random(rand_core::OsRng{})).collect());
+        let (p1, p2, p3) = (random_poly(5), random_poly(8), random_poly(3));
+        let x = Scalar::random(rand_core::OsRng{});
+
+        // Sum of polynomials of different lengths evaluates to the sum of the evaluations
+        let sum = p1.clone() + p2.clone();
+        assert_eq!(sum.0.len(), 8);
+        assert_eq!(sum.evaluate(&x).unwrap(), p1.evaluate(&x).unwrap() + p2.evaluate(&x).unwrap());
+        let sum = p2.clone() + p1.clone();
+        assert_eq!(sum.evaluate(&x).unwrap(), p1.evaluate(&x).unwrap() + p2.evaluate(&x).unwrap());
+
+        // Same with a sum over an iterator
+        let expected = p1.evaluate(&x).unwrap() + p2.evaluate(&x).unwrap() + p3.evaluate(&x).unwrap();
+        let sum: PolynomialG1 = [p1, p2, p3].into_iter().sum();
+        assert_eq!(sum.msm(&x).unwrap(), expected);
+        assert!(core::iter::empty::<PolynomialG1>().sum::<PolynomialG1>().0.is_empty());
+    }
+
+    #[test]
+    fn utils_test_powers(){
+        let x = Scalar::random(rand_core::OsRng{});
+        assert!(powers(&x, 0).is_empty());
+        assert_eq!(powers(&x, 1), vec![Scalar::ONE]);
+        let p = powers(&x, 100);
+        assert!((0..100).all(|i| p[i] == x.pow_vartime(&[i as u64, 0, 0, 0])));
+    }
+
+    #[test]
+    fn utils_test_batch_invert(){
+        let values: Vec<Scalar> = (0..100).map(|_| Scalar::random(rand_core::OsRng{})).collect();
+        let mut inverses = values.clone();
+        assert!(bool::from(batch_invert(&mut inverses)));
+        assert!(values.iter().zip(&inverses).all(|(v, i)| *i == v.invert().unwrap()));
+        assert!(bool::from(batch_invert(&mut [])));
+
+        // A zero value zeroes all the outputs
+        let mut inverses = values.clone();
+        inverses[42] = Scalar::ZERO;
+        assert!(!bool::from(batch_invert(&mut inverses)));
+        assert!(inverses.iter().all(|i| bool::from(i.is_zero())));
+    }
+
+    #[test]
+    fn utils_test_pairing_backends(){
+        use crate::{accumulator::Element, Accumulator, MembershipWitness, SecretKey, PublicKey};
+        use bls12_381_plus::G2Projective;
+        use group::Curve;
+
+        // Membership witness equation e(C, yP~ + Q~) - e(V, P~) for a valid and an invalid witness
+        let sk = SecretKey::new(None);
+        let pk = PublicKey::from(&sk);
+        let elements: Vec<Element> = (0..10).map(|_| Element::random()).collect();
+        let acc = Accumulator::random(rand_core::OsRng{});
+        let wit = MembershipWitness::new(&elements[0], acc, &sk).unwrap();
+        let terms = |y: &Element| [
+            (wit.0.to_affine(), (G2Projective::GENERATOR * y.0 + pk.0).to_affine()),
+            (acc.0.to_affine(), -G2Affine::generator()),
+        ];
+        let (valid, invalid) = (terms(&elements[0]), terms(&elements[1]));
+
+        assert_eq!(pairing_sum_multi_miller(&valid), pairing_sum_separate(&valid));
+        assert_eq!(pairing_sum_multi_miller(&invalid), pairing_sum_separate(&invalid));
+        assert!(bool::from(pairing_sum_separate(&valid).is_identity()));
+        assert!(!bool::from(pairing_sum_separate(&invalid).is_identity()));
+
+        // Random terms and the empty sum
+        let random: Vec<_> = (0..5).map(|_| (G1Projective::random(rand_core::OsRng{}).to_affine(), G2Projective::random(rand_core::OsRng{}).to_affine())).collect();
+        assert_eq!(pairing_sum_multi_miller(&random), pairing_sum_separate(&random));
+        assert_eq!(pairing_sum_multi_miller(&[]), pairing_sum_separate(&[]));
+        assert!(pairing_check(&valid) && !pairing_check(&invalid));
+
+        // The prepared G2 points give the same sums as the affine ones with the selected backend
+        let prepared: Vec<_> = random.iter().map(|(p, q)| (*p, PreparedG2::from(*q))).collect();
+        let refs: Vec<_> = prepared.iter().map(|(p, q)| (*p, q)).collect();
+        assert_eq!(pairing_sum_prepared(&refs), pairing_sum(&random));
+        let prepared: Vec<_> = valid.iter().map(|(p, q)| (*p, PreparedG2::from(*q))).collect();
+        let refs: Vec<_> = prepared.iter().map(|(p, q)| (*p, q)).collect();
+        assert!(pairing_check_prepared(&refs));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn utils_test_powers_parallel(){
+        let size = 10_000;
+        let x = Scalar::random(rand_core::OsRng{});
+
+        let t1 = Instant::now();
+        let p1 = powers_serial(&x, size);
+        let t1 = t1.elapsed();
+
+        let t2 = Instant::now();
+        let p2 = powers_parallel(&x, size);
+        let t2 = t2.elapsed();
+
+        println!("Serial powers up to degree {size}: {:?}", t1);
+        println!("Parallel powers up to degree {size} on {} threads: {:?}", rayon::current_num_threads(), t2);
+
+        assert_eq!(p1, p2);
+        // Segment boundaries with fewer powers than threads
+        (1..64).for_each(|n| assert_eq!(powers_parallel(&x, n), powers_serial(&x, n)));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn utils_test_msm_parallel(){
+        use group::GroupEncoding;
+        let size = 10_000;
+        let random_scalars = || (0..size).map(|_| Scalar::random(rand_core::OsRng{})).collect::<Vec<Scalar>>();
+        let bases = window_mul(G1Projective::random(rand_core::OsRng{}), random_scalars());
+        let scalars = random_scalars();
+
+        let t1 = Instant::now();
+        let r1 = msm_serial(&bases, &scalars);
+        let t1 = t1.elapsed();
+
+        let t2 = Instant::now();
+        let r2 = msm_parallel(&bases, &scalars);
+        let t2 = t2.elapsed();
+
+        println!("Serial msm of {size} coefficients: {:?}", t1);
+        println!("Parallel msm of {size} coefficients on {} threads: {:?}", rayon::current_num_threads(), t2);
+
+        assert_eq!(r1.to_bytes(), r2.to_bytes());
+    }
+
 }
\ No newline at end of file
@@ -1,19 +1,112 @@
-use super::{Accumulator, Coefficient, Element, Error, PolynomialG1, PublicKey, SecretKey};
-use bls12_381_plus::{multi_miller_loop, G1Affine, G1Projective, G2Prepared, G2Projective, Scalar};
-use core::{convert::TryFrom, fmt};
-use group::{Curve, Group, GroupEncoding};
+use super::{utils::{batch_invert, generate_fr, pairing_check, pairing_check_prepared, strip_format_version, to_affine_pair, PreparedG2, FORMAT_VERSION, SALT}, Accumulator, Coefficient, Element, AccumulatorError, PolynomialG1, PublicKey, SecretKey};
+use bls12_381_plus::{G1Affine, G1Projective, G2Affine, G2Projective, Scalar};
+use alloc::{vec, vec::Vec};
+use core::{convert::TryFrom, fmt, ops::ControlFlow};
+use group::{ff::Field, Curve, GroupEncoding};
 use serde::{Deserialize, Serialize};
+use subtle::{Choice, ConstantTimeEq};
 
-// Groups the new accumulator value and the deleted element after
+/// Groups the new accumulator value and the deleted element after a single deletion.
+/// 
+/// This is the message of the sequential update form: issuers emit it (e.g., in the `deletions` of `Issuer::revoke_elements_instant_update`)
+/// and holders consume it unchanged with `MembershipWitness::update_assign` or `Holder::update`.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Deletion(pub Accumulator, pub Element);
 
+/// Checkpoint of a sequential update (see `MembershipWitness::update_assign`) applied in steps,
+/// so that an interrupted update can be resumed, e.g., after the holder's process is restarted.
+/// 
+/// Each deletion only depends on the witness after the previous ones, hence resuming from a persisted cursor
+/// yields the same witness as a single uninterrupted update, provided the cursor is persisted after every step.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct UpdateCursor {
+    /// The witness after the first `applied` deletions.
+    pub witness: MembershipWitness,
+    /// Number of deletions applied so far.
+    pub applied: usize,
+    /// Accumulator value of the last applied deletion, `None` if no deletion was applied yet.
+    pub accumulator: Option<Accumulator>,
+}
+
+impl UpdateCursor {
+    /// Starts a sequential update of `witness`, with no deletion applied.
+    pub fn new(witness: MembershipWitness) -> Self {
+        Self { witness, applied: 0, accumulator: None }
+    }
+
+    /// Returns true if all the deletions `del` were applied.
+    pub fn is_complete(&self, del: &[Deletion]) -> bool {
+        self.applied == del.len()
+    }
+
+    /// Applies in place at most `steps` of the deletions `del` following the cursor, 
+    /// where `del` is the whole update the cursor was started for.
+    /// Returns true if the update is complete, in which case `witness` is the updated witness.
+    /// 
+    /// Returns `AccumulatorError::Revoked` if `y` is one of the deletions of this step, 
+    /// or `AccumulatorError::ConflictingUpdates` if `del` is not the update the cursor was advanced with;
+    /// in both cases the cursor is left unmodified.
+    pub fn resume(&mut self, y: Element, del: &[Deletion], steps: usize) -> Result<bool, AccumulatorError> {
+        let last = match self.applied {
+            0 => None,
+            n => Some(del.get(n - 1).ok_or(AccumulatorError::ConflictingUpdates)?.0),
+        };
+        if last != self.accumulator {
+            return Err(AccumulatorError::ConflictingUpdates);
+        }
+
+        let end = self.applied.saturating_add(steps).min(del.len());
+        let step = &del[self.applied..end];
+        self.witness.update_assign(y, step)?;
+        if let Some(d) = step.last() {
+            self.accumulator = Some(d.0);
+        }
+        self.applied = end;
+        Ok(self.is_complete(del))
+    }
+}
+
+/// Exact length in bytes of a serialized `MembershipWitness`, i.e., a compressed G1 point.
+pub const MEMBERSHIP_WITNESS_SIZE: usize = MembershipWitness::SIZE;
+
 /// A membership witness that can be used for membership proof generation
 /// as described in section 4 in
 /// <https://eprint.iacr.org/2020/777>
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+/// 
+/// # Constant time
+/// - Equality, both `==` and `ct_eq`, compares the underlying points in constant time; 
+///   `ct_eq` returns a `Choice` to combine with other constant-time checks without branching.
+/// - `update_assign` processes all the deletions whether or not `y` is among them, so its timing does not reveal 
+///   the position of the holder's element in the deletions, only the (public) outcome of the update.
+/// - `batch_update_assign` and its variants return early if `y` was deleted, skipping the MSM. 
+///   The revocation is public anyway, but the timing of the MSM over the powers of `y` depends on `y` (variable-time windows).
+/// - `verify` and `batch_verify` evaluate pairings over the witness and the element, with no branching on secret values,
+///   but the underlying scalar multiplications are not guaranteed to be constant-time.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(not(feature = "serde_human"), derive(Serialize, Deserialize))]
 pub struct MembershipWitness(pub G1Projective);
 
+/// With `serde_human`, human-readable formats encode the witness as the hex string of `to_bytes`.
+#[cfg(feature = "serde_human")]
+impl Serialize for MembershipWitness {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_human(&self.to_bytes(), &self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde_human")]
+impl<'de> Deserialize<'de> for MembershipWitness {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_human::<_, _, _, { Self::BYTES }>(deserializer, |bytes| Self::try_from(bytes), Self)
+    }
+}
+
+impl ConstantTimeEq for MembershipWitness {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
 impl fmt::Display for MembershipWitness {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "MembershipWitness {{ {} }}", self.0)
@@ -32,53 +125,102 @@ impl From<G1Projective> for MembershipWitness {
     }
 }
 
+/// Low-level addition of the underlying G1 points.
+/// 
+/// NOTE: this is NOT a witness update, the result is in general not a valid witness.
+impl core::ops::Add for MembershipWitness {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+/// Low-level subtraction of the underlying G1 points.
+/// 
+/// NOTE: this is NOT a witness update, the result is in general not a valid witness.
+impl core::ops::Sub for MembershipWitness {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+/// Low-level scalar multiplication of the underlying G1 point.
+/// 
+/// NOTE: this is NOT a witness update, the result is in general not a valid witness.
+impl core::ops::Mul<Scalar> for MembershipWitness {
+    type Output = Self;
+
+    fn mul(self, rhs: Scalar) -> Self {
+        Self(self.0 * rhs)
+    }
+}
+
 impl TryFrom<&[u8; 48]> for MembershipWitness {
-    type Error = Error;
+    type Error = AccumulatorError;
 
     fn try_from(value: &[u8; 48]) -> Result<Self, Self::Error> {
         let pt = G1Affine::from_compressed(value).map(G1Projective::from);
         if pt.is_some().unwrap_u8() == 1 {
             Ok(Self(pt.unwrap()))
         } else {
-            Err(Error {
-                message: String::from("incorrect byte sequence"),
-                code: 1,
-            })
+            Err(AccumulatorError::BadEncoding)
         }
     }
 }
 
 impl MembershipWitness {
-    const BYTES: usize = 80;
+    pub const BYTES: usize = 48;
+    /// Exact length in bytes of the serialized witness (compressed G1 point), as returned by `to_bytes`.
+    pub const SIZE: usize = Self::BYTES;
+    /// Length in bytes of the versioned encoding returned by `to_versioned_bytes`.
+    pub const VERSIONED_BYTES: usize = Self::BYTES + 1;
+    /// Minimum number of deletions from which `batch_update_assign` switches from direct evaluation to
+    /// Multi Scalar Multiplication.
+    pub const MSM_THRESHOLD: usize = 32;
 
     /// Compute the witness using a prehashed element
-    pub fn new(value: &Element, accumulator: Accumulator, secret_key: &SecretKey) -> Self {
-        Self(accumulator.remove(secret_key, *value).0)
+    /// 
+    /// Returns `AccumulatorError::NonInvertible` if `value` + α is zero, i.e., if the element is the negated secret key,
+    /// which has no witness.
+    pub fn new(value: &Element, accumulator: Accumulator, secret_key: &SecretKey) -> Result<Self, AccumulatorError> {
+        // C = V*(y + α)^-1
+        let t = Option::<Scalar>::from((value.0 + secret_key.0).invert()).ok_or(AccumulatorError::NonInvertible)?;
+        Ok(Self(accumulator.0 * t))
     }
 
 
     /// Membership witness update as defined in section 3 of <https://eprint.iacr.org/2022/1362>.
-    /// Return a new witness
-    pub fn update(&self, y: Element, del: &[Deletion]) -> Self {
+    /// Return a new witness, or an error if `y` is one of the deleted elements.
+    pub fn update(&self, y: Element, del: &[Deletion]) -> Result<Self, AccumulatorError> {
         let mut clone = *self;
-        clone.update_assign(y, del);
-        clone
+        clone.update_assign(y, del)?;
+        Ok(clone)
     }
 
-    /// Perform in place witness update as defined in section 3 of <https://eprint.iacr.org/2022/1362>
-    pub fn update_assign(&mut self, y: Element, del: &[Deletion]) {
+    /// Perform in place witness update as defined in section 3 of <https://eprint.iacr.org/2022/1362>.
+    /// 
+    /// Returns an error if `y` is one of the deleted elements, in which case the witness is left unmodified.
+    /// An empty `del` is a valid update that leaves the witness unmodified.
+    /// All the deletions are processed before checking for a revocation, so that the timing does not depend on the position of `y`.
+    /// The values 1/(y' - y) are computed with a single field inversion (see `utils::batch_invert`).
+    pub fn update_assign(&mut self, y: Element, del: &[Deletion]) -> Result<(), AccumulatorError> {
+        let mut wit = self.0;
+        let mut inverses: Vec<Scalar> = del.iter().map(|d| d.1.0 - y.0).collect();
+        // If this fails, then this value was removed
+        let revoked = !batch_invert(&mut inverses);
         // C' = 1/(y' - y) (C - V')
-        for d in del {
-            let mut inv = d.1.0 - y.0;
-            // If this fails, then this value was removed
-            let t = inv.invert();
-            if bool::from(t.is_none()) {
-                return;
-            }
-            inv = t.unwrap();
-            self.0 -= d.0 .0;
-            self.0 *= inv;
+        for (d, t) in del.iter().zip(inverses) {
+            wit -= d.0 .0;
+            wit *= t;
         }
+        if bool::from(revoked) {
+            return Err(AccumulatorError::Revoked);
+        }
+        self.0 = wit;
+        Ok(())
     }
 
     /// Perform batch update using the associated element `y`, the list of coefficients `omega`, 
@@ -90,28 +232,93 @@ impl MembershipWitness {
         y: Element,
         deletions: &[Element],
         omega: &[Coefficient],
-    ) -> Result<MembershipWitness, Error>
+    ) -> Result<MembershipWitness, AccumulatorError>
     {
-        return self.clone().batch_update_assign(y, deletions, omega);
+        let mut clone = *self;
+        clone.batch_update_assign(y, deletions, omega)?;
+        Ok(clone)
+    }
+
+    /// Checks that this witness is the result of applying the batch update (`deletions`, `omega`) 
+    /// to the witness `old` of the element `y`, i.e., that C' * dD(y) == C -〈Υy,Ω〉, 
+    /// and that the coefficients are valid, i.e., that this witness verifies for `pubkey` and the new accumulator value `accumulator`.
+    /// 
+    /// The relation alone cannot detect malicious coefficients, since any coefficient set satisfies it with the witness it produces,
+    /// hence the result is also checked against the independent accumulator value published by the issuer.
+    /// The check costs one MSM and one pairing check.
+    pub fn verify_update(&self, old: &MembershipWitness, y: Element, deletions: &[Element], omega: &[Coefficient], pubkey: PublicKey, accumulator: Accumulator) -> bool {
+        if check_batch_lengths(deletions, omega).is_err() {
+            return false;
+        }
+        let d_d = dd_eval(deletions, y.0);
+        if bool::from(d_d.is_zero()) {
+            return false;
+        }
+        let applied = match PolynomialG1(omega.iter().map(|c| c.0).collect()).msm(&y.0) {
+            Some(v) => self.0 * d_d == old.0 - v,
+            None => false,
+        };
+        applied && self.verify(y, pubkey, accumulator)
     }
 
     /// Perform batch update of the witness in-place
     /// using the associated element `y`, the list of coefficients `omega`, 
     /// and list of deleted elements `deletions`.
+    /// 
+    /// The evaluation of〈Υy,Ω〉is adaptive: batches with fewer than `MSM_THRESHOLD` deletions are evaluated
+    /// directly as in `batch_update_assign_direct`, larger ones using Multi Scalar Multiplication.
+    /// Both paths produce the same witness.
+    /// 
+    /// Returns `AccumulatorError::Revoked`, leaving the witness unmodified, if `y` is one of the deleted elements.
+    /// Returns `AccumulatorError::EmptyInput` if `deletions` is empty and `AccumulatorError::LengthMismatch`
+    /// if `omega` does not have one coefficient per deletion.
     pub fn batch_update_assign(
         &mut self,
         y: Element,
         deletions: &[Element],
         omega: &[Coefficient],
-    ) -> Result<MembershipWitness, Error>
+    ) -> Result<(), AccumulatorError>
     {
+        self.batch_update_assign_inner(y, deletions, omega, Self::batch_update_uses_msm(deletions.len()))
+    }
+
+    /// Same as `batch_update_assign`, but always evaluates〈Υy,Ω〉directly (one scalar multiplication per coefficient)
+    /// instead of using Multi Scalar Multiplication. 
+    /// This is the original update path: it is slower on large batches but has no setup cost, 
+    /// and serves as a reference implementation for the optimized one.
+    pub fn batch_update_assign_direct(
+        &mut self,
+        y: Element,
+        deletions: &[Element],
+        omega: &[Coefficient],
+    ) -> Result<(), AccumulatorError>
+    {
+        self.batch_update_assign_inner(y, deletions, omega, false)
+    }
+
+    /// Returns whether `batch_update_assign` uses Multi Scalar Multiplication for a batch of `deletions` deletions,
+    /// i.e., whether `deletions >= MSM_THRESHOLD`.
+    pub const fn batch_update_uses_msm(deletions: usize) -> bool {
+        deletions >= Self::MSM_THRESHOLD
+    }
+
+    fn batch_update_assign_inner(
+        &mut self,
+        y: Element,
+        deletions: &[Element],
+        omega: &[Coefficient],
+        msm: bool,
+    ) -> Result<(), AccumulatorError>
+    {
+        check_batch_lengths(deletions, omega)?;
+
         // dD(x) = ∏ 1..m (yD_i - x)
         let mut d_d = dd_eval(deletions.as_ref(), y.0);
 
         let t = d_d.invert();
         // If this fails, then this value was removed
         if bool::from(t.is_none()) {
-            return Err(Error::from_msg(1, "no inverse exists"));
+            return Err(AccumulatorError::Revoked);
         }
         d_d = t.unwrap();
 
@@ -123,15 +330,190 @@ impl MembershipWitness {
                 .collect::<Vec<G1Projective>>(),
         );
 
-        // Compute〈Υy,Ω〉using Multi Scalar Multiplication
-        if let Some(v) = poly.msm(&y.0) {
+        // Compute〈Υy,Ω〉using either Multi Scalar Multiplication or direct evaluation
+        let v = if msm { poly.msm(&y.0) } else { poly.evaluate(&y.0) };
+        if let Some(v) = v {
             // C' = 1 / dD * (C -〈Υy,Ω))
             self.0 -= v;
             self.0 *= d_d;
-            Ok(*self)
+            Ok(())
         } else {
-            Err(Error::from_msg(2, "polynomial could not be evaluated"))        
+            Err(AccumulatorError::PolynomialEval)        
+        }
+    }
+
+    /// Same as `batch_update_assign`, but reads the powers of `y` from the cache `powers`, extending it if needed,
+    /// instead of recomputing them, so that successive batch updates of the same witness share them.
+    pub fn batch_update_assign_with_powers(
+        &mut self,
+        powers: &mut ElementPowers,
+        deletions: &[Element],
+        omega: &[Coefficient],
+    ) -> Result<(), AccumulatorError>
+    {
+        check_batch_lengths(deletions, omega)?;
+
+        // dD(x) = ∏ 1..m (yD_i - x)
+        let t = dd_eval(deletions, powers.y.0).invert();
+        // If this fails, then this value was removed
+        if bool::from(t.is_none()) {
+            return Err(AccumulatorError::Revoked);
+        }
+
+        // Compute〈Υy,Ω〉using Multi Scalar Multiplication with the cached powers
+        let poly = PolynomialG1(omega.iter().map(|c| c.0).collect::<Vec<G1Projective>>());
+        let v = poly.msm_with_powers(powers.get(omega.len())).ok_or(AccumulatorError::PolynomialEval)?;
+
+        // C' = 1 / dD * (C -〈Υy,Ω))
+        self.0 -= v;
+        self.0 *= t.unwrap();
+        Ok(())
+    }
+
+    /// Same as `batch_update_assign`, but evaluates〈Υy,Ω〉over consecutive windows of at most `window` coefficients, 
+    /// so that the memory used by the MSM is bounded by the window size instead of the number of deletions.
+    ///
+    /// NOTE: the deletions and coefficients are still held in memory by the caller, 
+    /// use `batch_update_assign_streamed` to bound the memory of the whole update.
+    pub fn batch_update_assign_chunked(
+        &mut self,
+        y: Element,
+        deletions: &[Element],
+        omega: &[Coefficient],
+        window: usize,
+    ) -> Result<(), AccumulatorError>
+    {
+        check_batch_lengths(deletions, omega)?;
+        self.batch_update_assign_streamed(y, deletions.iter().copied(), omega.chunks(window.max(1)))
+    }
+
+    /// Same as `batch_update_assign`, but consumes the deletions `deletions` one at a time and the coefficients 
+    /// in the consecutive windows `omega_windows` (e.g., read from a file or the network), so that the memory used 
+    /// is bounded by the largest window, instead of the number of deletions.
+    ///
+    /// Since〈Υy,Ω〉= ∑ y^k_j *〈Υy,Ω_j〉for the windows Ω_j starting at index k_j, the resulting witness is identical to `batch_update_assign`,
+    /// at the cost of one additional point multiplication per window.
+    /// Returns `AccumulatorError::Revoked` before consuming any coefficient if `y` is one of the deleted elements,
+    /// and the same errors as `batch_update_assign` otherwise, leaving the witness unmodified.
+    pub fn batch_update_assign_streamed<W: AsRef<[Coefficient]>>(
+        &mut self,
+        y: Element,
+        deletions: impl IntoIterator<Item = Element>,
+        omega_windows: impl IntoIterator<Item = W>,
+    ) -> Result<(), AccumulatorError>
+    {
+        // dD(x) = ∏ 1..m (yD_i - x)
+        let (count, d_d) = deletions.into_iter().fold((0, Scalar::ONE), |(count, d_d), del| (count + 1, d_d * (del.0 - y.0)));
+        if count == 0 {
+            return Err(AccumulatorError::EmptyInput);
+        }
+        // If this fails, then this value was removed
+        let t = Option::<Scalar>::from(d_d.invert()).ok_or(AccumulatorError::Revoked)?;
+
+        // Compute〈Υy,Ω〉window by window, scaling each window by y^k for its first index k
+        let (mut v, mut y_k, mut processed) = (G1Projective::IDENTITY, Scalar::ONE, 0);
+        for chunk in omega_windows {
+            let chunk = chunk.as_ref();
+            if chunk.is_empty() {
+                continue;
+            }
+            let poly = PolynomialG1(chunk.iter().map(|c| c.0).collect::<Vec<G1Projective>>());
+            v += poly.msm(&y.0).ok_or(AccumulatorError::PolynomialEval)? * y_k;
+            y_k *= y.0.pow_vartime(&[chunk.len() as u64, 0, 0, 0]);
+            processed += chunk.len();
         }
+        if processed != count {
+            return Err(AccumulatorError::LengthMismatch);
+        }
+
+        // C' = 1 / dD * (C -〈Υy,Ω))
+        self.0 -= v;
+        self.0 *= t;
+        Ok(())
+    }
+
+    /// Same as `batch_update_assign_chunked`, but calls `progress` with the number of coefficients processed so far 
+    /// and the total number of coefficients after each window of〈Υy,Ω〉.
+    ///
+    /// If `progress` returns `ControlFlow::Break`, the update stops and returns `AccumulatorError::Aborted`, leaving the witness unmodified.
+    pub fn batch_update_assign_with_progress(
+        &mut self,
+        y: Element,
+        deletions: &[Element],
+        omega: &[Coefficient],
+        window: usize,
+        mut progress: impl FnMut(usize, usize) -> ControlFlow<()>,
+    ) -> Result<(), AccumulatorError>
+    {
+        check_batch_lengths(deletions, omega)?;
+
+        // dD(x) = ∏ 1..m (yD_i - x)
+        let t = dd_eval(deletions, y.0).invert();
+        // If this fails, then this value was removed
+        if bool::from(t.is_none()) {
+            return Err(AccumulatorError::Revoked);
+        }
+
+        // Compute〈Υy,Ω〉window by window with Horner's rule, starting from the last window
+        let window = window.max(1);
+        let y_window = y.0.pow_vartime(&[window as u64, 0, 0, 0]);
+        let mut v = G1Projective::IDENTITY;
+        let mut processed = 0;
+        for chunk in omega.chunks(window).rev() {
+            let poly = PolynomialG1(chunk.iter().map(|c| c.0).collect::<Vec<G1Projective>>());
+            v *= y_window;
+            v += poly.msm(&y.0).ok_or(AccumulatorError::PolynomialEval)?;
+            processed += chunk.len();
+            if progress(processed, omega.len()).is_break() {
+                return Err(AccumulatorError::Aborted);
+            }
+        }
+
+        // C' = 1 / dD * (C -〈Υy,Ω))
+        self.0 -= v;
+        self.0 *= t.unwrap();
+        Ok(())
+    }
+
+    /// Perform in-place the batch updates `updates`, given as pairs `(deletions, omega)` in the order
+    /// they were issued, aggregating them so that a single inversion is needed.
+    ///
+    /// Unrolling C_k = 1 / dD_k * (C_(k-1) -〈Υy,Ω_k〉), the final witness is
+    /// C' = 1 / ∏ dD_k * (C - ∑ (∏ i<k dD_i) *〈Υy,Ω_k〉).
+    /// Returns an error, leaving the witness unmodified, if `y` was deleted by any of the updates
+    /// or if any of the updates is malformed as in `batch_update_assign`.
+    pub fn aggregate_batch_update_assign(
+        &mut self,
+        y: Element,
+        updates: &[(&[Element], &[Coefficient])],
+    ) -> Result<(), AccumulatorError>
+    {
+        let mut prefix = Scalar::ONE;
+        let mut sum = G1Projective::IDENTITY;
+        for (deletions, omega) in updates {
+            check_batch_lengths(deletions, omega)?;
+            let poly = PolynomialG1(omega.iter().map(|c| c.0).collect::<Vec<G1Projective>>());
+            let v = poly.msm(&y.0).ok_or(AccumulatorError::PolynomialEval)?;
+            sum += v * prefix;
+            prefix *= dd_eval(deletions, y.0);
+        }
+
+        let t = prefix.invert();
+        // If this fails, then this value was removed
+        if bool::from(t.is_none()) {
+            return Err(AccumulatorError::Revoked);
+        }
+        self.0 -= sum;
+        self.0 *= t.unwrap();
+        Ok(())
+    }
+
+    /// Perform in place the witness update for element `y` after `added` was added back to the accumulator `accumulator`,
+    /// where `accumulator` is the value before the addition. Only public values are needed.
+    pub fn add_update_assign(&mut self, y: Element, accumulator: Accumulator, added: Element) {
+        // C' = V + (y_added - y) C
+        self.0 *= added.0 - y.0;
+        self.0 += accumulator.0;
     }
 
     /// Substitutes the underlying G1 point with the `new_wit` given as input.
@@ -140,27 +522,85 @@ impl MembershipWitness {
     }
 
     /// Verify this is a valid witness for element `y`, public key `pubkey`, and accumulator value `accumulator`.
+    /// 
+    /// An identity (degenerate) accumulator is always invalid for membership: the verification fails without evaluating the pairings,
+    /// since the identity witness would satisfy the equation for any element.
     pub fn verify(&self, y: Element, pubkey: PublicKey, accumulator: Accumulator) -> bool {
+        if accumulator.is_degenerate() {
+            return false;
+        }
         let mut p = G2Projective::GENERATOR;
         p *= y.0;
         p += pubkey.0;
-        let g2 = G2Projective::GENERATOR;
+        let (c, v) = to_affine_pair(&self.0, &accumulator.0);
         
         // Notation as per section 2 in <https://eprint.iacr.org/2020/777>
         // e(C, yP~ + Q~) == e(V, P~) <=>  e(C, yP~ + Q~) - e(V, P~) == 0_{G_t}
-        bool::from(
-            multi_miller_loop(&[
-                // e(C, yP~ + Q~)
-                (&self.0.to_affine(), &G2Prepared::from(p.to_affine())),
-                // -e(V, P~)
-                (
-                    &accumulator.0.to_affine(),
-                    &G2Prepared::from(-g2.to_affine()),
-                ),
-            ])
-            .final_exponentiation()
-            .is_identity(),
-        )
+        pairing_check(&[
+            // e(C, yP~ + Q~)
+            (c, p.to_affine()),
+            // -e(V, P~)
+            (v, -G2Affine::generator()),
+        ])
+    }
+
+    /// Verify this witness for element `y` and public key `pubkey` against each of the historical accumulator values `accs`, 
+    /// labeled by their epoch, returning the validity for each epoch.
+    /// 
+    /// The G2 terms yP~ + Q~ and -P~ are prepared once and reused for all the accumulators.
+    /// NOTE: a witness is valid for a single accumulator value, hence this detects when the witness went stale.
+    pub fn verify_timeline(&self, y: Element, pubkey: PublicKey, accs: &[(u64, Accumulator)]) -> Vec<(u64, bool)> {
+        let mut p = G2Projective::GENERATOR;
+        p *= y.0;
+        p += pubkey.0;
+        let (p, g2) = (PreparedG2::from(p.to_affine()), PreparedG2::from(-G2Affine::generator()));
+        let c = self.0.to_affine();
+
+        // Convert all the accumulators to affine form with a single field inversion
+        let points: Vec<G1Projective> = accs.iter().map(|(_, acc)| acc.0).collect();
+        let mut affine = vec![G1Affine::identity(); points.len()];
+        G1Projective::batch_normalize(&points, &mut affine);
+
+        accs.iter()
+            .zip(affine)
+            .map(|((epoch, acc), v)| {
+                if acc.is_degenerate() {
+                    return (*epoch, false);
+                }
+                // e(C, yP~ + Q~) - e(V, P~) == 0_{G_t}
+                (*epoch, pairing_check_prepared(&[(c, &p), (v, &g2)]))
+            })
+            .collect()
+    }
+
+    /// Verifies the witnesses `items` of their elements for the public key `pubkey` and the accumulator value `accumulator`,
+    /// returning the indices of the invalid witnesses in increasing order.
+    /// 
+    /// With random r_i, the equations e(C_i, y_iP~ + Q~) == e(V, P~) are folded into 
+    /// e(∑ r_i*y_i*C_i - (∑ r_i)*V, P~) + e(∑ r_i*C_i, Q~) == 0_{G_t}, i.e., a single Miller loop over two pairings for the whole batch.
+    /// If the folded check fails, the items are split in halves which are checked recursively as in `proof::batch_verify_each`, 
+    /// so that a few invalid witnesses cost O(log K) additional Miller loops each.
+    /// The G2 terms -P~ and Q~ are prepared once and shared across all the checks.
+    /// All the witnesses are invalid for an identity accumulator, as in `verify`.
+    pub fn batch_verify(items: &[(Element, MembershipWitness)], pubkey: PublicKey, accumulator: Accumulator) -> Vec<usize> {
+        let indices: Vec<usize> = (0..items.len()).collect();
+        if accumulator.is_degenerate() {
+            return indices;
+        }
+        let prepared = (PreparedG2::from(-G2Affine::generator()), PreparedG2::from(pubkey.0.to_affine()));
+        let mut invalid = Vec::new();
+        bisect_witnesses(items, &indices, &prepared, accumulator, &mut invalid);
+        invalid
+    }
+
+    /// Verifies the witnesses `items` of their elements, e.g., received from different holders, for the public key `pubkey` 
+    /// and the accumulator value `accumulator`, returning the validity of each witness in the same order.
+    /// 
+    /// Same as calling `verify` on each item, but the witnesses are checked together with a random linear combination (see `batch_verify`).
+    pub fn verify_many(items: &[(Element, MembershipWitness)], pubkey: PublicKey, accumulator: Accumulator) -> Vec<bool> {
+        let mut valid = vec![true; items.len()];
+        Self::batch_verify(items, pubkey, accumulator).into_iter().for_each(|i| valid[i] = false);
+        valid
     }
 
     /// Return the byte sequence for this witness.
@@ -170,42 +610,243 @@ impl MembershipWitness {
         res
     }
 
-    /// Old unoptimized version, just for testing
-    fn _batch_update_assign(
-        &mut self,
-        y: Element,
-        deletions: &[Element],
-        coefficients: &[Coefficient],
-    ) -> Result<MembershipWitness, Error>{
-        // dD(x) = ∏ 1..m (yD_i - x)
-        let mut d_d = dd_eval(deletions.as_ref(), y.0);
+    /// Convert a byte sequence of exactly `MembershipWitness::SIZE` bytes to a witness.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AccumulatorError> {
+        let bytes = <&[u8; Self::SIZE]>::try_from(bytes)
+            .map_err(|_| AccumulatorError::BadEncoding)?;
+        Self::try_from(bytes)
+    }
 
-        let t = d_d.invert();
-        // If this fails, then this value was removed
-        if bool::from(t.is_none()) {
-            return Err(Error::from_msg(1, "no inverse exists"));
+    /// Returns the encoding `FORMAT_VERSION || to_bytes()`, to be used when persisting the witness.
+    pub fn to_versioned_bytes(&self) -> [u8; Self::VERSIONED_BYTES] {
+        let mut res = [0u8; Self::VERSIONED_BYTES];
+        res[0] = FORMAT_VERSION;
+        res[1..].copy_from_slice(&self.to_bytes());
+        res
+    }
+
+    /// Restores a witness persisted with `to_versioned_bytes`.
+    /// 
+    /// Returns `AccumulatorError::IncompatibleVersion` if the version byte is not in `SUPPORTED_FORMAT_VERSIONS`,
+    /// and `AccumulatorError::BadEncoding` if the payload is malformed.
+    pub fn from_versioned_bytes(bytes: &[u8]) -> Result<Self, AccumulatorError> {
+        Self::from_bytes(strip_format_version(bytes)?)
+    }
+
+}
+
+/// Cache of the powers 1, y, y^2, ... of an element `y`, grown on demand,
+/// used by `MembershipWitness::batch_update_assign_with_powers` to evaluate successive update polynomials.
+/// 
+/// NOTE: the cache keeps as many powers as the largest update applied, i.e., 32 bytes per deletion.
+#[derive(Debug, Clone)]
+pub struct ElementPowers {
+    y: Element,
+    powers: Vec<Scalar>,
+}
+
+impl ElementPowers {
+    /// Creates an empty cache of the powers of `y`.
+    pub fn new(y: Element) -> Self {
+        Self { y, powers: vec![Scalar::ONE] }
+    }
+
+    /// Returns the element whose powers are cached.
+    pub fn get_elem(&self) -> Element {
+        self.y
+    }
+
+    /// Returns the number of cached powers.
+    pub fn num_powers(&self) -> usize {
+        self.powers.len()
+    }
+
+    /// Returns the powers 1, y, ..., y^(n-1), computing only the ones not already cached.
+    pub fn get(&mut self, n: usize) -> &[Scalar] {
+        while self.powers.len() < n {
+            let next = self.powers[self.powers.len() - 1] * self.y.0;
+            self.powers.push(next);
         }
-        d_d = t.unwrap();
+        &self.powers[..n]
+    }
+}
 
-        let poly = PolynomialG1(
-            coefficients
-                .as_ref()
-                .iter()
-                .map(|c| c.0)
-                .collect::<Vec<G1Projective>>(),
-        );
+/// Verifier of membership witnesses for a fixed public key, 
+/// which caches the prepared -P~ and a fixed-base multiplication table for the G2 generator P~.
+/// 
+/// The term yP~ + Q~ of each verification is computed with 63 additions instead of a full scalar multiplication,
+/// which speeds up the verification of many distinct elements.
+/// NOTE: the table lookups depend on `y`, hence the verification is not constant time in the element.
+#[derive(Debug, Clone)]
+pub struct PreparedWitnessVerifier {
+    pubkey: PublicKey,
+    g2_neg: PreparedG2,
+    /// table[j][d-1] = d*16^j*P~ for each 4-bit window j and digit d in 1..16
+    table: Vec<[G2Projective; 15]>,
+}
 
-        // Compute〈Υy,Ω〉using direct evaluation
-        if let Some(v) = poly.evaluate(&y.0) {
-            // C' = 1 / dD * (C -〈Υy,Ω))
-            self.0 -= v;
-            self.0 *= d_d;
-            Ok(*self)
-        } else {
-            Err(Error::from_msg(2, "polynomial could not be evaluated")) 
+impl PreparedWitnessVerifier {
+    const WINDOW_BITS: usize = 4;
+
+    /// Precomputes the fixed-base table of the G2 generator and prepares the verification for public key `pubkey`.
+    pub fn new(pubkey: PublicKey) -> Self {
+        let mut base = G2Projective::GENERATOR;
+        let table = (0..Scalar::BYTES * 8 / Self::WINDOW_BITS)
+            .map(|_| {
+                let mut row = [base; 15];
+                for d in 1..row.len() {
+                    row[d] = row[d - 1] + base;
+                }
+                base = row[14] + base;
+                row
+            })
+            .collect();
+        Self { pubkey, g2_neg: PreparedG2::from(-G2Affine::generator()), table }
+    }
+
+    /// Computes y*P~ using the fixed-base table
+    fn mul_generator(&self, y: &Scalar) -> G2Projective {
+        y.to_le_bytes()
+            .iter()
+            .flat_map(|b| [b & 0x0f, b >> 4])
+            .zip(&self.table)
+            .filter(|(d, _)| *d != 0)
+            .fold(G2Projective::IDENTITY, |acc, (d, row)| acc + row[d as usize - 1])
+    }
+
+    /// Verify `wit` is a valid witness for element `y` against accumulator value `accumulator`,
+    /// same as `MembershipWitness::verify` with the cached public key.
+    pub fn verify(&self, wit: &MembershipWitness, y: Element, accumulator: Accumulator) -> bool {
+        if accumulator.is_degenerate() {
+            return false;
         }
+        // e(C, yP~ + Q~) - e(V, P~) == 0_{G_t}
+        let p = PreparedG2::from((self.mul_generator(&y.0) + self.pubkey.0).to_affine());
+        let (c, v) = to_affine_pair(&wit.0, &accumulator.0);
+        pairing_check_prepared(&[(c, &p), (v, &self.g2_neg)])
     }
+}
 
+/// A non-membership witness (C, d) for an element x, with d != 0, such that C*(x + α) + d*P = V, 
+/// i.e., e(C, xP~ + Q~) * e(P, P~)^d == e(V, P~), as per Section 2 in <https://eprint.iacr.org/2020/777>.
+/// 
+/// In the standard construction d = f_Y(-x) is fixed by the accumulated set Y, instead of being chosen by the issuer.
+/// Here the accumulator starts from a random value rather than from a polynomial in α, hence d is derived from the accumulator state,
+/// i.e., d = H(x || V) at issuance and d' = d / (y' - x) after each deletion (V', y'), so that a witness is unique for a given x and V.
+/// 
+/// NOTE: the accumulator value does not reveal its elements, hence the non-membership of x 
+/// is attested by the issuer, which must only issue it for elements that are not members (e.g., revoked ones).
+/// Without the trapdoor, a membership witness for x cannot be turned into a non-membership witness for x.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct NonMembershipWitness {
+    pub(crate) c: G1Projective,
+    pub(crate) d: Scalar,
+}
+
+impl NonMembershipWitness {
+
+    /// Computes a non-membership witness for the element `x` against `accumulator`, using the trapdoor `secret_key`.
+    /// 
+    /// Returns `AccumulatorError::DegenerateAccumulator` if `accumulator` is the identity, 
+    /// and `AccumulatorError::NonInvertible` if x + α or the derived d is zero.
+    pub fn new(x: &Element, accumulator: Accumulator, secret_key: &SecretKey) -> Result<Self, AccumulatorError> {
+        if accumulator.is_degenerate() {
+            return Err(AccumulatorError::DegenerateAccumulator);
+        }
+        let t = Option::<Scalar>::from((x.0 + secret_key.0).invert()).ok_or(AccumulatorError::NonInvertible)?;
+        let d = Self::derive_d(x, accumulator);
+        if bool::from(d.is_zero()) {
+            return Err(AccumulatorError::NonInvertible);
+        }
+        // C = (V - d*P) / (x + α)
+        let c = (accumulator.0 - G1Projective::GENERATOR * d) * t;
+        Ok(Self { c, d })
+    }
+
+    /// Derives d = H(x || V) for the element `x` and the accumulator `accumulator` at issuance.
+    fn derive_d(x: &Element, accumulator: Accumulator) -> Scalar {
+        let mut ikm = Vec::with_capacity(Element::BYTES + Accumulator::BYTES);
+        ikm.extend_from_slice(&x.to_bytes());
+        ikm.extend_from_slice(&accumulator.to_bytes());
+        generate_fr(SALT, Some(&ikm), rand_core::OsRng {})
+    }
+
+    /// Verifies this non-membership witness for element `x` against the public key `pubkey` and the accumulator `accumulator`.
+    /// 
+    /// Degenerate witnesses (d == 0 or C == 0) and a degenerate accumulator are rejected: 
+    /// with d == 0 the witness is a membership witness, and C == 0 only holds if V = d*P.
+    pub fn verify(&self, x: Element, pubkey: PublicKey, accumulator: Accumulator) -> bool {
+        if bool::from(self.d.is_zero()) || bool::from(self.c.is_identity()) || accumulator.is_degenerate() {
+            return false;
+        }
+        let p = G2Projective::GENERATOR * x.0 + pubkey.0;
+        // e(C, xP~ + Q~) + e(d*P - V, P~) == 0_{G_t}
+        pairing_check(&[
+            (self.c.to_affine(), p.to_affine()),
+            ((G1Projective::GENERATOR * self.d - accumulator.0).to_affine(), G2Affine::generator()),
+        ])
+    }
+
+    /// Updates in place this non-membership witness for element `x` with the deletions `del`, 
+    /// computing C' = (C - V') / (y' - x) and d' = d / (y' - x) for each deletion (V', y').
+    /// 
+    /// Returns `AccumulatorError::Revoked` if `x` is one of the deleted elements, as `MembershipWitness::update_assign`, 
+    /// in which case the witness is left unmodified.
+    pub fn update_assign(&mut self, x: Element, del: &[Deletion]) -> Result<(), AccumulatorError> {
+        let mut wit = *self;
+        for d in del {
+            let t = Option::<Scalar>::from((d.1.0 - x.0).invert()).ok_or(AccumulatorError::Revoked)?;
+            wit.c = (wit.c - d.0.0) * t;
+            wit.d *= t;
+        }
+        *self = wit;
+        Ok(())
+    }
+}
+
+/// Appends to `invalid` the indices in `indices` of the witnesses failing verification, bisecting the failing batches
+fn bisect_witnesses(items: &[(Element, MembershipWitness)], indices: &[usize], prepared: &(PreparedG2, PreparedG2), accumulator: Accumulator, invalid: &mut Vec<usize>) {
+    if indices.is_empty() || witness_batch_check(items, indices, prepared, accumulator) {
+        return;
+    }
+    if let [i] = indices {
+        invalid.push(*i);
+        return;
+    }
+    let (left, right) = indices.split_at(indices.len() / 2);
+    bisect_witnesses(items, left, prepared, accumulator, invalid);
+    bisect_witnesses(items, right, prepared, accumulator, invalid);
+}
+
+/// Checks e((∑ r_i)*V - ∑ r_i*y_i*C_i, -P~) + e(∑ r_i*C_i, Q~) == 0_{G_t} with r_i sampled from a CSPRNG,
+/// so that invalid witnesses cannot cancel out, where `prepared` holds the prepared -P~ and Q~. 
+/// A single witness is checked without random linear combination, i.e., with r_1 = 1.
+fn witness_batch_check(items: &[(Element, MembershipWitness)], indices: &[usize], prepared: &(PreparedG2, PreparedG2), accumulator: Accumulator) -> bool {
+    let rng = rand_core::OsRng {};
+    let (mut lhs, mut c, mut r_sum) = (G1Projective::IDENTITY, G1Projective::IDENTITY, Scalar::ZERO);
+    for &i in indices {
+        let (y, wit) = items[i];
+        // Randomly select r_i
+        let r = if indices.len() == 1 { Scalar::ONE } else { generate_fr(SALT, None, rng) };
+        lhs -= wit.0 * (r * y.0);
+        c += wit.0 * r;
+        r_sum += r;
+    }
+    lhs += accumulator.0 * r_sum;
+    let (lhs, c) = to_affine_pair(&lhs, &c);
+
+    pairing_check_prepared(&[(lhs, &prepared.0), (c, &prepared.1)])
+}
+
+/// Checks the batch update has at least one deletion and one coefficient of Omega per deletion
+fn check_batch_lengths(deletions: &[Element], omega: &[Coefficient]) -> Result<(), AccumulatorError> {
+    if deletions.is_empty() {
+        return Err(AccumulatorError::EmptyInput);
+    }
+    if deletions.len() != omega.len() {
+        return Err(AccumulatorError::LengthMismatch);
+    }
+    Ok(())
 }
 
 /// Evaluates poly dD(y) = ∏ 1..m (yD_i - y)
@@ -229,6 +870,7 @@ mod tests {
     use crate::key;
     use std::time::Instant;
     use std::time::SystemTime;
+    use group::Group;
 
     fn init(upd_size: usize) -> (key::SecretKey, key::PublicKey, Accumulator, Vec<Element>) {
         let key = SecretKey::new(Some(b"1234567890"));
@@ -246,11 +888,11 @@ mod tests {
 
         // Non revoked (y,C) pair
         let elem = elements[0];
-        let mut wit = MembershipWitness::new(&elem, acc, &key);
+        let mut wit = MembershipWitness::new(&elem, acc, &key).unwrap();
 
         // Revoked (y,C) pair
         let elem_d = elements[1];
-        let mut wit_d = MembershipWitness::new(&elem_d, acc, &key);
+        let mut wit_d = MembershipWitness::new(&elem_d, acc, &key).unwrap();
 
         // Revoke everyone except for elem
         let dels = &elements[1..upd_size];
@@ -262,11 +904,11 @@ mod tests {
 
         // Update non-revoked element
         let t = Instant::now();
-        wit.update_assign(elem, &deletions.as_slice());
+        wit.update_assign(elem, deletions.as_slice()).expect("Cannot update witness");
         let t = t.elapsed();
 
         // Try update revoked elem
-        wit_d.update_assign(elem_d, &deletions.as_slice());
+        assert!(wit_d.update_assign(elem_d, deletions.as_slice()).is_err());
 
         assert!(wit.verify(elem, pubkey, acc));
         assert!(!wit.verify(elem_d, pubkey, acc));
@@ -283,11 +925,11 @@ mod tests {
 
         // Non revoked (y, wit) pair
         let y = elements[0];
-        let mut wit = MembershipWitness::new(&y, acc, &key);
+        let mut wit = MembershipWitness::new(&y, acc, &key).unwrap();
 
         // Revoked (y_d, wit_d) pair
         let y_d = elements[1];
-        let mut wit_d = MembershipWitness::new(&y_d, acc, &key);
+        let mut wit_d = MembershipWitness::new(&y_d, acc, &key).unwrap();
 
         // Revoke y_1, ..., y_(upd_size-1) and compute coefficients for batch update
         let deletions = &elements[1..];
@@ -299,8 +941,9 @@ mod tests {
         wit.batch_update_assign(y, deletions, &coefficients).expect("Error when evaluating poly");
         let t1 = t1.elapsed();
         let t2 = Instant::now();
-        wit2._batch_update_assign(y, deletions, &coefficients).expect("Error when evaluating poly");
+        wit2.batch_update_assign_direct(y, deletions, &coefficients).expect("Error when evaluating poly");
         let t2 = t2.elapsed();
+        assert_eq!(wit, wit2);
 
         // Try updating revoked element
         wit_d.batch_update_assign(y_d, deletions, &coefficients);
@@ -321,6 +964,600 @@ mod tests {
         wit_batch_update(upd_size);
     }
 
+    // Compare the sequential update with batched inversion against the naive loop with one inversion per deletion
+    #[test]
+    fn wit_test_sequential_update_batched_inversion() {
+        let upd_size = 5_000;
+        let (key, pubkey, mut acc, elements) = init(upd_size + 1);
+        let elem = elements[0];
+        let wit = MembershipWitness::new(&elem, acc, &key).unwrap();
+        let deletions: Vec<Deletion> = elements[1..].iter().map(|&d| Deletion(acc.remove_assign(&key, d), d)).collect();
+
+        // Naive loop
+        let t1 = Instant::now();
+        let mut naive = wit.0;
+        for d in &deletions {
+            naive = (naive - d.0.0) * (d.1.0 - elem.0).invert().unwrap();
+        }
+        let t1 = t1.elapsed();
+
+        // Batched inversion
+        let t2 = Instant::now();
+        let batched = wit.update(elem, &deletions).expect("Cannot update witness");
+        let t2 = t2.elapsed();
+
+        assert_eq!(batched, MembershipWitness(naive));
+        assert!(batched.verify(elem, pubkey, acc));
+        println!("Sequential update of {} deletions with one inversion per deletion: {:?}", upd_size, t1);
+        println!("Sequential update of {} deletions with batched inversion: {:?}", upd_size, t2);
+    }
+
+    // Test serialized size
+    #[test]
+    fn wit_test_size() {
+        let (key, _, acc, elements) = init(1);
+        let wit = MembershipWitness::new(&elements[0], acc, &key).unwrap();
+
+        // Check serialized witness has exactly SIZE bytes and is only accepted with that length
+        let bytes = wit.to_bytes();
+        assert_eq!(bytes.len(), MembershipWitness::SIZE);
+        assert_eq!(crate::MEMBERSHIP_WITNESS_SIZE, MembershipWitness::SIZE);
+        assert_eq!(MembershipWitness::from_bytes(&bytes).expect("Cannot parse witness"), wit);
+        assert!(MembershipWitness::from_bytes(&bytes[1..]).is_err());
+        assert!(MembershipWitness::from_bytes(&[bytes.as_slice(), &[0u8; 32]].concat()).is_err());
+    }
+
+    // Test sequential update with the element revoked in the middle of the deletions
+    #[test]
+    fn wit_test_sequential_update_revoked() {
+        let (key, pubkey, mut acc, elements) = init(5);
+        let elem = elements[2];
+        let wit = MembershipWitness::new(&elem, acc, &key).unwrap();
+
+        // Revoke all elements, including elem in the middle
+        let deletions: Vec<Deletion> = elements.iter().map(|&d| Deletion(acc.remove_assign(&key, d), d)).collect();
+
+        // Check the update fails and leaves the witness unmodified
+        let mut updated = wit;
+        assert!(updated.update_assign(elem, &deletions).is_err());
+        assert_eq!(updated, wit);
+        assert!(wit.update(elem, &deletions).is_err());
+
+        // Check updating up to the previous deletion succeeds
+        let updated = wit.update(elem, &deletions[..2]).expect("Cannot update witness");
+        assert!(updated.verify(elem, pubkey, deletions[1].0));
+    }
+
+    // Test witness validity across epochs
+    #[test]
+    fn wit_test_verify_timeline() {
+        let (key, pubkey, mut acc, elements) = init(3);
+        let (elem, wit) = (elements[0], MembershipWitness::new(&elements[0], acc, &key).unwrap());
+
+        // Revoke one element per epoch
+        let mut accs = vec![(0, acc)];
+        let mut deletions = Vec::new();
+        for (epoch, d) in elements[1..].iter().enumerate() {
+            deletions.push(Deletion(acc.remove_assign(&key, *d), *d));
+            accs.push((epoch as u64 + 1, acc));
+        }
+
+        // Check witness is valid only in the epoch it was computed for, and the updated witness only in the last one
+        assert_eq!(wit.verify_timeline(elem, pubkey, &accs), vec![(0, true), (1, false), (2, false)]);
+        let updated = wit.update(elem, &deletions).expect("Cannot update witness");
+        assert_eq!(updated.verify_timeline(elem, pubkey, &accs), vec![(0, false), (1, false), (2, true)]);
+    }
+
+    // Test batch verification over valid and invalid witnesses
+    #[test]
+    fn wit_test_batch_verify() {
+        let (key, pubkey, acc, elements) = init(10);
+        let mut items: Vec<(Element, MembershipWitness)> = elements.iter().map(|y| (*y, MembershipWitness::new(y, acc, &key).unwrap())).collect();
+
+        // Check all valid witnesses pass, including trivial batches
+        assert!(MembershipWitness::batch_verify(&items, pubkey, acc).is_empty());
+        assert!(MembershipWitness::batch_verify(&items[..1], pubkey, acc).is_empty());
+        assert!(MembershipWitness::batch_verify(&[], pubkey, acc).is_empty());
+
+        // Check exactly the invalid witnesses are reported: a random witness, a witness for another element, and a swapped pair
+        items[2].1 = MembershipWitness(G1Projective::random(rand_core::OsRng {}));
+        items[5].0 = Element::random();
+        let (w7, w8) = (items[7].1, items[8].1);
+        (items[7].1, items[8].1) = (w8, w7);
+        assert_eq!(MembershipWitness::batch_verify(&items, pubkey, acc), vec![2, 5, 7, 8]);
+        assert_eq!(MembershipWitness::batch_verify(&items[2..3], pubkey, acc), vec![0]);
+
+        // Check the valid witnesses fail against another accumulator
+        let other = Accumulator::random(rand_core::OsRng {});
+        assert_eq!(MembershipWitness::batch_verify(&items[..2], pubkey, other), vec![0, 1]);
+    }
+
+    // Test the negated secret key has no witness
+    #[test]
+    fn wit_test_new_non_invertible() {
+        let (key, pubkey, acc, elements) = init(1);
+        let y = Element(-key.0);
+        assert_eq!(MembershipWitness::new(&y, acc, &key), Err(AccumulatorError::NonInvertible));
+        assert!(MembershipWitness::new(&elements[0], acc, &key).unwrap().verify(elements[0], pubkey, acc));
+    }
+
+    // Test verification of many witnesses against per-item verification
+    #[test]
+    fn wit_test_verify_many() {
+        let (key, pubkey, acc, elements) = init(20);
+        let mut items: Vec<(Element, MembershipWitness)> = elements.iter().map(|y| (*y, MembershipWitness::new(y, acc, &key).unwrap())).collect();
+        assert!(MembershipWitness::verify_many(&items, pubkey, acc).iter().all(|v| *v));
+        assert!(MembershipWitness::verify_many(&[], pubkey, acc).is_empty());
+
+        // Mix in invalid witnesses: random witnesses, witnesses for other elements, and the identity
+        items[0].1 = MembershipWitness(G1Projective::random(rand_core::OsRng {}));
+        items[3].0 = Element::random();
+        items[11].1 = MembershipWitness(G1Projective::IDENTITY);
+        items[19].1 = items[18].1;
+        let expected: Vec<bool> = items.iter().map(|(y, wit)| wit.verify(*y, pubkey, acc)).collect();
+        assert_eq!(expected.iter().filter(|v| !**v).count(), 4);
+        assert_eq!(MembershipWitness::verify_many(&items, pubkey, acc), expected);
+        assert_eq!(MembershipWitness::verify_many(&items[11..12], pubkey, acc), vec![false]);
+        assert_eq!(MembershipWitness::verify_many(&items[12..13], pubkey, acc), vec![true]);
+    }
+
+    // Test constant-time equality
+    #[test]
+    fn wit_test_ct_eq() {
+        let (key, _, acc, elements) = init(2);
+        let (wit0, wit1) = (MembershipWitness::new(&elements[0], acc, &key).unwrap(), MembershipWitness::new(&elements[1], acc, &key).unwrap());
+
+        // Check ct_eq agrees with ==, also for the same point in other projective coordinates
+        assert!(bool::from(wit0.ct_eq(&wit0)));
+        assert!(!bool::from(wit0.ct_eq(&wit1)));
+        let doubled = MembershipWitness(wit0.0.double() - wit0.0);
+        assert_eq!(bool::from(wit0.ct_eq(&doubled)), wit0 == doubled);
+        assert!(bool::from(elements[0].ct_eq(&elements[0])));
+        assert!(!bool::from(elements[0].ct_eq(&elements[1])));
+        assert!(bool::from(elements[0].ct_eq(&Element::hash(b"0"))));
+
+        // Check ct_eq composes with other choices
+        assert!(!bool::from(wit0.ct_eq(&wit0) & elements[0].ct_eq(&elements[1])));
+    }
+
+    // Test byte serialization round trip
+    #[test]
+    fn wit_test_bytes_round_trip() {
+        let (key, pubkey, acc, elements) = init(1);
+        let wit = MembershipWitness::new(&elements[0], acc, &key).unwrap();
+
+        // Check parsed witness equals the original one and verifies against the same accumulator
+        let parsed = MembershipWitness::try_from(&wit.to_bytes()).expect("Cannot parse witness");
+        assert_eq!(parsed, wit);
+        assert!(parsed.verify(elements[0], pubkey, acc));
+    }
+
+    // Test point arithmetic
+    #[test]
+    fn wit_test_arithmetic() {
+        let (key, _, acc, elements) = init(2);
+        let w = MembershipWitness::new(&elements[0], acc, &key).unwrap();
+        let w2 = MembershipWitness::new(&elements[1], acc, &key).unwrap();
+        let s = Scalar::from(7u64);
+
+        assert_eq!(w + w2, MembershipWitness(w.0 + w2.0));
+        assert_eq!(w - w2, MembershipWitness(w.0 - w2.0));
+        assert_eq!(w * s, MembershipWitness(w.0 * s));
+        assert_eq!((w + w2) - w2, w);
+    }
+
+    #[test]
+    fn wit_test_batch_update_in_place() {
+        let (key, pubkey, mut acc, elements) = init(10);
+        let y = elements[0];
+        let mut wit = MembershipWitness::new(&y, acc, &key).unwrap();
+        let coefficients = acc.update_assign(&key, &elements[1..]);
+
+        // Owned update leaves the witness untouched
+        let updated = wit.batch_update(y, &elements[1..], &coefficients).expect("Cannot update witness");
+        assert!(!wit.verify(y, pubkey, acc));
+
+        // In-place update yields the same witness, which is then used for verification
+        wit.batch_update_assign(y, &elements[1..], &coefficients).expect("Cannot update witness");
+        assert_eq!(wit, updated);
+        assert!(wit.verify(y, pubkey, acc));
+    }
+
+    #[test]
+    fn wit_test_aggregate_batch_update() {
+        let (key, pubkey, mut acc, elements) = init(20);
+        let y = elements[0];
+        let mut wit = MembershipWitness::new(&y, acc, &key).unwrap();
+        let mut wit_d = MembershipWitness::new(&elements[15], acc, &key).unwrap();
+
+        // Revoke elements in two batches
+        let del_1 = &elements[1..10];
+        let omega_1 = acc.update_assign(&key, del_1);
+        let del_2 = &elements[10..];
+        let omega_2 = acc.update_assign(&key, del_2);
+        let updates = [(del_1, omega_1.as_slice()), (del_2, omega_2.as_slice())];
+
+        // Aggregated update matches the sequential one
+        let mut wit_seq = wit;
+        wit_seq.batch_update_assign(y, del_1, &omega_1).expect("Cannot update witness");
+        wit_seq.batch_update_assign(y, del_2, &omega_2).expect("Cannot update witness");
+        wit.aggregate_batch_update_assign(y, &updates).expect("Cannot update witness");
+        assert_eq!(wit, wit_seq);
+        assert!(wit.verify(y, pubkey, acc));
+
+        // Revoked element cannot be updated
+        let old = wit_d;
+        assert!(wit_d.aggregate_batch_update_assign(elements[15], &updates).is_err());
+        assert_eq!(wit_d, old);
+    }
+
+    #[test]
+    fn wit_test_adaptive_batch_update() {
+        // Selector crosses over exactly at the threshold
+        let t = MembershipWitness::MSM_THRESHOLD;
+        assert!(!MembershipWitness::batch_update_uses_msm(t - 1));
+        assert!(MembershipWitness::batch_update_uses_msm(t));
+
+        let (key, pubkey, _, elements) = init(2 * t + 2);
+        let y = elements[0];
+        for n in [1, 2, 8, 9, t - 1, t, t + 1, 2 * t] {
+            // Revoke n elements from a fresh accumulator
+            let mut acc = Accumulator::random(rand_core::OsRng {});
+            let wit = MembershipWitness::new(&y, acc, &key).unwrap();
+            let deletions = &elements[1..=n];
+            let omega = acc.update_assign(&key, deletions);
+
+            // Adaptive, MSM and direct evaluation produce the same witness
+            let mut wit_adaptive = wit;
+            let mut wit_direct = wit;
+            wit_adaptive.batch_update_assign(y, deletions, &omega).expect("Cannot update witness");
+            wit_direct.batch_update_assign_direct(y, deletions, &omega).expect("Cannot update witness");
+            assert_eq!(wit_adaptive, wit_direct);
+            assert!(wit_direct.verify(y, pubkey, acc));
+        }
+    }
+
+    #[test]
+    fn wit_test_non_membership() {
+        let (key, pubkey, mut acc, elements) = init(4);
+        let x = elements[0];
+
+        // Revoke x, then issue its non-membership witness
+        let mem_wit = MembershipWitness::new(&x, acc, &key).unwrap();
+        acc.remove_assign(&key, x);
+        let mut wit = NonMembershipWitness::new(&x, acc, &key).expect("Cannot create non-membership witness");
+        assert!(wit.verify(x, pubkey, acc));
+        assert!(!wit.verify(elements[1], pubkey, acc));
+
+        // d is derived from x and the accumulator, not chosen at random
+        assert_eq!(NonMembershipWitness::new(&x, acc, &key), Ok(wit));
+        assert_eq!(wit.d, NonMembershipWitness::derive_d(&x, acc));
+        let other = NonMembershipWitness::new(&elements[1], acc, &key).unwrap();
+        assert_ne!(other.d, wit.d);
+
+        // A membership witness is not a non-membership witness
+        let fake = NonMembershipWitness { c: mem_wit.0, d: Scalar::ZERO };
+        assert!(!fake.verify(x, pubkey, acc));
+
+        // Degenerate witnesses and accumulators are rejected
+        let d = Scalar::random(rand_core::OsRng);
+        let c = (acc.0 - G1Projective::GENERATOR * d) * (x.0 + key.0).invert().unwrap();
+        assert!(NonMembershipWitness { c, d }.verify(x, pubkey, acc));
+        let v = Accumulator(G1Projective::GENERATOR * d);
+        assert!(!NonMembershipWitness { c: G1Projective::IDENTITY, d }.verify(x, pubkey, v));
+        assert!(!NonMembershipWitness { c: G1Projective::IDENTITY, d: Scalar::ZERO }.verify(x, pubkey, Accumulator(G1Projective::IDENTITY)));
+        assert_eq!(NonMembershipWitness::new(&x, Accumulator(G1Projective::IDENTITY), &key), Err(AccumulatorError::DegenerateAccumulator));
+
+        // Update the witness with later deletions
+        let deletions: Vec<Deletion> = elements[1..].iter().map(|&d| Deletion(acc.remove_assign(&key, d), d)).collect();
+        assert!(!wit.verify(x, pubkey, acc));
+        wit.update_assign(x, &deletions).expect("Cannot update witness");
+        assert!(wit.verify(x, pubkey, acc));
+
+        // Deleting x again fails and leaves the witness unmodified
+        let old = wit;
+        assert_eq!(wit.update_assign(x, &[Deletion(acc, x)]), Err(AccumulatorError::Revoked));
+        assert_eq!(wit, old);
+    }
+
+    #[test]
+    fn wit_test_manual_coefficients() {
+        let (key, pubkey, mut acc, elements) = init(6);
+        let y = elements[0];
+        let mut wit = MembershipWitness::new(&y, acc, &key).unwrap();
+
+        // Scale the secret coefficients by the accumulator before the deletions, then delete
+        let deletions = &elements[1..];
+        let omega: Vec<Coefficient> = key.create_coefficients(deletions).into_iter().map(|c| Coefficient(acc.0 * c.0)).collect();
+        assert_eq!(omega, acc.update(&key, deletions));
+        acc.remove_elements_assign(&key, deletions);
+
+        // Evaluating the polynomial with msm matches the direct evaluation
+        let poly = PolynomialG1(omega.iter().map(|c| c.0).collect());
+        assert_eq!(poly.msm(&y.0), poly.evaluate(&y.0));
+
+        // The manual coefficients drive the batch update
+        wit.batch_update_assign(y, deletions, &omega).expect("Cannot update witness");
+        assert!(wit.verify(y, pubkey, acc));
+    }
+
+    #[test]
+    fn wit_test_verify_update() {
+        let (key, pubkey, mut acc, elements) = init(6);
+        let y = elements[0];
+        let old = MembershipWitness::new(&y, acc, &key).unwrap();
+        let deletions = &elements[1..];
+        let omega = acc.update_assign(&key, deletions);
+
+        // Correctly applied update passes
+        let wit = old.batch_update(y, deletions, &omega).expect("Cannot update witness");
+        assert!(wit.verify_update(&old, y, deletions, &omega, pubkey, acc));
+
+        // Corrupted coefficients or witness fail
+        let mut corrupted = omega.clone();
+        corrupted[2] = Coefficient(corrupted[2].0 + G1Projective::GENERATOR);
+        assert!(!wit.verify_update(&old, y, deletions, &corrupted, pubkey, acc));
+        assert!(!MembershipWitness(wit.0.double()).verify_update(&old, y, deletions, &omega, pubkey, acc));
+        assert!(!wit.verify_update(&old, y, &deletions[1..], &omega, pubkey, acc));
+
+        // The witness produced by corrupted coefficients is rejected, although it satisfies the relation with them
+        let bad = old.batch_update(y, deletions, &corrupted).expect("Cannot update witness");
+        assert!(!bad.verify_update(&old, y, deletions, &corrupted, pubkey, acc));
+
+        // A valid witness for another accumulator value is rejected
+        assert!(!wit.verify_update(&old, y, deletions, &omega, pubkey, Accumulator::random(rand_core::OsRng {})));
+    }
+
+    #[test]
+    fn wit_test_versioned_bytes() {
+        let (key, _, acc, elements) = init(1);
+        let wit = MembershipWitness::new(&elements[0], acc, &key).unwrap();
+
+        // Current-version payload loads fine
+        let bytes = wit.to_versioned_bytes();
+        assert_eq!(MembershipWitness::from_versioned_bytes(&bytes).expect("Cannot load witness"), wit);
+
+        // Hand-crafted payload of an old version is rejected
+        let mut old = [0u8; MembershipWitness::VERSIONED_BYTES];
+        old[1..].copy_from_slice(&wit.to_bytes());
+        assert_eq!(MembershipWitness::from_versioned_bytes(&old).unwrap_err(), AccumulatorError::IncompatibleVersion(0));
+        assert_eq!(MembershipWitness::from_versioned_bytes(&wit.to_bytes()).unwrap_err(), AccumulatorError::IncompatibleVersion(bytes[1]));
+    }
+
+    #[test]
+    fn wit_test_error_variants() {
+        let (key, _, mut acc, elements) = init(3);
+        let wit = MembershipWitness::new(&elements[1], acc, &key).unwrap();
+        let coefficients = acc.update_assign(&key, &elements[1..]);
+
+        // Revoked element, empty update polynomial and malformed bytes
+        let err = wit.batch_update(elements[1], &elements[1..], &coefficients).unwrap_err();
+        assert_eq!(err, AccumulatorError::Revoked);
+        assert_eq!(wit.batch_update(elements[0], &elements[1..], &[]).unwrap_err(), AccumulatorError::LengthMismatch);
+        assert!(matches!(MembershipWitness::try_from(&[0xffu8; 48]), Err(AccumulatorError::BadEncoding)));
+
+        // Legacy error keeps code and message
+        let legacy = crate::Error::from(err);
+        assert_eq!(legacy.code, 8);
+        assert_eq!(legacy.to_string(), AccumulatorError::Revoked.to_string());
+    }
+
+    // Test malformed batch updates are rejected leaving the witness unmodified
+    #[test]
+    fn wit_test_batch_update_malformed() {
+        let (key, pubkey, mut acc, elements) = init(4);
+        let mut wit = MembershipWitness::new(&elements[0], acc, &key).unwrap();
+        let unchanged = wit;
+        let coefficients = acc.update_assign(&key, &elements[1..]);
+
+        // Empty deletions, with or without coefficients
+        assert_eq!(wit.batch_update_assign(elements[0], &[], &[]).unwrap_err(), AccumulatorError::EmptyInput);
+        assert_eq!(wit.batch_update_assign(elements[0], &[], &coefficients).unwrap_err(), AccumulatorError::EmptyInput);
+        assert_eq!(wit, unchanged);
+
+        // One coefficient less or more than the deletions
+        let err = wit.batch_update_assign(elements[0], &elements[1..], &coefficients[1..]).unwrap_err();
+        assert_eq!(err, AccumulatorError::LengthMismatch);
+        let err = wit.batch_update_assign(elements[0], &elements[2..], &coefficients).unwrap_err();
+        assert_eq!(err, AccumulatorError::LengthMismatch);
+        assert_eq!(wit, unchanged);
+
+        // Same checks for each aggregated update
+        let valid: (&[Element], &[Coefficient]) = (&elements[1..], &coefficients);
+        assert_eq!(wit.aggregate_batch_update_assign(elements[0], &[valid, (&[], &[])]).unwrap_err(), AccumulatorError::EmptyInput);
+        let err = wit.aggregate_batch_update_assign(elements[0], &[valid, (&elements[1..], &coefficients[..1])]).unwrap_err();
+        assert_eq!(err, AccumulatorError::LengthMismatch);
+        assert_eq!(wit, unchanged);
+
+        // Well-formed update still succeeds
+        wit.batch_update_assign(elements[0], &elements[1..], &coefficients).expect("Cannot update witness");
+        assert!(wit.verify(elements[0], pubkey, acc));
+    }
+
+    /// Deletion set given as indices in [y, y_1, y_2, 0], with the expected result of the sequential and of the batch update
+    type EdgeCase = (&'static [usize], Result<(), AccumulatorError>, Result<(), AccumulatorError>);
+
+    /// Seed corpus of adversarial deletion sets
+    const EDGE_CASES: &[EdgeCase] = &[
+        (&[0], Err(AccumulatorError::Revoked), Err(AccumulatorError::Revoked)),
+        (&[1, 0, 2], Err(AccumulatorError::Revoked), Err(AccumulatorError::Revoked)),
+        (&[0, 0], Err(AccumulatorError::Revoked), Err(AccumulatorError::Revoked)),
+        (&[], Ok(()), Err(AccumulatorError::EmptyInput)),
+        (&[1, 1], Ok(()), Ok(())),
+        (&[1, 2, 1, 2], Ok(()), Ok(())),
+        (&[3], Ok(()), Ok(())),
+    ];
+
+    // Test both update paths return the documented result on edge cases, without panicking
+    #[test]
+    fn wit_test_update_edge_cases() {
+        let (key, pubkey, acc, elements) = init(3);
+        let y = elements[0];
+        let candidates = [y, elements[1], elements[2], Element(Scalar::ZERO)];
+        let wit = MembershipWitness::new(&y, acc, &key).unwrap();
+
+        for (case, seq_expected, batch_expected) in EDGE_CASES {
+            let deleted: Vec<Element> = case.iter().map(|&i| candidates[i]).collect();
+
+            // Sequential update
+            let mut seq_acc = acc;
+            let deletions: Vec<Deletion> = deleted.iter().map(|&d| Deletion(seq_acc.remove_assign(&key, d), d)).collect();
+            let mut seq_wit = wit;
+            assert_eq!(&seq_wit.update_assign(y, &deletions), seq_expected, "Sequential update of {case:?}");
+            assert!((seq_expected.is_err() && seq_wit == wit) || seq_wit.verify(y, pubkey, seq_acc));
+
+            // Batch update
+            let mut batch_acc = acc;
+            let omega = batch_acc.update_assign(&key, &deleted);
+            let mut batch_wit = wit;
+            assert_eq!(&batch_wit.batch_update_assign(y, &deleted, &omega), batch_expected, "Batch update of {case:?}");
+            assert!((batch_expected.is_err() && batch_wit == wit) || batch_wit.verify(y, pubkey, batch_acc));
+            let mut aggr_wit = wit;
+            let result = aggr_wit.aggregate_batch_update_assign(y, &[(&deleted, &omega)]);
+            assert_eq!(&result, batch_expected, "Aggregated update of {case:?}");
+            assert_eq!(aggr_wit, batch_wit);
+        }
+    }
+
+    #[test]
+    fn wit_test_batch_update_with_powers() {
+        // Successive batch updates sharing the cached powers match the plain updates
+        let (key, pubkey, mut acc, elements) = init(15);
+        let mut wit = MembershipWitness::new(&elements[0], acc, &key).unwrap();
+        let mut cached = wit;
+        let mut powers = ElementPowers::new(elements[0]);
+        for deletions in [&elements[1..10], &elements[10..12], &elements[12..]] {
+            let omega = acc.update_assign(&key, deletions);
+            wit.batch_update_assign(elements[0], deletions, &omega).expect("Cannot update witness");
+            cached.batch_update_assign_with_powers(&mut powers, deletions, &omega).expect("Cannot update witness");
+            assert_eq!(cached, wit);
+        }
+        assert!(cached.verify(elements[0], pubkey, acc));
+        assert_eq!(powers.num_powers(), 9);
+        assert_eq!(powers.get(3)[2], elements[0].0.square());
+
+        // Same errors as the plain update
+        let omega = acc.update_assign(&key, &elements[..1]);
+        assert_eq!(cached.batch_update_assign_with_powers(&mut powers, &elements[..1], &omega).unwrap_err(), AccumulatorError::Revoked);
+        assert_eq!(cached.batch_update_assign_with_powers(&mut powers, &elements[1..], &omega).unwrap_err(), AccumulatorError::LengthMismatch);
+        assert_eq!(cached, wit);
+    }
+
+    // Test chunked and monolithic batch updates agree on a large deletion set
+    #[test]
+    fn wit_test_batch_update_chunked() {
+        const DELETIONS: usize = 50_000;
+        const WINDOW: usize = 4096;
+
+        // Check chunked update of a real batch deletion is valid for any window
+        let (key, pubkey, mut acc, elements) = init(11);
+        let wit = MembershipWitness::new(&elements[0], acc, &key).unwrap();
+        let omega = acc.update_assign(&key, &elements[1..]);
+        let full = wit.batch_update(elements[0], &elements[1..], &omega).expect("Cannot update witness");
+        for window in [1, 3, 10, 20] {
+            let mut chunked = wit;
+            chunked.batch_update_assign_chunked(elements[0], &elements[1..], &omega, window).expect("Cannot update witness");
+            assert_eq!(chunked, full);
+        }
+        assert!(full.verify(elements[0], pubkey, acc));
+
+        // Large synthetic update: random deletions and coefficients i*G, since the result only depends on y, dD and Ω
+        let y = Element::random();
+        let deletions: Vec<Element> = (0..DELETIONS).map(|_| Element::random()).collect();
+        let mut omega = Vec::with_capacity(DELETIONS);
+        let mut c = G1Projective::GENERATOR;
+        for _ in 0..DELETIONS {
+            omega.push(Coefficient(c));
+            c += G1Projective::GENERATOR;
+        }
+
+        let t = Instant::now();
+        let full = wit.batch_update(y, &deletions, &omega).expect("Cannot update witness");
+        println!("Time for full batch update with {DELETIONS} deletions: {:?}", t.elapsed());
+        let mut chunked = wit;
+        let t = Instant::now();
+        chunked.batch_update_assign_chunked(y, &deletions, &omega, WINDOW).expect("Cannot update witness");
+        println!("Time for chunked batch update with {DELETIONS} deletions and window {WINDOW}: {:?}", t.elapsed());
+        assert_eq!(chunked, full);
+
+        // Streaming the deletions and unevenly sized windows of coefficients yields the same witness
+        let mut streamed = wit;
+        let windows = [&omega[..1000], &omega[1000..1000], &omega[1000..20_000], &omega[20_000..]];
+        streamed.batch_update_assign_streamed(y, deletions.iter().copied(), windows).expect("Cannot update witness");
+        assert_eq!(streamed, full);
+
+        // Same errors as the monolithic update
+        let mut streamed = wit;
+        assert_eq!(streamed.batch_update_assign_streamed(y, core::iter::empty(), omega.chunks(WINDOW)).unwrap_err(), AccumulatorError::EmptyInput);
+        assert_eq!(streamed.batch_update_assign_streamed(y, deletions.iter().copied(), omega[1..].chunks(WINDOW)).unwrap_err(), AccumulatorError::LengthMismatch);
+        assert_eq!(streamed.batch_update_assign_streamed(deletions[7], deletions.iter().copied(), omega.chunks(WINDOW)).unwrap_err(), AccumulatorError::Revoked);
+        assert_eq!(streamed, wit);
+        let mut chunked = wit;
+        assert_eq!(chunked.batch_update_assign_chunked(deletions[7], &deletions, &omega, WINDOW).unwrap_err(), AccumulatorError::Revoked);
+        assert_eq!(chunked.batch_update_assign_chunked(y, &deletions[1..], &omega, WINDOW).unwrap_err(), AccumulatorError::LengthMismatch);
+        assert_eq!(chunked, wit);
+    }
+
+    #[test]
+    fn wit_test_prepared_verifier() {
+        let (key, pubkey, acc, elements) = init(20);
+        let ver = PreparedWitnessVerifier::new(pubkey);
+
+        // Fixed-base multiplication matches the generic one, including edge scalars
+        for y in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE, elements[0].0] {
+            assert_eq!(ver.mul_generator(&y), G2Projective::GENERATOR * y);
+        }
+
+        // Prepared verification agrees with the plain one
+        let t = Instant::now();
+        elements.iter().for_each(|y| {
+            let wit = MembershipWitness::new(y, acc, &key).unwrap();
+            assert!(ver.verify(&wit, *y, acc));
+            assert_eq!(ver.verify(&wit, elements[0], acc), wit.verify(elements[0], pubkey, acc));
+        });
+        println!("Time to verify {} witnesses with prepared verifier: {:?}", 2 * elements.len(), t.elapsed());
+    }
+
+    // Sequential update interrupted and resumed from a persisted cursor
+    #[test]
+    fn wit_test_resumable_update() {
+        // Init parameters
+        let key = SecretKey::new(Some(b"resumable"));
+        let mut acc = Accumulator::random(rand_core::OsRng {});
+        let y = Element::hash(b"holder");
+        let wit = MembershipWitness::new(&y, acc, &key).unwrap();
+
+        // Revoke 1000 elements sequentially, and update the witness in one shot
+        let deletions = acc.remove_batch(&key, &Element::sample_n(1000));
+        let expected = wit.update(y, &deletions).unwrap();
+
+        // Interrupt the update after an arbitrary number of deletions, persisting the cursor
+        let mut cursor = UpdateCursor::new(wit);
+        assert!(!cursor.resume(y, &deletions, 437).unwrap());
+        assert_eq!(cursor.applied, 437);
+        let bytes = bincode::serialize(&cursor).expect("Serialization error!");
+
+        // Check resuming the restored cursor against another update is rejected, leaving it unmodified
+        let mut cursor: UpdateCursor = bincode::deserialize(&bytes).expect("Deserialization error");
+        assert_eq!(cursor.resume(y, &deletions[1..], 10), Err(AccumulatorError::ConflictingUpdates));
+        assert_eq!(cursor.resume(y, &deletions[..400], 10), Err(AccumulatorError::ConflictingUpdates));
+        assert_eq!(cursor.applied, 437);
+
+        // Resume in steps and check the final witness equals the one-shot update
+        while !cursor.resume(y, &deletions, 200).unwrap() {}
+        assert!(cursor.is_complete(&deletions));
+        assert_eq!(cursor.witness, expected);
+        assert!(cursor.witness.verify(y, PublicKey::from(&key), acc));
+
+        // Check a revoked element's cursor does not advance past the step deleting it
+        let revoked = deletions[600].1;
+        let mut cursor = UpdateCursor::new(MembershipWitness::new(&revoked, deletions[599].0, &key).unwrap());
+        cursor.applied = 600;
+        cursor.accumulator = Some(deletions[599].0);
+        assert_eq!(cursor.resume(revoked, &deletions, 50), Err(AccumulatorError::Revoked));
+        assert_eq!(cursor.applied, 600);
+    }
+
     // Test serialization
     #[test]
     fn wit_test_serialize() {
@@ -328,7 +1565,7 @@ mod tests {
         // Init parameters
         let sk = SecretKey::new(Some(b"test"));
         let acc = Accumulator::random(rand_core::OsRng {});
-        let wit = MembershipWitness::new(&Element::hash(b"test"), acc, &sk);
+        let wit = MembershipWitness::new(&Element::hash(b"test"), acc, &sk).unwrap();
 
         // Try serialize and deserialize
         let bytes = bincode::serialize(&wit).expect("Serialization error!");
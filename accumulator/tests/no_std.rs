@@ -0,0 +1,30 @@
+//! Smoke test for the `no_std` build of the crate.
+//!
+//! Run with `cargo test --no-default-features --test no_std`: the library is
+//! then compiled under `#![no_std]` (alloc only) and only this harness links `std`.
+
+use accumulator::{Accumulator, Element, MembershipWitness, PublicKey, SecretKey};
+
+#[test]
+fn no_std_batch_update_assign() {
+    // Setup issuer without relying on an OS rng
+    let key = SecretKey::new(Some(b"no_std smoke test"));
+    let pubkey = PublicKey::from(&key);
+    let mut acc = Accumulator::hash_to_curve(b"no_std seed", b"NO_STD_SMOKE_TEST");
+    let elements: Vec<Element> = (0..10u32).map(|i| Element::hash(&i.to_le_bytes())).collect();
+
+    // Issue witnesses for a non-revoked and a revoked element
+    let y = elements[0];
+    let mut wit = MembershipWitness::new(&y, acc, &key).unwrap();
+    let y_d = elements[1];
+    let mut wit_d = MembershipWitness::new(&y_d, acc, &key).unwrap();
+
+    // Revoke all but the first element and batch update both witnesses
+    let deletions = &elements[1..];
+    let coefficients = acc.update_assign(&key, deletions);
+    wit.batch_update_assign(y, deletions, &coefficients).expect("Cannot update witness");
+    assert!(wit_d.batch_update_assign(y_d, deletions, &coefficients).is_err());
+
+    assert!(wit.verify(y, pubkey, acc));
+    assert!(!wit_d.verify(y_d, pubkey, acc));
+}
@@ -0,0 +1,28 @@
+//! Build test for witness-only consumers of the crate.
+//!
+//! Run with `cargo test --no-default-features --test witness_only`: the `proofs` feature is then disabled,
+//! hence the proof module and `merlin` are not compiled, while the witness updates still work.
+#![cfg(not(feature = "proofs"))]
+
+use accumulator::{Accumulator, Element, MembershipWitness, PublicKey, SecretKey};
+
+#[test]
+fn witness_only_batch_update() {
+    // Setup issuer and witnesses
+    let key = SecretKey::new(Some(b"witness-only build test"));
+    let pubkey = PublicKey::from(&key);
+    let mut acc = Accumulator::hash_to_curve(b"witness-only seed", b"WITNESS_ONLY_TEST");
+    let elements: Vec<Element> = (0..10u32).map(|i| Element::hash(&i.to_le_bytes())).collect();
+    let y = elements[0];
+    let mut wit = MembershipWitness::new(&y, acc, &key).unwrap();
+    let y_d = elements[1];
+    let mut wit_d = MembershipWitness::new(&y_d, acc, &key).unwrap();
+
+    // Revoke all but the first element and batch update both witnesses
+    let deletions = &elements[1..];
+    let coefficients = acc.update_assign(&key, deletions);
+    wit.batch_update_assign(y, deletions, &coefficients).expect("Cannot update witness");
+    assert!(wit_d.batch_update_assign(y_d, deletions, &coefficients).is_err());
+    assert!(wit.verify(y, pubkey, acc));
+    assert!(!wit_d.verify(y_d, pubkey, acc));
+}
@@ -0,0 +1,122 @@
+//! Property tests of the witness updates: for any key, member set and non-empty revoked subset,
+//! the batch (`batch_update_assign`, with Multi Scalar Multiplication for large batches), direct evaluation
+//! (`batch_update_assign_direct`) and sequential (`update_assign`) updates yield the same witness,
+//! which verifies if and only if the element is not revoked.
+//!
+//! The harness is a plain loop over a fixed list of cases, not a property testing framework: there is no shrinking.
+//! Instead, cases are generated from the smallest up, exhaustively for small member sets and then pseudo-randomly
+//! from a fixed seed, and checked in that order, so that the first failing case reported is the smallest one of the list
+//! and is reproducible from its parameters.
+
+use accumulator::{Accumulator, Element, MembershipWitness, PublicKey, SecretKey};
+
+/// Number of members enumerated exhaustively, with all their non-empty revoked subsets.
+const EXHAUSTIVE_MEMBERS: usize = 4;
+/// Number of pseudo-random cases, with up to `MAX_MEMBERS` members.
+const RANDOM_CASES: u64 = 8;
+const MAX_MEMBERS: usize = 40;
+
+/// A member set of `revoked.len()` elements derived from `seed`, of which those flagged in `revoked` are deleted.
+#[derive(Debug)]
+struct Case {
+    seed: u64,
+    revoked: Vec<bool>,
+}
+
+/// SplitMix64, a small deterministic generator to derive the cases.
+fn split_mix(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+/// Returns the cases, smallest first: all the non-empty revoked subsets of up to `EXHAUSTIVE_MEMBERS` members,
+/// then revoking all but one member and random subsets of random member sets.
+fn cases() -> Vec<Case> {
+    let mut cases = Vec::new();
+    for members in 1..=EXHAUSTIVE_MEMBERS {
+        for mask in 1..(1u32 << members) {
+            let revoked = (0..members).map(|i| mask & (1 << i) != 0).collect();
+            cases.push(Case { seed: members as u64, revoked });
+        }
+    }
+
+    let mut state = 0x5eed;
+    for _ in 0..RANDOM_CASES {
+        let seed = split_mix(&mut state);
+        let members = EXHAUSTIVE_MEMBERS + 1 + (split_mix(&mut state) as usize) % (MAX_MEMBERS - EXHAUSTIVE_MEMBERS);
+        let kept = (split_mix(&mut state) as usize) % members;
+        cases.push(Case { seed, revoked: (0..members).map(|i| i != kept).collect() });
+
+        let mut revoked: Vec<bool> = (0..members).map(|_| split_mix(&mut state).is_multiple_of(2)).collect();
+        revoked[kept] = true;
+        cases.push(Case { seed, revoked });
+    }
+    cases.sort_by_key(|case| case.revoked.len());
+    cases
+}
+
+/// Checks the properties on `case`, returning the first violated one.
+fn check(case: &Case) -> Result<(), String> {
+    let seed = case.seed.to_le_bytes();
+    let key = SecretKey::new(Some(&seed));
+    let pubkey = PublicKey::from(&key);
+    let acc = Accumulator::hash_to_curve(&seed, b"WITNESS_PROPERTIES");
+    let elements: Vec<Element> = (0..case.revoked.len() as u64)
+        .map(|i| Element::hash_with_domain(&seed, &i.to_le_bytes()))
+        .collect();
+    let deleted: Vec<Element> = elements.iter().zip(&case.revoked).filter(|(_, &r)| r).map(|(&y, _)| y).collect();
+
+    // Batch and sequential forms of the same revocation
+    let mut batch_acc = acc;
+    let coefficients = batch_acc.update_assign(&key, &deleted);
+    let mut seq_acc = acc;
+    let sequential = seq_acc.remove_batch(&key, &deleted);
+    if batch_acc != seq_acc {
+        return Err("batch and sequential revocations give different accumulators".into());
+    }
+
+    for (i, (&y, &revoked)) in elements.iter().zip(&case.revoked).enumerate() {
+        let wit = MembershipWitness::new(&y, acc, &key).map_err(|e| format!("member {i}: {e}"))?;
+        let (mut batch, mut direct, mut seq) = (wit, wit, wit);
+        let results = [
+            batch.batch_update_assign(y, &deleted, &coefficients),
+            direct.batch_update_assign_direct(y, &deleted, &coefficients),
+            seq.update_assign(y, &sequential),
+        ];
+
+        if revoked {
+            // (c) Whether the update fails or not, the witness of a revoked element never verifies
+            let mut distinct = vec![batch];
+            distinct.extend([direct, seq].into_iter().filter(|w| *w != batch));
+            if distinct.iter().any(|w| w.verify(y, pubkey, batch_acc)) {
+                return Err(format!("revoked member {i} verifies"));
+            }
+        } else {
+            // (a) All the updates succeed and agree, and (b) the witness verifies
+            if let Some(e) = results.iter().find_map(|r| r.clone().err()) {
+                return Err(format!("member {i} failed to update: {e}"));
+            }
+            if batch != direct || batch != seq {
+                return Err(format!("updates of member {i} disagree"));
+            }
+            if !batch.verify(y, pubkey, batch_acc) {
+                return Err(format!("updated member {i} does not verify"));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn witness_update_properties() {
+    let cases = cases();
+    assert!(cases.iter().any(|case| case.revoked.iter().filter(|&&r| r).count() >= MembershipWitness::MSM_THRESHOLD));
+    for case in &cases {
+        if let Err(e) = check(case) {
+            panic!("{e} for {case:?}");
+        }
+    }
+}
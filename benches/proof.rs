@@ -0,0 +1,111 @@
+use accumulator::{proof::{ProofCommitting, PROOF_LABEL}, Coefficient, Element, ElementPowers, MembershipWitness, ProofParamsPrivate};
+use bls12_381_plus::{G1Projective, Scalar};
+use entities::{Issuer, Verifier};
+use criterion::{
+    criterion_group, criterion_main, Criterion,
+};
+use merlin::Transcript;
+
+
+//-------BENCHMARK PARAMETERS ------//
+
+const NUM_SAMPLES: usize = 10; // the number of samples for each benchmark
+const DELETIONS: [usize; 3] = [100, 1000, 10_000]; // the number of deletions of the update applied before proving
+
+
+criterion_group!(name = benches;
+    config = Criterion::default().sample_size(NUM_SAMPLES);
+    targets = proof_generation, update_and_prove
+);
+criterion_main!(benches);
+
+
+// Benchmarks the phases of membership proof generation: commitment (group operations),
+// transcript (serialization and hashing of params and commitments), and final Schnorr responses (scalar math).
+fn proof_generation(c: &mut Criterion) {
+    c.benchmark_group("proof_generation");
+
+    // Creates an issuer with one holder
+    let mut issuer = Issuer::new(None);
+    let rh = issuer.add("holder").unwrap();
+    let pp = issuer.get_proof_params();
+    let priv_params = ProofParamsPrivate::new(rh.get_elem(), &rh.get_witness());
+    let pc = ProofCommitting::new(&pp, &priv_params);
+
+    // Challenge derivation, shared by the transcript and full proof benchmarks
+    let challenge = |pc: ProofCommitting| {
+        let mut transcript = Transcript::new(PROOF_LABEL);
+        pp.add_to_transcript(&mut transcript);
+        pc.get_bytes_for_challenge(&mut transcript);
+        Element::from_transcript(PROOF_LABEL, &mut transcript)
+    };
+    let challenge_hash = challenge(pc);
+
+    // The benchmarked phases still produce a valid proof
+    assert!(Verifier::new(pp).verify(pc.gen_proof(challenge_hash)));
+
+    c.bench_function("Proof commitment", |b| {
+        b.iter(|| ProofCommitting::new(&pp, &priv_params))
+    });
+
+    c.bench_function("Proof transcript", |b| {
+        b.iter(|| challenge(pc))
+    });
+
+    c.bench_function("Proof responses", |b| {
+        b.iter(|| pc.gen_proof(challenge_hash))
+    });
+
+    c.bench_function("Proof generation", |b| {
+        b.iter(|| {
+            let pc = ProofCommitting::new(&pp, &priv_params);
+            pc.gen_proof(challenge(pc))
+        })
+    });
+}
+
+
+// Benchmarks a batch update followed by a proof generation, recomputing the powers of the holder's element
+// for each update or reading them from a warm `ElementPowers` cache, as done by `Holder::batch_update`.
+// The cache saves the m-1 scalar multiplications computing 1, y, ..., y^(m-1) for an update with m deletions.
+fn update_and_prove(c: &mut Criterion) {
+    c.benchmark_group("update_and_prove");
+
+    // Creates an issuer with one holder
+    let mut issuer = Issuer::new(None);
+    let rh = issuer.add("holder").unwrap();
+    let pp = issuer.get_proof_params();
+    let (y, wit) = (rh.get_elem(), rh.get_witness());
+
+    for m in DELETIONS {
+        // Synthetic update: random deletions and coefficients i*G, since the cost only depends on m
+        let deletions: Vec<Element> = (0..m).map(|_| Element::random()).collect();
+        let omega: Vec<Coefficient> = (1..=m as u64).map(|i| Coefficient(G1Projective::GENERATOR * Scalar::from(i))).collect();
+        let mut powers = ElementPowers::new(y);
+        powers.get(m);
+
+        let prove = |w: MembershipWitness| {
+            let pc = ProofCommitting::new(&pp, &ProofParamsPrivate::new(y, &w));
+            let mut transcript = Transcript::new(PROOF_LABEL);
+            pp.add_to_transcript(&mut transcript);
+            pc.get_bytes_for_challenge(&mut transcript);
+            pc.gen_proof(Element::from_transcript(PROOF_LABEL, &mut transcript))
+        };
+
+        c.bench_function(&format!("Update with {m} deletions and prove"), |b| {
+            b.iter(|| {
+                let mut w = wit;
+                w.batch_update_assign(y, &deletions, &omega).unwrap();
+                prove(w)
+            })
+        });
+
+        c.bench_function(&format!("Update with {m} deletions and prove (cached powers)"), |b| {
+            b.iter(|| {
+                let mut w = wit;
+                w.batch_update_assign_with_powers(&mut powers, &deletions, &omega).unwrap();
+                prove(w)
+            })
+        });
+    }
+}
@@ -1,12 +1,9 @@
-use entities::issuer::Issuer;
+#![allow(dead_code)]
 use accumulator::{
-    Accumulator, Deletion, Element, MembershipWitness, PublicKey, SecretKey
+    window_mul, Accumulator, Deletion, Element, MembershipWitness, PublicKey, SecretKey
 };
-use bls12_381_plus::ff::Field;
 use bls12_381_plus::Scalar;
-use serde::{Serialize, Serializer};
-use core::num;
-use std::collections::{hash_map, HashMap, HashSet};
+use entities::Issuer;
 use std::vec::Vec;
 use criterion::{
     criterion_group, criterion_main, Criterion,
@@ -34,12 +31,13 @@ const UPDATES: [usize; 10] = [10_000, 20_000, 30_000, 40_000, 50_000, 60_000, 70
 const CLIENT_UPDATES: [usize; 21] = [1, 500, 1000, 1500, 2000, 2500, 3000, 3500, 4000, 4500, 5000, 5500, 6000, 6500, 7000, 7500, 8000, 8500, 9000, 9500, 10000];
 const BATCH_CLIENT_UPDATES: [usize; 21] = [1, 500, 1000, 1500, 2000, 2500, 3000, 3500, 4000, 4500, 5000, 5500, 6000, 6500, 7000, 7500, 8000, 8500, 9000, 9500, 10000];
 const NUM_SAMPLES: usize = 30; // the number of samples for each benchmark
+const BULK_ADDITIONS: usize = 50_000; // Number of holders enrolled one by one in the bulk enrolment benchmark
 
 
 
 criterion_group!(name = benches;
     config = Criterion::default().sample_size(NUM_SAMPLES);
-    targets = client_batch_update
+    targets = client_batch_update, powers_of_y, issuer_bulk_add
 );
 criterion_main!(benches);
 
@@ -61,10 +59,7 @@ fn issuer_update(c: &mut Criterion) {
         let key = SecretKey::new(None);
         let items: Vec<Element> = (0..USERS).map(|_| Element::random()).collect();
         let mut acc = Accumulator::random(rand_core::OsRng {});
-        let mut witness: Vec<MembershipWitness> = (0..USERS).map(|i| MembershipWitness::new(&items[i], acc, &key)).collect();
-
-        //Creats a witness for some user
-        let y = items.last().unwrap().clone();
+        let mut witness: Vec<MembershipWitness> = (0..USERS).map(|i| MembershipWitness::new(&items[i], acc, &key).unwrap()).collect();
 
         // Gets set of updates and deletions
         let (non_revoked, revoked) = items.split_at(num_upds);
@@ -83,14 +78,14 @@ fn issuer_update(c: &mut Criterion) {
             })
         });
         
-        // Benchmark of issuer update
+        // Benchmark of issuer update, recomputing the witnesses C_i = V*(𝛼+y_i)^-1 of the remaining users
         c.bench_function("Issuer", |b| {
             b.iter(|| {
-                for wit in witness.iter_mut(){
-                    wit.fast_update_assign(&coeff);
-                }
+                let coefficients = key.batch_inverses(non_revoked).into_iter().map(|c| c.0).collect();
+                witness = window_mul(acc.0, coefficients).into_iter().map(MembershipWitness).collect();
             })
         });
+        assert!(witness[0].verify(non_revoked[0], PublicKey::from(&key), acc));
 
 
         // Adds up the length of each message that must be sent
@@ -118,7 +113,7 @@ fn client_update(c: &mut Criterion) {
         let key = SecretKey::new(None);
         let items: Vec<Element> = (0..USERS).map(|_| Element::random()).collect();
         let mut acc = Accumulator::random(rand_core::OsRng {});
-        let mut witness: Vec<MembershipWitness> = (0..USERS).map(|i| MembershipWitness::new(&items[i], acc, &key)).collect();
+        let witness: Vec<MembershipWitness> = (0..USERS).map(|i| MembershipWitness::new(&items[i], acc, &key).unwrap()).collect();
 
         
 
@@ -127,20 +122,20 @@ fn client_update(c: &mut Criterion) {
         
         //Update a witness for some user
         let pos = witness.len()-1;
-        let y = items[pos].clone();
+        let y = items[pos];
         let wit = witness[pos];
         
         let mut deletions: Vec<Deletion> = Vec::new();
         
         revoked.iter().for_each(|&d| {
             acc.remove_assign(&key, d);
-            deletions.push(Deletion{0: acc, 1: d});
+            deletions.push(Deletion(acc, d));
         });
         
 
         c.bench_function("Client_Deletion", |b| {
             b.iter(|| {
-                wit.update(y, &deletions);
+                let _ = wit.update(y, &deletions);
             })
         });
 
@@ -171,8 +166,8 @@ fn client_batch_update(c: &mut Criterion) {
         let mut acc = Accumulator::random(rand_core::OsRng {});
 
         // Takes the last user, gives them a witness
-        let y = items.last().unwrap().clone();
-        let mut witness = MembershipWitness::new(&y, acc, &key);
+        let y = *items.last().unwrap();
+        let mut witness = MembershipWitness::new(&y, acc, &key).unwrap();
 
         // Creates lists of elements delete
         let (deletions, _) = items.split_at(num_ups);
@@ -184,15 +179,46 @@ fn client_batch_update(c: &mut Criterion) {
         // Benchmarks user response
         c.bench_function("Batch update user-side update", |b| {
             b.iter(|| {
-                witness.batch_update(y, &deletions, &coefficients);
+                let _ = witness.batch_update(y, &deletions, &coefficients);
             })
         });
 
-        witness.batch_update_assign(y, &deletions, &coefficients);
+        witness.batch_update_assign(y, &deletions, &coefficients).expect("Error when evaluating poly");
         assert!(witness.verify(y, PublicKey::from(&key), acc));
     }
 }
 
+// Powers 1, y, ..., y^d fed to the MSM of the batch update, computed in parallel with `--features parallel`
+fn powers_of_y(c: &mut Criterion) {
+    c.benchmark_group("powers_of_y");
+    let y = Element::random().0;
+
+    for degree in [1_000, 10_000, 100_000] {
+        c.bench_function(&format!("Powers of y up to degree {degree}"), |b| {
+            b.iter(|| accumulator::powers(&y, degree + 1))
+        });
+    }
+}
+
+// Enrolment of many holders one by one, with and without reserving the issuer's capacity upfront
+fn issuer_bulk_add(c: &mut Criterion) {
+    c.benchmark_group("issuer_bulk_add");
+    let pseudos: Vec<String> = (0..BULK_ADDITIONS).map(|i| i.to_string()).collect();
+
+    c.bench_function(&format!("Add {BULK_ADDITIONS} holders"), |b| {
+        b.iter(|| {
+            let mut issuer = Issuer::new(None);
+            pseudos.iter().for_each(|p| { issuer.add(p.as_str()).unwrap(); });
+        })
+    });
+    c.bench_function(&format!("Add {BULK_ADDITIONS} holders with reserved capacity"), |b| {
+        b.iter(|| {
+            let mut issuer = Issuer::with_capacity(None, BULK_ADDITIONS);
+            pseudos.iter().for_each(|p| { issuer.add(p.as_str()).unwrap(); });
+        })
+    });
+}
+
 /* 
 // Multiparty threshold updates in ALLOSAUR
 fn allosaur_update(c: &mut Criterion) {
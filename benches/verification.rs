@@ -0,0 +1,93 @@
+use accumulator::{Accumulator, Element, MembershipWitness, PreparedWitnessVerifier, PublicKey, SecretKey};
+use entities::{FederatedVerifier, Holder, Issuer, Verifier};
+use criterion::{
+    criterion_group, criterion_main, Criterion,
+};
+
+
+//-------BENCHMARK PARAMETERS ------//
+
+const PROOFS: usize = 10; // Number of interleaved proofs per issuer
+const WITNESSES: usize = 100; // Number of distinct-element witness verifications
+const NUM_SAMPLES: usize = 10; // the number of samples for each benchmark
+
+
+criterion_group!(name = benches;
+    config = Criterion::default().sample_size(NUM_SAMPLES);
+    targets = federated_verification, witness_verification
+);
+criterion_main!(benches);
+
+
+// Benchmarks the verification of interleaved proofs from two issuers
+fn federated_verification(c: &mut Criterion) {
+    c.benchmark_group("federated_verification");
+
+    // Creates two issuers with one holder each
+    let (mut issuer1, mut issuer2) = (Issuer::new(None), Issuer::new(None));
+    let mut holder1 = Holder::new("holder1", issuer1.add("holder1").unwrap(), issuer1.get_proof_params());
+    let mut holder2 = Holder::new("holder2", issuer2.add("holder2").unwrap(), issuer2.get_proof_params());
+    let (id1, id2) = (holder1.get_accumulator_id(), holder2.get_accumulator_id());
+
+    // Creates interleaved proofs
+    let proofs: Vec<_> = (0..PROOFS)
+        .flat_map(|_| [(id1, holder1.proof_membership_cached()), (id2, holder2.proof_membership_cached())])
+        .collect();
+
+    // Benchmark verification with a new verifier for each proof
+    c.bench_function("Interleaved verification without cache", |b| {
+        b.iter(|| {
+            for (id, proof) in &proofs {
+                let pp = if *id == id1 { issuer1.get_proof_params() } else { issuer2.get_proof_params() };
+                assert!(Verifier::new(pp).verify(*proof));
+            }
+        })
+    });
+
+    // Benchmark verification with cached prepared params
+    let mut ver = FederatedVerifier::new();
+    ver.update_public_params(issuer1.get_proof_params());
+    ver.update_public_params(issuer2.get_proof_params());
+    c.bench_function("Interleaved verification with cache", |b| {
+        b.iter(|| {
+            for (id, proof) in &proofs {
+                assert!(ver.verify(id, *proof));
+            }
+        })
+    });
+}
+
+
+// Benchmarks the verification of witnesses for many distinct elements
+fn witness_verification(c: &mut Criterion) {
+    c.benchmark_group("witness_verification");
+
+    // Creates witnesses for distinct elements
+    let key = SecretKey::new(None);
+    let pubkey = PublicKey::from(&key);
+    let acc = Accumulator::random(rand_core::OsRng {});
+    let witnesses: Vec<_> = (0..WITNESSES)
+        .map(|_| {
+            let y = Element::random();
+            (y, MembershipWitness::new(&y, acc, &key).unwrap())
+        })
+        .collect();
+
+    c.bench_function("Witness verification", |b| {
+        b.iter(|| {
+            for (y, wit) in &witnesses {
+                assert!(wit.verify(*y, pubkey, acc));
+            }
+        })
+    });
+
+    // Benchmark verification with the fixed-base table of the G2 generator
+    let ver = PreparedWitnessVerifier::new(pubkey);
+    c.bench_function("Witness verification with prepared verifier", |b| {
+        b.iter(|| {
+            for (y, wit) in &witnesses {
+                assert!(ver.verify(wit, *y, acc));
+            }
+        })
+    });
+}
@@ -1,10 +1,13 @@
 use accumulator::{
-    accumulator::Element, proof::{self, Proof, ProofParamsPublic, PROOF_LABEL}, witness::{Deletion, MembershipWitness}, Accumulator, Error, ProofParamsPrivate
+    accumulator::Element, proof::{DesignatedProof, ParamsDelta, Proof, ProofParamsPublic, UnionProof, CONTEXT_LABEL, PROOF_LABEL}, witness::{Deletion, ElementPowers, MembershipWitness}, Accumulator, AccumulatorError, Coefficient, ProofParamsPrivate, PublicKey
 };
-use crate::{issuer::RevocationHandle, UpdatePolynomials};
+use crate::{issuer::RevocationHandle, EpochUpdate, RekeyUpdate, UpdateOperation, UpdatePolynomials, Verifier};
 use crate::Updatable;
 
 use bls12_381_plus::Scalar;
+use core::ops::ControlFlow;
+use std::{collections::BTreeMap, sync::Arc};
+use rand_core::{CryptoRng, RngCore};
 
 #[derive(Debug)]
 pub struct Holder {
@@ -12,42 +15,294 @@ pub struct Holder {
     y: Element,
     w: MembershipWitness,
     pp: ProofParamsPublic,
+    pending: Vec<UpdatePolynomials>,
+    powers: ElementPowers,
+    credentials: BTreeMap<String, (Element, MembershipWitness)>,
 }
 
 impl Holder {
+    /// Number of coefficients processed between two calls to the progress callback of `batch_update_with_progress`.
+    pub const PROGRESS_WINDOW: usize = 256;
 
     /// Returns a new `Holder` instance, associated with pseudonym `pseudo`,
     /// the revocation handle `rh`, and the public parameters `pp` to be used for creating non-revocation proofs.
     pub fn new<T: Into<String>>(pseudo: T, rh: RevocationHandle, pp: ProofParamsPublic) -> Self {
         let pseudo: String = pseudo.into();
-        Self { pseudo, y: rh.get_elem(), w: rh.get_witness(), pp}
+        let y = rh.get_elem();
+        Self { pseudo, y, w: rh.get_witness(), pp, pending: Vec::new(), powers: ElementPowers::new(y), credentials: BTreeMap::new() }
     }
 
-    /// Sequentially updates the witness using the vector of deletions `rh`.
-    pub fn update(&mut self, del: &[Deletion]){
-        self.w.update_assign(self.y, del);
+    /// Same as `new`, but first checks that the untrusted parameters `pp` are well-formed (see `ProofParamsPublic::validate`).
+    pub fn new_validated<T: Into<String>>(pseudo: T, rh: RevocationHandle, pp: ProofParamsPublic) -> Result<Self, AccumulatorError> {
+        pp.validate()?;
+        Ok(Self::new(pseudo, rh, pp))
+    }
+
+    /// Recovers a holder that lost its witness but still knows its element `y`, without the issuer's secret key:
+    /// replays the public update history `history` on `initial_witness`, the witness received at issuance, 
+    /// and returns a holder with the reconstructed witness and the parameters `pp`.
+    /// 
+    /// `history` must contain, in order, all the update polynomials published since `initial_witness` was issued.
+    /// Returns `AccumulatorError::Revoked` if `y` was deleted by one of the updates, 
+    /// and `AccumulatorError::StaleWitness` if the reconstructed witness is not valid for the accumulator of `pp`, 
+    /// e.g., because the history is incomplete.
+    pub fn recover<T: Into<String>>(pseudo: T, y: Element, initial_witness: MembershipWitness, history: &[UpdatePolynomials], pp: ProofParamsPublic) -> Result<Self, AccumulatorError> {
+        let mut holder = Self { pseudo: pseudo.into(), y, w: initial_witness, pp, pending: Vec::new(), powers: ElementPowers::new(y), credentials: BTreeMap::new() };
+        if !history.is_empty() {
+            holder.batch_updates(history)?;
+        }
+        if !holder.test_membership_cached() {
+            return Err(AccumulatorError::StaleWitness);
+        }
+        Ok(holder)
+    }
+
+    /// Same as `Updatable::update_public_params`, but first checks that the untrusted parameters `new_pp` are well-formed,
+    /// leaving the cached parameters unmodified otherwise (see `ProofParamsPublic::validate`).
+    pub fn update_public_params_validated(&mut self, new_pp: ProofParamsPublic) -> Result<(), AccumulatorError> {
+        new_pp.validate()?;
+        self.pp = new_pp;
+        Ok(())
+    }
+
+    /// Same as `Updatable::update_accumulator`, but reads the new accumulator from the delta `delta` of the issuer's parameters
+    /// (see `ProofParamsPublic::delta_from`), instead of receiving the full parameters after each revocation.
+    /// 
+    /// Returns `AccumulatorError::PublicKeyMismatch`, leaving the cached parameters unmodified, if the delta is for another public key,
+    /// e.g., after a key rotation, in which case the full parameters are needed.
+    pub fn apply_params_delta(&mut self, delta: &ParamsDelta) -> Result<(), AccumulatorError> {
+        let mut pp = self.pp;
+        pp.apply_delta(delta)?;
+        self.update_accumulator(pp.get_accumulator());
+        Ok(())
+    }
+
+    /// Sequentially updates the witness using the vector of deletions `del`, 
+    /// as emitted by the issuer in the `deletions` of an `Update` (see `Issuer::revoke_elements_instant_update`).
+    /// 
+    /// Returns an error, leaving the witness unmodified, if the holder's element is among the deletions.
+    /// The additional credentials are also updated (see `add_credential`).
+    pub fn update(&mut self, del: &[Deletion]) -> Result<MembershipWitness, AccumulatorError>{
+        self.update_all(|y, w, _| w.update_assign(y, del), |y, w| w.update_assign(y, del))
     }
 
     /// Batch update the holder's witness with the update polynomials received as input.
-    pub fn batch_update(& mut self, update_poly: &UpdatePolynomials) -> Result<MembershipWitness, Error>{
-        self.w.batch_update_assign(self.y, &update_poly.deletions, &update_poly.omegas)
+    /// 
+    /// The powers of the holder's element are cached across batch updates (see `ElementPowers`).
+    /// The additional credentials are also updated (see `add_credential`).
+    /// NOTE: the coefficients are not checked, use `batch_update_validated` for updates received from an untrusted channel.
+    pub fn batch_update(& mut self, update_poly: &UpdatePolynomials) -> Result<MembershipWitness, AccumulatorError>{
+        self.update_all(
+            |_, w, powers| w.batch_update_assign_with_powers(powers, &update_poly.deletions, &update_poly.omegas),
+            |y, w| w.batch_update_assign(y, &update_poly.deletions, &update_poly.omegas),
+        )
+    }
+
+    /// Adds the credential of another accumulated attribute of the holder, with element and witness given by `rh`, 
+    /// under the name `key`, replacing any credential with the same name.
+    /// The name should be the pseudonym under which the issuer enrolled the attribute, which `apply_rekey` uses to rotate the credential.
+    /// 
+    /// The additional credentials are updated together with the holder's own witness by all the update methods (e.g., `update`, 
+    /// `batch_update`, `batch_updates`, `apply_updates` and `flush_updates`), and proved individually with `proof_membership_for`. A credential whose element is revoked by an update is dropped,
+    /// without affecting the holder's own witness or the other credentials.
+    pub fn add_credential<K: Into<String>>(&mut self, key: K, rh: RevocationHandle) {
+        self.credentials.insert(key.into(), (rh.get_elem(), rh.get_witness()));
+    }
+
+    /// Returns the current witness of the additional credential `key`, or `None` if there is none (e.g., it was revoked).
+    pub fn get_credential(&self, key: &str) -> Option<MembershipWitness> {
+        self.credentials.get(key).map(|(_, w)| *w)
+    }
+
+    /// Creates a membership proof for the additional credential `key` using the cached parameters,
+    /// or returns `None` if there is no such credential (e.g., it was revoked).
+    pub fn proof_membership_for(&self, key: &str) -> Option<Proof> {
+        let (y, w) = self.credentials.get(key)?;
+        Some(Proof::create(&self.pp, *y, w, &mut rand_core::OsRng {}))
+    }
+
+    /// Applies the update `own` to the holder's witness and then, only if it succeeds, the update `other` to the witness 
+    /// of each additional credential. Both are applied to copies, hence on error the holder's witness and the credentials are left unmodified.
+    /// 
+    /// The credentials revoked by the update are dropped, while on any other error a credential keeps its witness,
+    /// without affecting the holder's own witness or the other credentials.
+    fn update_all(
+        &mut self, 
+        own: impl FnOnce(Element, &mut MembershipWitness, &mut ElementPowers) -> Result<(), AccumulatorError>,
+        mut other: impl FnMut(Element, &mut MembershipWitness) -> Result<(), AccumulatorError>,
+    ) -> Result<MembershipWitness, AccumulatorError> {
+        let mut w = self.w;
+        own(self.y, &mut w, &mut self.powers)?;
+        self.w = w;
+        self.credentials.retain(|_, (y, w)| {
+            let mut updated = *w;
+            match other(*y, &mut updated) {
+                Ok(()) => { *w = updated; true },
+                Err(err) => err != AccumulatorError::Revoked,
+            }
+        });
+        Ok(self.w)
+    }
+
+    /// Same as `batch_update`, with update polynomials shared with other holders, e.g., when an in-process server 
+    /// distributes a single allocation of the update to many holders updated on different threads, without cloning the coefficients.
+    pub fn batch_update_shared(&mut self, update_poly: Arc<UpdatePolynomials>) -> Result<MembershipWitness, AccumulatorError>{
+        self.batch_update(&update_poly)
+    }
+
+    /// Same as `batch_update`, but first checks that the untrusted update polynomials `update_poly` are well-formed 
+    /// (see `UpdatePolynomials::validate`), leaving the witness unmodified otherwise.
+    pub fn batch_update_validated(&mut self, update_poly: &UpdatePolynomials) -> Result<MembershipWitness, AccumulatorError>{
+        update_poly.validate()?;
+        self.batch_update(update_poly)
+    }
+
+    /// Same as `batch_update`, but calls `progress` with the number of coefficients processed so far and their total
+    /// every `PROGRESS_WINDOW` coefficients, e.g., to report the progress of a large update.
+    /// 
+    /// If `progress` returns `ControlFlow::Break`, the update is aborted and `AccumulatorError::Aborted` is returned, 
+    /// leaving the witness unmodified. The powers of the holder's element are not cached.
+    /// The additional credentials are updated once the holder's own witness is, without reporting progress.
+    pub fn batch_update_with_progress(&mut self, update_poly: &UpdatePolynomials, progress: impl FnMut(usize, usize) -> ControlFlow<()>) -> Result<MembershipWitness, AccumulatorError>{
+        self.update_all(
+            |y, w, _| w.batch_update_assign_with_progress(y, &update_poly.deletions, &update_poly.omegas, Self::PROGRESS_WINDOW, progress),
+            |y, w| w.batch_update_assign(y, &update_poly.deletions, &update_poly.omegas),
+        )
     }
 
     /// Sequentially apply multiples batch updates to the holder's witness 
     /// with the array update polynomials received as input.
-    pub fn batch_updates(& mut self, update_poly: &[UpdatePolynomials]) -> Result<MembershipWitness, Error>{
-        let mut result: Result<MembershipWitness, Error> = Err(Error::from_msg(3, "Input polynomial vector is empty"));
-        
-        for up in update_poly{
-            result = self.w.batch_update_assign(self.y, &up.deletions, &up.omegas);
-            if result.is_err(){
-                return Err(result.err().unwrap());
-            }
+    /// 
+    /// Applying the pieces returned by `UpdatePolynomials::split` reproduces the update of the unsplit polynomials.
+    /// The updates are transactional: if any of them fails (e.g., because it is malformed or revokes the holder's element),
+    /// the error is returned and the witness is restored to its state before the call.
+    pub fn batch_updates(& mut self, update_poly: &[UpdatePolynomials]) -> Result<MembershipWitness, AccumulatorError>{
+        self.apply_batch_updates(update_poly.iter())
+    }
+
+    /// Same as `batch_updates`, but first checks that the stamped updates `updates` form a contiguous chain 
+    /// starting from the update epoch `epoch` at which the holder's witness is valid (see `Issuer::get_update_epoch`),
+    /// i.e., the i-th update applies to epoch `epoch + i`.
+    /// 
+    /// Returns `AccumulatorError::UnexpectedEpoch` with the first missing or out of order epoch, leaving the witness unmodified.
+    /// On success, the witness is valid at epoch `epoch + updates.len()`.
+    pub fn batch_updates_from(&mut self, epoch: u64, updates: &[EpochUpdate]) -> Result<MembershipWitness, AccumulatorError>{
+        if let Some((_, expected)) = updates.iter().zip(epoch..).find(|(up, expected)| up.epoch != *expected) {
+            return Err(AccumulatorError::UnexpectedEpoch(expected));
         }
-        
-        result
+        self.apply_batch_updates(updates.iter().map(|up| &up.polys))
+    }
+
+    /// Checks, without updating the witness, that none of the update polynomials `history` deletes the holder's element,
+    /// e.g., before applying a long chain of updates with `batch_updates`.
+    /// 
+    /// Only the deletions are scanned, hence the check costs no group operation and no allocation.
+    /// Returns `AccumulatorError::RevokedAt` with the index in `history` of the first update deleting the holder's element.
+    pub fn would_survive(&self, history: &[UpdatePolynomials]) -> Result<(), AccumulatorError> {
+        match history.iter().position(|up| up.deletions.contains(&self.y)) {
+            Some(index) => Err(AccumulatorError::RevokedAt(index)),
+            None => Ok(()),
+        }
+    }
+
+    /// Transactionally applies the update polynomials `update_poly` in sequence, see `batch_updates`.
+    fn apply_batch_updates<'a>(&mut self, update_poly: impl ExactSizeIterator<Item = &'a UpdatePolynomials>) -> Result<MembershipWitness, AccumulatorError>{
+        if update_poly.len() == 0 {
+            return Err(AccumulatorError::EmptyInput);
+        }
+        let update_poly: Vec<&UpdatePolynomials> = update_poly.collect();
+        self.update_all(
+            |_, w, powers| update_poly.iter().try_for_each(|up| w.batch_update_assign_with_powers(powers, &up.deletions, &up.omegas)),
+            |y, w| update_poly.iter().try_for_each(|up| w.batch_update_assign(y, &up.deletions, &up.omegas)),
+        )
     }
     
+    /// Sequentially applies the operations `ops` of the update stream to the holder's witness, in the order they were issued.
+    /// 
+    /// If one of the deletions revokes the holder's element, `AccumulatorError::Revoked` is returned unless `continue_past_revocation` is set,
+    /// in which case the following deletions are skipped until the element is added back, whose witness is then the accumulator before the addition.
+    /// The updates are transactional: on error, including if the element is still revoked at the end of the stream, the witness is left unmodified.
+    /// The additional credentials are updated with the same semantics once the holder's own witness is.
+    pub fn apply_updates(&mut self, ops: &[UpdateOperation], continue_past_revocation: bool) -> Result<MembershipWitness, AccumulatorError>{
+        self.update_all(
+            |y, w, powers| Self::apply_operations(y, w, powers, ops, continue_past_revocation),
+            |y, w| Self::apply_operations(y, w, &mut ElementPowers::new(y), ops, continue_past_revocation),
+        )
+    }
+
+    /// Applies the operations `ops` to the witness `w` of the element `y`, whose powers are cached in `powers`, see `apply_updates`.
+    fn apply_operations(y: Element, w: &mut MembershipWitness, powers: &mut ElementPowers, ops: &[UpdateOperation], continue_past_revocation: bool) -> Result<(), AccumulatorError> {
+        let mut revoked = false;
+        for op in ops {
+            match op {
+                UpdateOperation::Deletions(_) if revoked => {},
+                UpdateOperation::Deletions(up) => match w.batch_update_assign_with_powers(powers, &up.deletions, &up.omegas) {
+                    Err(AccumulatorError::Revoked) if continue_past_revocation => revoked = true,
+                    res => res?,
+                },
+                // C' = V + (y_added - y) C, that is, V if the holder's element is the one added back
+                UpdateOperation::Addition { elem, accumulator } => {
+                    w.add_update_assign(y, *accumulator, *elem);
+                    revoked &= *elem != y;
+                },
+            }
+        }
+        match revoked {
+            true => Err(AccumulatorError::Revoked),
+            false => Ok(()),
+        }
+    }
+
+    /// Stores the update polynomials `update_poly` without applying them, deferring the expensive MSM 
+    /// until the next call to `flush_updates` or to one of the proof generation methods.
+    pub fn defer_update(&mut self, update_poly: UpdatePolynomials) {
+        self.pending.push(update_poly);
+    }
+
+    /// Returns the number of deferred updates not yet applied to the witness.
+    pub fn pending_updates(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Applies all deferred updates to the holder's witness, aggregating them so that a single inversion is needed.
+    /// 
+    /// The pending updates are consumed even on failure, in which case the witness and the additional credentials are left unmodified
+    /// (e.g., because the holder's element was revoked by one of them).
+    pub fn flush_updates(&mut self) -> Result<MembershipWitness, AccumulatorError> {
+        if self.pending.is_empty() {
+            return Ok(self.w);
+        }
+        let pending = std::mem::take(&mut self.pending);
+        let updates: Vec<(&[Element], &[Coefficient])> = pending.iter()
+            .map(|up| (up.deletions.as_slice(), up.omegas.as_slice()))
+            .collect();
+        self.update_all(|y, w, _| w.aggregate_batch_update_assign(y, &updates), |y, w| w.aggregate_batch_update_assign(y, &updates))
+    }
+
+    /// Applies the re-key update `update` produced by `Issuer::rotate_key`, replacing the holder's witness 
+    /// and cached parameters with the re-issued witness and the new parameters.
+    /// 
+    /// Returns `AccumulatorError::NotMember`, leaving the holder unmodified, if the update contains no valid witness for the holder's pseudonym.
+    /// The additional credentials are rotated with the re-issued witness of their key, and dropped if the update contains no valid one.
+    pub fn apply_rekey(&mut self, update: &RekeyUpdate) -> Result<MembershipWitness, AccumulatorError> {
+        let pp = update.params;
+        let valid = |key: &String, y: Element| update.witnesses.get(key)
+            .filter(|w| w.verify(y, pp.get_public_key(), pp.get_accumulator()))
+            .copied();
+        match valid(&self.pseudo, self.y) {
+            Some(w) => {
+                self.w = w;
+                self.pp = pp;
+                self.credentials.retain(|key, (y, w)| match valid(key, *y) {
+                    Some(new_w) => { *w = new_w; true },
+                    None => false,
+                });
+                Ok(self.w)
+            }
+            None => Err(AccumulatorError::NotMember),
+        }
+    }
+
     /// Replace the holder's witness with the input witness `new_mw`.
     pub fn replace_witness(&mut self, new_mw: MembershipWitness) {
         self.w = new_mw;
@@ -55,23 +310,186 @@ impl Holder {
 
     /// Test membership of the holder's witness against the accumulator contained 
     /// in the proof parameters `pp` received as input or in the cached parameters. 
+    /// 
+    /// NOTE: deferred updates are not flushed, hence the test uses the stale witness if any update is pending.
+    #[deprecated(note = "use `test_membership_cached` or `test_membership_with`, which make the parameters explicit")]
     pub fn test_membership(&self, pub_params: Option<ProofParamsPublic>)->bool{
-        let pp = if pub_params.is_some() { pub_params.unwrap() } else { self.pp };
+        self.test_membership_with(&pub_params.unwrap_or(self.pp))
+    }
+
+    /// Test membership of the holder's witness against the accumulator in the cached parameters,
+    /// i.e., the last ones set with `update_public_params`.
+    /// 
+    /// NOTE: deferred updates are not flushed, hence the test uses the stale witness if any update is pending.
+    pub fn test_membership_cached(&self) -> bool {
+        self.test_membership_with(&self.pp)
+    }
+
+    /// Test membership of the holder's witness against the accumulator in the proof parameters `pp`, ignoring the cached parameters.
+    /// 
+    /// NOTE: deferred updates are not flushed, hence the test uses the stale witness if any update is pending.
+    pub fn test_membership_with(&self, pp: &ProofParamsPublic) -> bool {
         self.w.verify(self.y, pp.get_public_key(), pp.get_accumulator())
     }
 
+    /// Same as `test_membership` against the proof parameters `pp`, but distinguishes the reason of a failure.
+    /// 
+    /// Returns `AccumulatorError::AccumulatorIdMismatch` if the cached parameters are for another accumulator than `pp` (see `is_current`),
+    /// i.e., the holder must update its witness and parameters, and `AccumulatorError::NotMember` if the witness is not valid for `pp`,
+    /// e.g., because the holder was revoked.
+    pub fn check_membership(&self, pp: &ProofParamsPublic) -> Result<(), AccumulatorError> {
+        if !self.is_current(pp) {
+            return Err(AccumulatorError::AccumulatorIdMismatch);
+        }
+        match self.test_membership_with(pp) {
+            true => Ok(()),
+            false => Err(AccumulatorError::NotMember),
+        }
+    }
+
     /// Creates a new membership proof using either the optional input parameters or the cached parameters.
-    pub fn proof_membership(&self, pub_params: Option<ProofParamsPublic>) -> Proof {
-        let pp = if pub_params.is_some() { pub_params.unwrap() } else { self.pp };
+    /// 
+    /// Deferred updates are flushed first. If flushing fails, the proof is created with the unmodified witness 
+    /// and will not verify against the updated accumulator.
+    #[deprecated(note = "use `proof_membership_cached` or `proof_membership_with`, which make the parameters explicit")]
+    pub fn proof_membership(&mut self, pub_params: Option<ProofParamsPublic>) -> Proof {
+        let pp = pub_params.unwrap_or(self.pp);
+        self.proof_membership_with(&pp)
+    }
+
+    /// Creates a new membership proof against the cached parameters, i.e., the last ones set with `update_public_params`.
+    /// 
+    /// Deferred updates are flushed first, as in `proof_membership_with`.
+    pub fn proof_membership_cached(&mut self) -> Proof {
+        let pp = self.pp;
+        self.proof_membership_with(&pp)
+    }
+
+    /// Creates a new membership proof against the proof parameters `pp`, ignoring the cached parameters.
+    /// 
+    /// Deferred updates are flushed first. If flushing fails, the proof is created with the unmodified witness 
+    /// and will not verify against the updated accumulator.
+    pub fn proof_membership_with(&mut self, pp: &ProofParamsPublic) -> Proof {
+        self.proof_membership_with_rng(pp, &mut rand_core::OsRng {})
+    }
+
+    /// Same as `proof_membership_with`, but draws the blinding randomness from `rng` instead of the operating system's RNG,
+    /// e.g., a seeded RNG for reproducible proofs in tests, or an RNG hedged with a nonce in production.
+    /// The same RNG state, witness and parameters produce a byte-identical proof.
+    /// NOTE: proofs created from the same RNG state are linkable, a seeded RNG must never be reused across presentations.
+    /// 
+    /// Panics if `rng` fails, see `try_proof_membership_with_rng`.
+    pub fn proof_membership_with_rng(&mut self, pp: &ProofParamsPublic, rng: &mut (impl RngCore + CryptoRng)) -> Proof {
+        self.try_proof_membership_with_rng(pp, rng).expect("random number generator failed")
+    }
+
+    /// Same as `proof_membership_with_rng`, but returns `AccumulatorError::RngFailure` if `rng` fails instead of panicking,
+    /// e.g., when the operating system's RNG is not available yet.
+    pub fn try_proof_membership_with_rng(&mut self, pp: &ProofParamsPublic, rng: &mut (impl RngCore + CryptoRng)) -> Result<Proof, AccumulatorError> {
+        let _ = self.flush_updates();
+        prove_membership(self.y, &self.w, pp, PROOF_LABEL, None, rng)
+    }
+
+    /// Same as `proof_membership_with`, but labels the transcript with the protocol domain `label` instead of `PROOF_LABEL`,
+    /// so that deployments sharing this crate cannot reuse each other's proofs.
+    /// The proof only verifies with `Verifier::verify_labeled` under the same label.
+    pub fn proof_membership_labeled(&mut self, pp: &ProofParamsPublic, label: &'static [u8]) -> Proof {
+        let _ = self.flush_updates();
+        prove_membership(self.y, &self.w, pp, label, None, &mut rand_core::OsRng {}).expect("random number generator failed")
+    }
+
+    /// Same as `proof_membership`, but first checks the holder's witness against the accumulator of the parameters,
+    /// so that no proof is created if it cannot verify.
+    /// 
+    /// Returns `AccumulatorError::Revoked` if one of the deferred updates revokes the holder's element,
+    /// and `AccumulatorError::StaleWitness` if the witness is not valid for the accumulator, 
+    /// e.g., because the holder must fetch and apply the latest updates, or was revoked by an update it did not apply.
+    /// Returns `AccumulatorError::RngFailure` if the operating system's RNG fails.
+    pub fn proof_membership_checked(&mut self, pub_params: Option<ProofParamsPublic>) -> Result<Proof, AccumulatorError> {
+        let pp = pub_params.unwrap_or(self.pp);
+        self.flush_updates()?;
+        if !self.test_membership_with(&pp) {
+            return Err(AccumulatorError::StaleWitness);
+        }
+        prove_membership(self.y, &self.w, &pp, PROOF_LABEL, None, &mut rand_core::OsRng {})
+    }
+
+    /// Same as `proof_membership`, but binds the proof to the verifier-supplied `context` (e.g., a nonce or session id)
+    /// absorbed into the transcript, so that the proof only verifies with `Verifier::verify_with_context` under the same context
+    /// and cannot be replayed to other verifiers or sessions.
+    pub fn proof_membership_with_context(&mut self, pub_params: Option<ProofParamsPublic>, context: &[u8]) -> Proof {
+        let pp = pub_params.unwrap_or(self.pp);
+        let _ = self.flush_updates();
+        prove_membership(self.y, &self.w, &pp, PROOF_LABEL, Some(context), &mut rand_core::OsRng {}).expect("random number generator failed")
+    }
+
+    /// Creates one membership proof per nonce in `nonces`, each bound to its nonce as in `proof_membership_with_context`,
+    /// using either the optional input parameters or the cached parameters.
+    /// 
+    /// The transcript prefix and the private parameters are computed once and shared by all proofs,
+    /// while each proof draws fresh blindings: reusing the commitment under two challenges would reveal the holder's element.
+    /// Deferred updates are NOT flushed, call `flush_updates` first if any update is pending.
+    pub fn proof_membership_multi_nonce(&self, pub_params: Option<ProofParamsPublic>, nonces: &[&[u8]]) -> Vec<Proof> {
+        let pp = pub_params.unwrap_or(self.pp);
+        let priv_params = ProofParamsPrivate::new(self.y, &self.w);
         let mut transcript = merlin::Transcript::new(PROOF_LABEL);
         pp.add_to_transcript(&mut transcript);
 
-        let priv_params = ProofParamsPrivate::new(self.y, &self.w);
-        let pc = proof::ProofCommitting::new(&pp, &priv_params);
-        pc.get_bytes_for_challenge(&mut transcript);
+        nonces.iter()
+            .map(|nonce| {
+                let mut transcript = transcript.clone();
+                transcript.append_message(CONTEXT_LABEL, nonce);
+                Proof::try_create_with_transcript(&pp, &priv_params, transcript, &mut rand_core::OsRng {}).expect("random number generator failed")
+            })
+            .collect()
+    }
+
+    /// Creates a new membership proof using either the optional input parameters or the cached parameters,
+    /// and verifies it locally before returning it.
+    /// 
+    /// Returns `AccumulatorError::PublicKeyMismatch`, without creating the proof, if the input parameters 
+    /// belong to another issuer than the cached ones, i.e., their public keys differ.
+    /// The accumulator ids are not compared, since the input parameters are usually newer than the cached ones.
+    /// Returns `AccumulatorError::ProofSelfCheckFailed` if the proof does not verify, e.g., because the holder's witness is outdated.
+    pub fn proof_membership_self_verified(&mut self, pub_params: Option<ProofParamsPublic>) -> Result<Proof, AccumulatorError> {
+        let pp = pub_params.unwrap_or(self.pp);
+        if pp.get_public_key() != self.pp.get_public_key() {
+            return Err(AccumulatorError::PublicKeyMismatch);
+        }
+        let proof = self.proof_membership_with(&pp);
+        match Verifier::new(pp).verify(proof) {
+            true => Ok(proof),
+            false => Err(AccumulatorError::ProofSelfCheckFailed),
+        }
+    }
 
-        let challenge_hash = Element::from_transcript(PROOF_LABEL, &mut transcript);
-        return pc.gen_proof(challenge_hash);
+    /// Creates a new membership proof that only the verifier with public key `verifier_pubkey` can verify,
+    /// using either the optional input parameters or the cached parameters, after flushing deferred updates.
+    pub fn proof_membership_designated(&mut self, pub_params: Option<ProofParamsPublic>, verifier_pubkey: &PublicKey) -> DesignatedProof {
+        let pp = pub_params.unwrap_or(self.pp);
+        let _ = self.flush_updates();
+        DesignatedProof::new(&pp, &ProofParamsPrivate::new(self.y, &self.w), verifier_pubkey)
+    }
+
+    /// Creates a membership proof in the union of the accumulators in `pps`, which share the same public key, 
+    /// widening the anonymity set to all their members without revealing which accumulator contains the holder.
+    /// 
+    /// Deferred updates are NOT flushed, call `flush_updates` first if any update is pending.
+    /// Returns an error if the holder's witness is not valid for any of the accumulators or if the public keys differ.
+    pub fn proof_membership_in_union(&self, pps: &[ProofParamsPublic]) -> Result<UnionProof, AccumulatorError> {
+        let index = pps.iter()
+            .position(|pp| self.w.verify(self.y, pp.get_public_key(), pp.get_accumulator()))
+            .ok_or(AccumulatorError::NotMember)?;
+        UnionProof::new(pps, index, &ProofParamsPrivate::new(self.y, &self.w))
+    }
+
+    /// Checks whether the accumulator in the public parameters `pp` has the same id as the holder's cached accumulator,
+    /// so that update work can be skipped when nothing changed.
+    /// 
+    /// NOTE: a matching id is necessary but not sufficient for the witness to be valid 
+    /// (e.g., the cached parameters may have been refreshed without updating the witness), `test_membership` remains authoritative.
+    pub fn is_current(&self, pp: &ProofParamsPublic) -> bool {
+        self.get_accumulator_id() == pp.get_accumulator().get_id()
     }
 
     /// Returns the id of the the holder's accumulator.
@@ -83,6 +501,191 @@ impl Holder {
     pub fn get_pseudo(&self) -> String{
         return self.pseudo.clone();
     }
+
+    /// Serializes the holder's identity `(pseudo, y, w, accumulator_id)`, excluding the public parameters 
+    /// which are shared by all holders of the same accumulator.
+    pub fn export_identity(&self) -> Vec<u8> {
+        bincode::serialize(&(&self.pseudo, self.y, self.w, self.get_accumulator_id())).expect("Serialization error")
+    }
+
+    /// Returns the exact number of bytes of the identity returned by `export_identity`, without serializing it,
+    /// plus the size of the serialized public parameters if `include_params` is true, e.g., to budget per-credential storage.
+    /// 
+    /// NOTE: deferred updates and cached powers are not persisted, hence not counted.
+    pub fn serialized_size(&self, include_params: bool) -> usize {
+        // The pseudonym is prefixed by its length as a u64, followed by y, w and the accumulator id
+        let identity = 8 + self.pseudo.len() + Element::BYTES + MembershipWitness::BYTES + Scalar::BYTES;
+        match include_params {
+            true => identity + ProofParamsPublic::BYTES,
+            false => identity,
+        }
+    }
+
+    /// Restores a holder from the identity bytes produced by `export_identity`, reattaching the shared public parameters `pp`.
+    /// 
+    /// Returns an error if the bytes are malformed or if the accumulator id does not match the one in `pp`.
+    pub fn from_identity(bytes: &[u8], pp: ProofParamsPublic) -> Result<Self, AccumulatorError> {
+        let (pseudo, y, w, acc_id): (String, Element, MembershipWitness, Scalar) = bincode::deserialize(bytes)
+            .map_err(|_| AccumulatorError::BadEncoding)?;
+        if acc_id != pp.get_accumulator().get_id() {
+            return Err(AccumulatorError::AccumulatorIdMismatch);
+        }
+        Ok(Self { pseudo, y, w, pp, pending: Vec::new(), powers: ElementPowers::new(y), credentials: BTreeMap::new() })
+    }
+
+    /// Checks that the holder's element is the hash of the pseudonym `pseudo`, i.e. `y == Element::hash(pseudo)`.
+    ///
+    /// Useful to detect issuer and holder encoding the same pseudonym differently (e.g., trailing whitespace).
+    /// NOTE: only elements issued with `Issuer::add_derived` derive from the pseudonym, random elements (e.g., of `Issuer::add`) never match.
+    pub fn verify_element_matches(&self, pseudo: &str) -> bool {
+        Element::hash(pseudo.as_bytes()) == self.y
+    }
+}
+
+/// Creates a membership proof for the element `y` with witness `w` against the public parameters `pp`,
+/// with a transcript labeled `label` and optionally bound to the verifier-supplied `context`.
+fn prove_membership(y: Element, w: &MembershipWitness, pp: &ProofParamsPublic, label: &'static [u8], context: Option<&[u8]>, rng: &mut (impl RngCore + CryptoRng)) -> Result<Proof, AccumulatorError> {
+    let mut transcript = merlin::Transcript::new(label);
+    pp.add_to_transcript(&mut transcript);
+    if let Some(context) = context {
+        transcript.append_message(CONTEXT_LABEL, context);
+    }
+    Proof::try_create_with_transcript(pp, &ProofParamsPrivate::new(y, w), transcript, rng)
+}
+
+/// Holder storing only its pseudonym, element and witness, for architectures passing the public parameters 
+/// with each call instead of caching them (e.g., holders kept as plain records in a database).
+#[derive(Debug, Clone)]
+pub struct StatelessHolder {
+    pseudo: String,
+    y: Element,
+    w: MembershipWitness,
+}
+
+impl StatelessHolder {
+
+    /// Returns a new `StatelessHolder` instance, associated with pseudonym `pseudo`, element `y` and witness `w`.
+    pub fn new<T: Into<String>>(pseudo: T, y: Element, w: MembershipWitness) -> Self {
+        Self { pseudo: pseudo.into(), y, w }
+    }
+
+    /// Sequentially updates the witness using the vector of deletions `del`, 
+    /// as emitted by the issuer in the `deletions` of an `Update` (see `Issuer::revoke_elements_instant_update`).
+    /// 
+    /// Returns an error, leaving the witness unmodified, if the holder's element is among the deletions.
+    pub fn update(&mut self, del: &[Deletion]) -> Result<MembershipWitness, AccumulatorError>{
+        self.w.update_assign(self.y, del)?;
+        Ok(self.w)
+    }
+
+    /// Batch update the holder's witness with the update polynomials received as input.
+    pub fn batch_update(&mut self, update_poly: &UpdatePolynomials) -> Result<MembershipWitness, AccumulatorError>{
+        self.w.batch_update_assign(self.y, &update_poly.deletions, &update_poly.omegas)?;
+        Ok(self.w)
+    }
+
+    /// Test membership of the holder's witness against the accumulator contained in the proof parameters `pp`.
+    pub fn test_membership(&self, pp: &ProofParamsPublic) -> bool {
+        self.w.verify(self.y, pp.get_public_key(), pp.get_accumulator())
+    }
+
+    /// Creates a new membership proof using the proof parameters `pp`.
+    pub fn proof_membership(&self, pp: &ProofParamsPublic) -> Proof {
+        Proof::create(pp, self.y, &self.w, &mut rand_core::OsRng {})
+    }
+
+    /// Returns the holder's witness.
+    pub fn get_witness(&self) -> MembershipWitness {
+        self.w
+    }
+
+    /// Returns the pseudonym associated to the holder.
+    pub fn get_pseudo(&self) -> String {
+        self.pseudo.clone()
+    }
+
+    /// Converts into a `Holder` caching the public parameters `pp`.
+    pub fn with_params(self, pp: ProofParamsPublic) -> Holder {
+        Holder { pseudo: self.pseudo, y: self.y, w: self.w, pp, pending: Vec::new(), powers: ElementPowers::new(self.y), credentials: BTreeMap::new() }
+    }
+}
+
+/// Builder of a `Holder` whose pseudonym, element, witness and public parameters arrive separately,
+/// e.g., the revocation handle from the issuer and the parameters from a public bulletin board.
+#[derive(Debug, Clone, Default)]
+pub struct HolderBuilder {
+    pseudo: Option<String>,
+    y: Option<Element>,
+    w: Option<MembershipWitness>,
+    pp: Option<ProofParamsPublic>,
+}
+
+impl HolderBuilder {
+    /// Returns a builder with no input set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the pseudonym of the holder.
+    pub fn pseudonym<T: Into<String>>(mut self, pseudo: T) -> Self {
+        self.pseudo = Some(pseudo.into());
+        self
+    }
+
+    /// Sets the element of the holder.
+    pub fn element(mut self, y: Element) -> Self {
+        self.y = Some(y);
+        self
+    }
+
+    /// Sets the witness of the holder's element.
+    pub fn witness(mut self, w: MembershipWitness) -> Self {
+        self.w = Some(w);
+        self
+    }
+
+    /// Sets both the element and the witness from the revocation handle `rh` issued for the holder.
+    pub fn revocation_handle(self, rh: RevocationHandle) -> Self {
+        self.element(rh.get_elem()).witness(rh.get_witness())
+    }
+
+    /// Sets the public parameters to be cached by the holder.
+    pub fn params(mut self, pp: ProofParamsPublic) -> Self {
+        self.pp = Some(pp);
+        self
+    }
+
+    /// Builds the holder, after checking that the parameters are well-formed (see `ProofParamsPublic::validate`) 
+    /// and that the witness is valid for the element under the parameters.
+    /// 
+    /// Returns `AccumulatorError::MissingField` if any input is not set, the error of `ProofParamsPublic::validate` if the parameters are malformed,
+    /// and `AccumulatorError::StaleWitness` if the witness does not verify, e.g., because it is for another accumulator.
+    pub fn build(self) -> Result<Holder, AccumulatorError> {
+        let pseudo = self.pseudo.ok_or(AccumulatorError::MissingField("pseudonym"))?;
+        let y = self.y.ok_or(AccumulatorError::MissingField("element"))?;
+        let w = self.w.ok_or(AccumulatorError::MissingField("witness"))?;
+        let pp = self.pp.ok_or(AccumulatorError::MissingField("params"))?;
+        pp.validate()?;
+        if !w.verify(y, pp.get_public_key(), pp.get_accumulator()) {
+            return Err(AccumulatorError::StaleWitness);
+        }
+        Ok(StatelessHolder::new(pseudo, y, w).with_params(pp))
+    }
+}
+
+/// Batch updates the witnesses of all the `holders` with the update polynomials `update_poly`,
+/// returning the result of `Holder::batch_update` of each holder in the same order.
+/// 
+/// A failing holder (e.g., a revoked one) does not prevent the update of the others.
+/// With the `parallel` feature, the holders are updated concurrently with rayon.
+pub fn batch_update_holders(holders: &mut [Holder], update_poly: &UpdatePolynomials) -> Vec<Result<MembershipWitness, AccumulatorError>> {
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        holders.par_iter_mut().map(|holder| holder.batch_update(update_poly)).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    holders.iter_mut().map(|holder| holder.batch_update(update_poly)).collect()
 }
 
 impl Updatable for Holder{
@@ -104,12 +707,310 @@ mod tests {
     use super::*;
     use crate::holder::Holder;
     use crate::verifier::Verifier;
-    use crate::issuer::Issuer;
-    use accumulator::{generate_fr, witness, SALT};
+    use crate::issuer::{Issuer, RevocationReason};
+    use accumulator::SecretKey;
     use core::num;
     use std::time::{Instant, SystemTime};
     const ADD_SIZE: usize = 1001;
 
+    #[test]
+    fn holder_batch_update_cached_powers() {
+        // Setup issuer and holders
+        let mut issuer = Issuer::new(None);
+        let rh = issuer.add("holder").unwrap();
+        let mut holder = Holder::new("holder", rh, issuer.get_proof_params());
+        let pseudos: Vec<String> = (0..12).map(|i| i.to_string()).collect();
+        pseudos.iter().for_each(|p| { issuer.add(p).unwrap(); });
+
+        // Successive updates of different sizes share the cached powers and match the plain witness update
+        let mut wit = rh.get_witness();
+        for batch in [&pseudos[..9], &pseudos[9..10], &pseudos[10..]] {
+            let polys = issuer.revoke_elements_instant(batch, RevocationReason::Unspecified).expect("Cannot revoke");
+            wit.batch_update_assign(rh.get_elem(), &polys.deletions, &polys.omegas).expect("Cannot update witness");
+            assert_eq!(holder.batch_update(&polys).expect("Cannot update witness"), wit);
+        }
+        assert_eq!(holder.powers.num_powers(), 9);
+        assert!(Verifier::new(issuer.get_proof_params()).verify(holder.proof_membership_with(&issuer.get_proof_params())));
+    }
+
+    #[test]
+    fn holder_batch_update_with_progress() {
+        // Setup issuer and holder
+        let mut issuer = Issuer::new(None);
+        let rh = issuer.add("holder").unwrap();
+        let mut holder = Holder::new("holder", rh, issuer.get_proof_params());
+        let pseudos: Vec<String> = (0..600).map(|i| i.to_string()).collect();
+        pseudos.iter().for_each(|p| { issuer.add(p).unwrap(); });
+        let polys = issuer.revoke_elements_instant(&pseudos, RevocationReason::Unspecified).expect("Cannot revoke");
+        let pp = issuer.get_proof_params();
+
+        // Aborting after the first window leaves the witness unmodified
+        let mut calls = 0;
+        let res = holder.batch_update_with_progress(&polys, |_, _| { calls += 1; ControlFlow::Break(()) });
+        assert_eq!(res, Err(AccumulatorError::Aborted));
+        assert_eq!(calls, 1);
+        assert_eq!(holder.w, rh.get_witness());
+        assert!(!holder.test_membership_with(&pp));
+
+        // The progress is reported monotonically up to the total number of coefficients
+        let mut reported = Vec::new();
+        let wit = holder.batch_update_with_progress(&polys, |done, total| { reported.push((done, total)); ControlFlow::Continue(()) }).expect("Cannot update witness");
+        assert_eq!(reported.len(), 600usize.div_ceil(Holder::PROGRESS_WINDOW));
+        assert!(reported.windows(2).all(|w| w[0].0 < w[1].0) && reported.iter().all(|&(_, total)| total == 600));
+        assert_eq!(reported.last(), Some(&(600, 600)));
+        let mut expected = rh.get_witness();
+        expected.batch_update_assign(rh.get_elem(), &polys.deletions, &polys.omegas).expect("Cannot update witness");
+        assert_eq!(wit, expected);
+        assert!(holder.test_membership_with(&pp));
+    }
+
+    #[test]
+    fn holder_apply_updates_past_revocation() {
+        // Setup issuer and holders
+        let mut issuer = Issuer::new(None);
+        let rh = issuer.add("holder").unwrap();
+        let other_rh = issuer.add("other").unwrap();
+        let pp = issuer.get_proof_params();
+        (0..4).for_each(|i| { issuer.add(i.to_string()).unwrap(); });
+
+        // The holder is revoked, then added back between two other revocations
+        let mut ops = vec![UpdateOperation::Deletions(issuer.revoke_instant(&"0".to_string(), RevocationReason::Unspecified).unwrap())];
+        ops.push(UpdateOperation::Deletions(issuer.revoke_elements_instant(&["holder".to_string(), "1".to_string()], RevocationReason::Unspecified).unwrap()));
+        ops.push(UpdateOperation::Deletions(issuer.revoke_instant(&"2".to_string(), RevocationReason::Unspecified).unwrap()));
+        let accumulator = issuer.get_accumulator();
+        issuer.reinstate("holder", rh.get_elem()).expect("Cannot reinstate element");
+        ops.push(UpdateOperation::Addition { elem: rh.get_elem(), accumulator });
+        ops.push(UpdateOperation::Deletions(issuer.revoke_instant(&"3".to_string(), RevocationReason::Unspecified).unwrap()));
+        let new_pp = issuer.get_proof_params();
+
+        // By default the revocation aborts the update, leaving the witness unmodified
+        let mut holder = Holder::new("holder", rh, pp);
+        assert_eq!(holder.apply_updates(&ops, false), Err(AccumulatorError::Revoked));
+        assert_eq!(holder.apply_updates(&ops[..3], true), Err(AccumulatorError::Revoked));
+        assert_eq!(holder.w, rh.get_witness());
+
+        // Continuing past the revocation reaches the re-added state
+        holder.apply_updates(&ops, true).expect("Cannot update witness");
+        assert!(holder.test_membership_with(&new_pp));
+        assert!(Verifier::new(new_pp).verify(holder.proof_membership_with(&new_pp)));
+
+        // Other holders follow the whole stream regardless of the option
+        let mut other = Holder::new("other", other_rh, pp);
+        other.apply_updates(&ops, false).expect("Cannot update witness");
+        assert!(other.test_membership_with(&new_pp));
+    }
+
+    #[test]
+    fn holder_proof_membership_checked() {
+        // Setup issuer and holders
+        let mut issuer = Issuer::new(None);
+        let mut holder = Holder::new("holder", issuer.add("holder").unwrap(), issuer.get_proof_params());
+        let mut revoked = Holder::new("revoked", issuer.add("revoked").unwrap(), issuer.get_proof_params());
+        issuer.add("other").unwrap();
+
+        // A fresh witness yields a valid proof
+        let proof = holder.proof_membership_checked(None).expect("Cannot create proof");
+        assert!(Verifier::new(issuer.get_proof_params()).verify(proof));
+
+        // A witness missing an update is stale, until the update is applied
+        let polys = issuer.revoke_instant(&"other".to_string(), RevocationReason::Unspecified).unwrap();
+        let pp = issuer.get_proof_params();
+        assert_eq!(holder.proof_membership_checked(Some(pp)), Err(AccumulatorError::StaleWitness));
+        holder.defer_update(polys.clone());
+        assert!(Verifier::new(pp).verify(holder.proof_membership_checked(Some(pp)).expect("Cannot create proof")));
+
+        // A revoked holder gets the error, while the unchecked variant still builds a (non-verifying) proof
+        revoked.defer_update(polys);
+        revoked.flush_updates().unwrap();
+        let polys = issuer.revoke_instant(&"revoked".to_string(), RevocationReason::Unspecified).unwrap();
+        let pp = issuer.get_proof_params();
+        revoked.defer_update(polys);
+        assert_eq!(revoked.proof_membership_checked(Some(pp)), Err(AccumulatorError::Revoked));
+        assert_eq!(revoked.proof_membership_checked(Some(pp)), Err(AccumulatorError::StaleWitness));
+        assert!(!Verifier::new(pp).verify(revoked.proof_membership_with(&pp)));
+    }
+
+    #[test]
+    fn holder_proof_membership_with_rng() {
+        use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+        // Setup issuer and holder
+        let mut issuer = Issuer::new(None);
+        let mut holder = Holder::new("holder", issuer.add("holder").unwrap(), issuer.get_proof_params());
+        let pp = issuer.get_proof_params();
+        let verifier = Verifier::new(pp);
+
+        // Same seed gives byte-identical proofs
+        let proof_1 = holder.proof_membership_with_rng(&pp, &mut ChaCha20Rng::seed_from_u64(1));
+        let proof_2 = holder.proof_membership_with_rng(&pp, &mut ChaCha20Rng::seed_from_u64(1));
+        assert_eq!(proof_1.to_bytes(), proof_2.to_bytes());
+
+        // Different seeds give different proofs
+        let proof_3 = holder.proof_membership_with_rng(&pp, &mut ChaCha20Rng::seed_from_u64(2));
+        assert_ne!(proof_1.to_bytes(), proof_3.to_bytes());
+
+        // All of them verify
+        assert!(verifier.verify(proof_1));
+        assert!(verifier.verify(proof_2));
+        assert!(verifier.verify(proof_3));
+    }
+
+    #[test]
+    fn holder_standalone_proof() {
+        use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+        // Setup issuer and holder, keeping the raw element and witness
+        let mut issuer = Issuer::new(None);
+        let rh = issuer.add("holder").unwrap();
+        let pp = issuer.get_proof_params();
+        let mut holder = Holder::new("holder", rh, pp);
+
+        // The standalone proof verifies and matches the holder's proof for the same RNG state
+        let proof = Proof::create(&pp, rh.get_elem(), &rh.get_witness(), &mut ChaCha20Rng::seed_from_u64(1));
+        assert!(Verifier::new(pp).verify(proof));
+        assert_eq!(proof, holder.proof_membership_with_rng(&pp, &mut ChaCha20Rng::seed_from_u64(1)));
+    }
+
+    #[test]
+    fn holder_recover() {
+        // Setup issuer and holder, keeping the witness received at issuance
+        let mut issuer = Issuer::new(None);
+        let rh = issuer.add("holder").unwrap();
+        let (y, initial_witness) = (rh.get_elem(), rh.get_witness());
+        let mut holder = Holder::new("holder", rh, issuer.get_proof_params());
+        let pseudos: Vec<String> = (0..6).map(|i| i.to_string()).collect();
+        pseudos.iter().for_each(|p| {issuer.add(p).unwrap();});
+
+        // Publish a revocation history, then the holder loses its witness
+        let history: Vec<UpdatePolynomials> = pseudos.chunks(2)
+            .map(|c| issuer.revoke_elements_instant(c, RevocationReason::Unspecified).unwrap())
+            .collect();
+        holder.batch_updates(&history).unwrap();
+        let pp = issuer.get_proof_params();
+
+        // Replaying the history reconstructs the current witness
+        let mut recovered = Holder::recover("holder", y, initial_witness, &history, pp).expect("Cannot recover witness");
+        assert_eq!(recovered.w, holder.w);
+        assert!(Verifier::new(pp).verify(recovered.proof_membership_cached()));
+
+        // An incomplete history yields a stale witness
+        assert_eq!(Holder::recover("holder", y, initial_witness, &history[1..], pp).err(), Some(AccumulatorError::StaleWitness));
+
+        // A revoked element cannot be recovered
+        let last = issuer.revoke_instant(&"holder".to_string(), RevocationReason::Unspecified).unwrap();
+        let history = [history, vec![last]].concat();
+        let pp = issuer.get_proof_params();
+        assert_eq!(Holder::recover("holder", y, initial_witness, &history, pp).err(), Some(AccumulatorError::Revoked));
+    }
+
+    #[test]
+    fn holder_apply_params_delta() {
+        // Setup issuer and holder
+        let mut issuer = Issuer::new(None);
+        let mut holder = Holder::new("holder", issuer.add("holder").unwrap(), issuer.get_proof_params());
+        issuer.add("revoked").unwrap();
+        let prev = issuer.get_proof_params();
+
+        // Send only the delta of the params after a revocation
+        let polys = issuer.revoke_instant(&"revoked".to_string(), RevocationReason::Unspecified).unwrap();
+        let new = issuer.get_proof_params();
+        let delta: ParamsDelta = bincode::deserialize(&bincode::serialize(&new.delta_from(&prev).unwrap()).unwrap()).unwrap();
+        holder.apply_params_delta(&delta).expect("Cannot apply delta");
+        assert_eq!(holder.pp, new);
+        holder.batch_update(&polys).unwrap();
+        assert!(Verifier::new(new).verify(holder.proof_membership_cached()));
+
+        // A delta after a key rotation is rejected
+        let rotated = issuer.rotate_key(SecretKey::new(None)).params;
+        assert_eq!(rotated.delta_from(&new).err(), Some(AccumulatorError::PublicKeyMismatch));
+        let mut other = Holder::new("other", issuer.add("other").unwrap(), rotated);
+        assert_eq!(other.apply_params_delta(&delta), Err(AccumulatorError::PublicKeyMismatch));
+        assert_eq!(other.pp, rotated);
+    }
+
+    #[test]
+    fn holder_builder() {
+        // Setup issuer, receiving the handle and the params separately
+        let mut issuer = Issuer::new(None);
+        let rh = issuer.add("holder").unwrap();
+        let pp = issuer.get_proof_params();
+
+        // A matching witness and params build a working holder
+        let mut holder = HolderBuilder::new()
+            .pseudonym("holder")
+            .element(rh.get_elem())
+            .witness(rh.get_witness())
+            .params(pp)
+            .build()
+            .expect("Cannot build holder");
+        assert_eq!(holder.get_pseudo(), "holder");
+        assert!(Verifier::new(pp).verify(holder.proof_membership_cached()));
+
+        // A witness not matching the params is rejected
+        issuer.add("other").unwrap();
+        issuer.revoke_instant(&"other".to_string(), RevocationReason::Unspecified).unwrap();
+        let builder = HolderBuilder::new().pseudonym("holder").revocation_handle(rh);
+        assert_eq!(builder.clone().params(issuer.get_proof_params()).build().err(), Some(AccumulatorError::StaleWitness));
+        assert_eq!(builder.clone().params(Issuer::new(None).get_proof_params()).build().err(), Some(AccumulatorError::StaleWitness));
+
+        // Missing inputs are reported
+        assert_eq!(builder.build().err(), Some(AccumulatorError::MissingField("params")));
+        assert_eq!(HolderBuilder::new().revocation_handle(rh).params(pp).build().err(), Some(AccumulatorError::MissingField("pseudonym")));
+    }
+
+    #[test]
+    fn holder_explicit_params() {
+        // Setup issuer and holder
+        let mut issuer = Issuer::new(None);
+        let mut holder = Holder::new("holder", issuer.add("holder").unwrap(), issuer.get_proof_params());
+        issuer.add("other").unwrap();
+        let old_pp = issuer.get_proof_params();
+
+        // Update the witness after a revocation, without updating the cached params
+        let polys = issuer.revoke_instant(&"other".to_string(), RevocationReason::Unspecified).unwrap();
+        let pp = issuer.get_proof_params();
+        holder.batch_update(&polys).unwrap();
+
+        // The explicit form uses the input params, the cached form the stale cached params
+        assert!(holder.test_membership_with(&pp) && !holder.test_membership_with(&old_pp));
+        assert!(!holder.test_membership_cached());
+        assert!(Verifier::new(pp).verify(holder.proof_membership_with(&pp)));
+        assert!(!Verifier::new(pp).verify(holder.proof_membership_cached()));
+
+        // The cached form uses the last params set with update_public_params
+        holder.update_public_params(pp);
+        assert!(holder.test_membership_cached());
+        assert!(Verifier::new(pp).verify(holder.proof_membership_cached()));
+
+        // The deprecated optional form falls back to the cached params
+        #[allow(deprecated)]
+        {
+            assert_eq!(holder.test_membership(None), holder.test_membership_cached());
+            assert_eq!(holder.test_membership(Some(old_pp)), holder.test_membership_with(&old_pp));
+            assert!(Verifier::new(pp).verify(holder.proof_membership(None)));
+        }
+    }
+
+    #[test]
+    fn holder_validated_params() {
+        // Setup issuer
+        let mut issuer = Issuer::new(None);
+        let params = issuer.get_proof_params();
+
+        // Tampered public key and identity accumulator are rejected
+        let mut tampered = params;
+        tampered.x_2 = bls12_381_plus::G2Projective::IDENTITY;
+        assert_eq!(Holder::new_validated("holder", issuer.add("holder").unwrap(), tampered).unwrap_err(), AccumulatorError::InvalidParams);
+        let mut holder = Holder::new_validated("holder", issuer.add("other").unwrap(), params).expect("Valid params rejected");
+        let mut degenerate = params;
+        degenerate.c_m = bls12_381_plus::G1Projective::IDENTITY;
+        assert_eq!(holder.update_public_params_validated(degenerate), Err(AccumulatorError::DegenerateAccumulator));
+
+        // The cached params are unmodified on failure
+        assert!(Verifier::new(params).verify(holder.proof_membership_cached()));
+        holder.update_public_params_validated(params).expect("Valid params rejected");
+    }
+
     #[test]
     fn holder_single_update() {
         // Setup issuer
@@ -125,25 +1026,25 @@ mod tests {
         });
 
         // Delete one of the elements, compute update and get updated params
-        let polys = issuer.revoke_instant(&1.to_string()).expect("Non existing element");
+        let polys = issuer.revoke_instant(&1.to_string(), RevocationReason::Unspecified).expect("Non existing element");
         let pp = issuer.get_proof_params();
 
         // Check non-revoked holder is invalid before updating and is valid after updating
         let mut valid_hol = &mut holders[0];
-        assert!(!valid_hol.test_membership(Some(pp)));
+        assert!(!valid_hol.test_membership_with(&pp));
         let t = Instant::now();
         valid_hol.batch_update(&polys);
         let t = t.elapsed();
-        assert!(valid_hol.test_membership(Some(pp)));
+        assert!(valid_hol.test_membership_with(&pp));
         println!("Time to update witness using polys after single update: {:?}",
             t
         );
 
         // Check revoked holder is always invalid
         let mut revoked_hol = &mut holders[1];
-        assert!(!revoked_hol.test_membership(Some(pp)));
+        assert!(!revoked_hol.test_membership_with(&pp));
         revoked_hol.batch_update(&polys);
-        assert!(!revoked_hol.test_membership(Some(pp)));
+        assert!(!revoked_hol.test_membership_with(&pp));
     }
 
     
@@ -162,16 +1063,16 @@ mod tests {
 
         // Delete one of the elements, compute update and get updated params
         let revoked: Vec<String> = (1..ADD_SIZE).map(|i|i.to_string()).collect();
-        let polys = issuer.revoke_elements_instant(&revoked.as_slice()).expect("Non existing element");
+        let polys = issuer.revoke_elements_instant(revoked.as_slice(), RevocationReason::Unspecified).expect("Non existing element");
         let pp = issuer.get_proof_params();
 
         // Check non-revoked holder is invalid before updating and is valid after updating
         let mut valid_hol = &mut holders[0];
-        assert!(!valid_hol.test_membership(Some(pp)));
+        assert!(!valid_hol.test_membership_with(&pp));
         let t = Instant::now();
         valid_hol.batch_update(&polys);
         let t = t.elapsed();
-        assert!(valid_hol.test_membership(Some(pp)));
+        assert!(valid_hol.test_membership_with(&pp));
         println!("Time to update witness after {} revocations in single batch: {:?}",
             polys.deletions.len(),
             t
@@ -179,9 +1080,9 @@ mod tests {
 
         // Check revoked holder is always invalid
         let mut revoked_hol = &mut holders[1];
-        assert!(!revoked_hol.test_membership(Some(pp)));
+        assert!(!revoked_hol.test_membership_with(&pp));
         revoked_hol.batch_update(&polys);
-        assert!(!revoked_hol.test_membership(Some(pp)));
+        assert!(!revoked_hol.test_membership_with(&pp));
     }
 
     
@@ -202,25 +1103,25 @@ mod tests {
         let mut polys = Vec::new();
 
         // Delete one of the elements and compute update
-        polys.push(issuer.revoke_instant(&1.to_string()).expect("Non existing element"));
+        polys.push(issuer.revoke_instant(&1.to_string(), RevocationReason::Unspecified).expect("Non existing element"));
 
         // Delete one of the elements without updating
         const CHUNK_SIZE: usize = 100;
-        let revoked_pseudos: Vec<String> =  (1..ADD_SIZE).map(|i| i.to_string()).collect();
+        let revoked_pseudos: Vec<String> =  (2..ADD_SIZE).map(|i| i.to_string()).collect();
         let revoked_pseudos: Vec<&[String]> = revoked_pseudos.chunks(CHUNK_SIZE).collect();
         for pseudos in revoked_pseudos{
-            polys.push(issuer.revoke_elements_instant(pseudos).expect("Non existing element"));
+            polys.push(issuer.revoke_elements_instant(pseudos, RevocationReason::Unspecified).expect("Non existing element"));
         }
         
         // Check non-revoked holder is invalid before updating and is valid after updating
         let pp = issuer.get_proof_params();
         let mut valid_hol = &mut holders[0];
-        assert!(!valid_hol.test_membership(Some(pp)));
+        assert!(!valid_hol.test_membership_with(&pp));
         
         let t = Instant::now();
         valid_hol.batch_updates(polys.as_slice());
         let t = t.elapsed();
-        assert!(valid_hol.test_membership(Some(pp)));
+        assert!(valid_hol.test_membership_with(&pp));
         println!("Time to update witness after {} revocations in {} batches of {} elements: {:?}",
             ADD_SIZE-1,
             (((ADD_SIZE-1) as f64)/ (CHUNK_SIZE as f64)).ceil(),
@@ -230,8 +1131,514 @@ mod tests {
 
         // Check revoked holder is always invalid
         let mut revoked_hol = &mut holders[1];
-        assert!(!revoked_hol.test_membership(Some(pp)));
+        assert!(!revoked_hol.test_membership_with(&pp));
         revoked_hol.batch_updates(polys.as_slice());
-        assert!(!revoked_hol.test_membership(Some(pp)));
+        assert!(!revoked_hol.test_membership_with(&pp));
+    }
+
+    #[test]
+    fn holder_sequential_update_messages() {
+        // Setup issuer and holder
+        let mut issuer = Issuer::new(None);
+        let pp = issuer.get_proof_params();
+        let mut holder = Holder::new("holder", issuer.add("holder").expect("Cannot add witness"), pp);
+        (0..5).for_each(|i| { issuer.add(i.to_string()).expect("Cannot add witness"); });
+        let (y, w) = (holder.y, holder.w);
+
+        // Revoke elements getting the sequential update messages
+        let revoked: Vec<String> = (0..5).map(|i| i.to_string()).collect();
+        let update = issuer.revoke_elements_instant_update(&revoked, RevocationReason::Unspecified).expect("Non existing element");
+
+        // Check holder and witness accept the same messages and agree
+        let direct = w.update(y, &update.deletions).expect("Cannot update witness");
+        assert_eq!(holder.update(&update.deletions).expect("Cannot update witness"), direct);
+        assert!(holder.test_membership_with(&issuer.get_proof_params()));
+    }
+
+    #[test]
+    fn holder_sequential_update_revoked() {
+        // Setup issuer and holder
+        let mut issuer = Issuer::new(None);
+        let pp = issuer.get_proof_params();
+        let mut holder = Holder::new("holder", issuer.add("holder").expect("Cannot add witness"), pp);
+        let y = holder.y;
+        let w = holder.w;
+
+        // Build sequential deletions with the holder's element in the middle (revocation is detected from the deleted elements alone)
+        let (mut acc, sk) = (issuer.get_accumulator(), SecretKey::new(None));
+        let elems = [Element::random(), y, Element::random()];
+        let deletions: Vec<Deletion> = elems.iter().map(|&d| Deletion(acc.remove_assign(&sk, d), d)).collect();
+
+        // Check the holder learns it was revoked and its witness is unchanged
+        assert_eq!(holder.update(&deletions).unwrap_err(), AccumulatorError::Revoked);
+        assert_eq!(holder.w, w);
+        assert!(holder.update(&deletions[..1]).is_ok());
+    }
+
+    #[test]
+    fn holder_split_batch_updates() {
+        const NUM_DELETIONS: usize = 50;
+
+        // Setup issuer
+        let mut issuer = Issuer::new(None);
+        let pp = issuer.get_proof_params();
+
+        // Add NUM_DELETIONS+1 holders
+        let mut holders = Vec::with_capacity(NUM_DELETIONS+1);
+        (0..NUM_DELETIONS+1).for_each(|i| {
+            let rh = issuer.add(i.to_string()).expect("Cannot add witness");
+            holders.push(Holder::new(i.to_string(), rh, pp));
+        });
+
+        // Revoke all holders except the first one and split update in pieces of 10 deletions
+        let revoked: Vec<String> = (1..NUM_DELETIONS+1).map(|i| i.to_string()).collect();
+        let polys = issuer.revoke_elements_instant(revoked.as_slice(), RevocationReason::Unspecified).expect("Non existing element");
+        let pieces = polys.split(16 + 10 * 80);
+        assert_eq!(pieces.len(), 5);
+        let pp = issuer.get_proof_params();
+
+        // Check applying the pieces gives the same witness as applying the whole update
+        let valid_hol = &mut holders[0];
+        let whole = valid_hol.w.batch_update(valid_hol.y, &polys.deletions, &polys.omegas).expect("Cannot update witness");
+        let split = valid_hol.batch_updates(pieces.as_slice()).expect("Cannot update witness");
+        assert_eq!(whole, split);
+        assert!(valid_hol.test_membership_with(&pp));
+    }
+
+    #[test]
+    fn holder_batch_updates_failure() {
+        // Setup issuer
+        let mut issuer = Issuer::new(None);
+        let pp = issuer.get_proof_params();
+        let mut holder = Holder::new("holder", issuer.add("holder").expect("Cannot add witness"), pp);
+        (0..10).for_each(|i| { issuer.add(i.to_string()).expect("Cannot add witness"); });
+
+        // Revoke two elements in each of five batches
+        let mut polys: Vec<UpdatePolynomials> = (0..5)
+            .map(|i| {
+                let pseudos = [(2 * i).to_string(), (2 * i + 1).to_string()];
+                issuer.revoke_elements_instant(&pseudos, RevocationReason::Unspecified).expect("Non existing element")
+            })
+            .collect();
+
+        // Check a corrupted middle batch leaves the witness unchanged
+        let wit = holder.w;
+        let omega = polys[2].omegas.pop().expect("Empty update polynomials");
+        assert_eq!(holder.batch_updates(&polys).unwrap_err(), AccumulatorError::LengthMismatch);
+        assert_eq!(holder.w, wit);
+
+        // Check the restored witness accepts the whole update once repaired
+        polys[2].omegas.push(omega);
+        holder.batch_updates(&polys).expect("Cannot update witness");
+        assert!(holder.test_membership_with(&issuer.get_proof_params()));
+    }
+
+    #[test]
+    fn holder_batch_update_shared() {
+        const HOLDERS: usize = 100;
+        const THREADS: usize = 4;
+
+        // Setup issuer and holders
+        let (mut issuer, mut holders) = Issuer::with_members(HOLDERS + 1);
+        let mut revoked = holders.pop().unwrap();
+
+        // Share a single allocation of the update polys across the threads updating the holders
+        let polys = Arc::new(issuer.revoke_instant(&HOLDERS.to_string(), RevocationReason::Unspecified).expect("Non existing element"));
+        std::thread::scope(|scope| {
+            for chunk in holders.chunks_mut(HOLDERS / THREADS) {
+                let polys = Arc::clone(&polys);
+                scope.spawn(move || chunk.iter_mut().for_each(|holder| {holder.batch_update_shared(Arc::clone(&polys)).expect("Cannot update witness");}));
+            }
+        });
+        assert_eq!(Arc::strong_count(&polys), 1);
+
+        // All the holders are valid, and the revoked one cannot update
+        let pp = issuer.get_proof_params();
+        assert!(holders.iter().all(|h| h.test_membership_with(&pp)));
+        assert_eq!(revoked.batch_update_shared(polys).err(), Some(AccumulatorError::Revoked));
+    }
+
+    #[test]
+    fn holder_batch_update_holders() {
+        const HOLDERS: usize = 10_000;
+        const REVOKED: usize = 4242;
+
+        // Setup issuer and holders
+        let mut issuer = Issuer::new(None);
+        let pp = issuer.get_proof_params();
+        let pseudos: Vec<String> = (0..HOLDERS).map(|i| i.to_string()).collect();
+        let rhs = issuer.add_elements(pseudos.iter().cloned()).expect("Cannot add witness");
+        let mut holders: Vec<Holder> = pseudos.iter().zip(rhs).map(|(pseudo, rh)| Holder::new(pseudo, rh, pp)).collect();
+        issuer.add("other").expect("Cannot add witness");
+
+        // Revoke one holder and update all of them
+        let polys = issuer.revoke_elements_instant(&[REVOKED.to_string(), "other".to_string()], RevocationReason::Unspecified)
+            .expect("Non existing element");
+        let t = Instant::now();
+        let results = batch_update_holders(&mut holders, &polys);
+        println!("Time to update {HOLDERS} holders: {:?}", t.elapsed());
+
+        // Check only the revoked holder fails and a sample of the others is valid
+        assert_eq!(results.len(), HOLDERS);
+        for (i, result) in results.iter().enumerate() {
+            assert_eq!(result.is_err(), i == REVOKED);
+        }
+        assert_eq!(results[REVOKED], Err(AccumulatorError::Revoked));
+        let pp = issuer.get_proof_params();
+        assert!(holders.iter().step_by(HOLDERS / 10).all(|h| h.test_membership_with(&pp)));
+    }
+
+    #[test]
+    fn holder_defer_updates() {
+        // Setup issuer
+        let mut issuer = Issuer::new(None);
+        let pp = issuer.get_proof_params();
+        let mut holder = Holder::new("holder", issuer.add("holder").expect("Cannot add witness"), pp);
+        let mut revoked = Holder::new("revoked", issuer.add("revoked").expect("Cannot add witness"), pp);
+        (0..10).for_each(|i| { issuer.add(i.to_string()).expect("Cannot add witness"); });
+
+        // Defer two updates
+        let revoked_pseudos: Vec<String> = (0..5).map(|i| i.to_string()).collect();
+        let polys_1 = issuer.revoke_elements_instant(&revoked_pseudos, RevocationReason::Unspecified).expect("Non existing element");
+        let revoked_pseudos: Vec<String> = (5..10).map(|i| i.to_string()).chain(["revoked".to_string()]).collect();
+        let polys_2 = issuer.revoke_elements_instant(&revoked_pseudos, RevocationReason::Unspecified).expect("Non existing element");
+        let pp = issuer.get_proof_params();
+        for h in [&mut holder, &mut revoked] {
+            h.update_public_params(pp);
+            h.defer_update(polys_1.clone());
+            h.defer_update(polys_2.clone());
+            assert_eq!(h.pending_updates(), 2);
+        }
+
+        // Witness is stale until the next proof flushes the pending updates
+        assert!(!holder.test_membership_cached());
+        let ver = Verifier::new(pp);
+        assert!(ver.verify(holder.proof_membership_cached()));
+        assert_eq!(holder.pending_updates(), 0);
+        assert!(holder.test_membership_cached());
+
+        // Revoked holder consumes the updates but still cannot prove membership
+        assert!(!ver.verify(revoked.proof_membership_cached()));
+        assert_eq!(revoked.pending_updates(), 0);
+    }
+
+    #[test]
+    fn holder_serialized_size() {
+        let mut issuer = Issuer::new(None);
+        for pseudo in ["", "holder", "hölder-😀"] {
+            let holder = Holder::new(pseudo, issuer.add(pseudo).expect("Cannot add witness"), issuer.get_proof_params());
+            let identity = holder.export_identity().len();
+            let params = bincode::serialize(&issuer.get_proof_params()).expect("Serialization error").len();
+            assert_eq!(holder.serialized_size(false), identity);
+            assert_eq!(holder.serialized_size(true), identity + params);
+        }
+    }
+
+    #[test]
+    fn holder_proof_membership_multi_nonce() {
+        // Setup issuer and holder
+        let mut issuer = Issuer::new(None);
+        let holder = Holder::new("holder", issuer.add("holder").expect("Cannot add witness"), issuer.get_proof_params());
+        let ver = Verifier::new(issuer.get_proof_params());
+
+        // Each proof verifies only under its own nonce
+        let nonces: [&[u8]; 3] = [b"nonce 1", b"nonce 2", b"nonce 3"];
+        let proofs = holder.proof_membership_multi_nonce(None, &nonces);
+        assert_eq!(proofs.len(), nonces.len());
+        for (i, proof) in proofs.iter().enumerate() {
+            for (j, nonce) in nonces.iter().enumerate() {
+                assert_eq!(ver.verify_with_context(*proof, nonce), i == j);
+            }
+            assert!(!ver.verify(*proof));
+        }
+        assert!(holder.proof_membership_multi_nonce(None, &[]).is_empty());
+    }
+
+    #[test]
+    fn holder_proof_membership_in_union() {
+        // Setup issuer with three accumulators sharing the same key
+        let mut issuer = Issuer::new(None);
+        let rh = issuer.add("holder").expect("Cannot add witness");
+        let pp = issuer.get_proof_params();
+        let pk = pp.get_public_key();
+        let pps = [
+            ProofParamsPublic::new(&Accumulator::random(rand_core::OsRng {}), &pk),
+            pp,
+            ProofParamsPublic::new(&Accumulator::random(rand_core::OsRng {}), &pk),
+        ];
+        let holder = Holder::new("holder", rh, pp);
+
+        // Proof verifies for the union, whatever the position of the holder's accumulator
+        let proof = holder.proof_membership_in_union(&pps).expect("Cannot create union proof");
+        assert!(proof.verify(&pps));
+        let mut shuffled = pps;
+        shuffled.swap(0, 1);
+        assert!(holder.proof_membership_in_union(&shuffled).expect("Cannot create union proof").verify(&shuffled));
+
+        // Proof does not verify for other accumulators and cannot be created if the holder is not a member
+        let others = [pps[0], pps[2]];
+        assert!(!proof.verify(&others));
+        assert!(!proof.verify(&[pps[0], pps[1]]));
+        assert_eq!(holder.proof_membership_in_union(&others).unwrap_err(), AccumulatorError::NotMember);
+
+        // Accumulators must share the same public key
+        let other_key = ProofParamsPublic::new(&pps[0].get_accumulator(), &PublicKey::from(&SecretKey::new(None)));
+        assert_eq!(holder.proof_membership_in_union(&[pp, other_key]).unwrap_err(), AccumulatorError::PublicKeyMismatch);
+    }
+
+    #[test]
+    fn holder_is_current() {
+        // Setup issuer
+        let mut issuer = Issuer::new(None);
+        let mut holder = Holder::new("holder", issuer.add("holder").expect("Cannot add witness"), issuer.get_proof_params());
+        issuer.add("revoked").expect("Cannot add witness");
+
+        // Id stays equal across a no-op param refresh
+        assert!(holder.is_current(&issuer.get_proof_params()));
+
+        // Id changes after a revocation, until the holder updates
+        let polys = issuer.revoke_instant(&"revoked".to_string(), RevocationReason::Unspecified).expect("Non existing element");
+        let pp = issuer.get_proof_params();
+        assert!(!holder.is_current(&pp));
+        holder.batch_update(&polys).expect("Cannot update witness");
+        holder.update_public_params(pp);
+        assert!(holder.is_current(&pp));
+        assert!(holder.test_membership_cached());
+    }
+
+    #[test]
+    fn holder_self_verified_proof() {
+        // Setup issuer and holders
+        let mut issuer = Issuer::new(None);
+        let pp = issuer.get_proof_params();
+        let mut holder = Holder::new("holder", issuer.add("holder").expect("Cannot add witness"), pp);
+        issuer.add("revoked").expect("Cannot add witness");
+
+        // Check proof passes the self-check with up-to-date witness
+        let proof = holder.proof_membership_self_verified(None).expect("Self-check failed");
+        assert!(Verifier::new(pp).verify(proof));
+
+        // Revoke another holder and check the stale witness fails the self-check
+        issuer.revoke_instant(&"revoked".to_string(), RevocationReason::Unspecified).expect("Non existing element");
+        assert_eq!(holder.proof_membership_self_verified(Some(issuer.get_proof_params())).unwrap_err(), AccumulatorError::ProofSelfCheckFailed);
+
+        // Check the params of another issuer are detected before proving
+        let foreign_pp = Issuer::new(None).get_proof_params();
+        assert_eq!(holder.proof_membership_self_verified(Some(foreign_pp)).unwrap_err(), AccumulatorError::PublicKeyMismatch);
+    }
+
+    #[test]
+    fn holder_export_identity() {
+        // Setup issuer and holder
+        let mut issuer = Issuer::new(None);
+        let pp = issuer.get_proof_params();
+        let holder = Holder::new("holder", issuer.add("holder").expect("Cannot add witness"), pp);
+
+        // Export identity and reload it with the shared params
+        let bytes = holder.export_identity();
+        println!("Holder identity: {} bytes", bytes.len());
+        let restored = Holder::from_identity(&bytes, pp).expect("Cannot restore holder");
+        assert_eq!(restored.get_pseudo(), "holder");
+        assert!(restored.test_membership_cached());
+
+        // Check reloading with the params of another accumulator fails
+        let other_pp = Issuer::new(None).get_proof_params();
+        assert!(matches!(Holder::from_identity(&bytes, other_pp), Err(AccumulatorError::AccumulatorIdMismatch)));
+        assert!(matches!(Holder::from_identity(&bytes[1..], pp), Err(AccumulatorError::BadEncoding)));
+    }
+
+    #[test]
+    fn holder_element_matches_pseudo() {
+        // Setup issuer and holders with derived and random elements
+        let mut issuer = Issuer::new(None);
+        let rh = issuer.add_derived("holder1").expect("Cannot add witness");
+        let holder = Holder::new("holder1", rh, issuer.get_proof_params());
+        let random = Holder::new("holder2", issuer.add("holder2").unwrap(), issuer.get_proof_params());
+        assert!(holder.test_membership(None));
+
+        // Check the same encoding matches, while a whitespace-padded pseudonym or a random element are detected
+        assert!(holder.verify_element_matches("holder1"));
+        assert!(!holder.verify_element_matches("holder1 "));
+        assert!(!random.verify_element_matches("holder2"));
+
+        // Check duplicate and revoked pseudonyms are not derived again
+        assert!(matches!(issuer.add_derived("holder1"), Err(AccumulatorError::DuplicatePseudonym)));
+        issuer.revoke_instant(&"holder1".to_string(), RevocationReason::Unspecified).unwrap();
+        assert!(matches!(issuer.add_derived("holder1"), Err(AccumulatorError::DuplicatePseudonym)));
+    }
+
+    #[test]
+    fn holder_stateless_per_call_params() {
+        // Setup issuer
+        let mut issuer = Issuer::new(None);
+        let rh = issuer.add("holder").expect("Cannot add holder");
+        issuer.add("other").expect("Cannot add other");
+        let pp = issuer.get_proof_params();
+
+        // Create holder without cached params and prove with per-call params
+        let mut holder = StatelessHolder::new("holder", rh.get_elem(), rh.get_witness());
+        assert!(holder.test_membership(&pp));
+        assert!(Verifier::new(pp).verify(holder.proof_membership(&pp)));
+
+        // Update after a revocation and prove against the new params
+        let polys = issuer.revoke_instant(&"other".to_string(), RevocationReason::Unspecified).expect("Non existing element");
+        let new_pp = issuer.get_proof_params();
+        assert!(!holder.test_membership(&new_pp));
+        holder.batch_update(&polys).expect("Cannot update witness");
+        assert!(holder.test_membership(&new_pp));
+        assert!(Verifier::new(new_pp).verify(holder.proof_membership(&new_pp)));
+        assert!(!Verifier::new(new_pp).verify(holder.proof_membership(&pp)));
+
+        // Attach the params to obtain a caching holder
+        let mut cached = holder.with_params(new_pp);
+        assert_eq!(cached.get_pseudo(), "holder");
+        assert!(Verifier::new(new_pp).verify(cached.proof_membership_cached()));
+    }
+
+    #[test]
+    fn holder_try_proof_rng_failure() {
+        // RNG shim failing on every call
+        struct FailingRng;
+        impl RngCore for FailingRng {
+            fn next_u32(&mut self) -> u32 { panic!("RNG failure") }
+            fn next_u64(&mut self) -> u64 { panic!("RNG failure") }
+            fn fill_bytes(&mut self, _dest: &mut [u8]) { panic!("RNG failure") }
+            fn try_fill_bytes(&mut self, _dest: &mut [u8]) -> Result<(), rand_core::Error> {
+                Err(rand_core::Error::from(num::NonZeroU32::new(rand_core::Error::CUSTOM_START).unwrap()))
+            }
+        }
+        impl CryptoRng for FailingRng {}
+
+        // Setup issuer and holder
+        let mut issuer = Issuer::new(None);
+        let pp = issuer.get_proof_params();
+        let mut holder = Holder::new("holder", issuer.add("holder").unwrap(), pp);
+
+        // The RNG failure is returned instead of panicking, the holder can still prove afterwards
+        assert_eq!(holder.try_proof_membership_with_rng(&pp, &mut FailingRng).err(), Some(AccumulatorError::RngFailure));
+        let proof = holder.try_proof_membership_with_rng(&pp, &mut rand_core::OsRng {}).unwrap();
+        assert!(Verifier::new(pp).verify(proof));
+    }
+
+    #[test]
+    fn holder_batch_updates_from_epochs() {
+        // Setup issuer and holder, recording the update epoch of the issued witness
+        let mut issuer = Issuer::new(None);
+        let rh = issuer.add("0").expect("Cannot add witness");
+        (1..6).for_each(|i| { issuer.add(i.to_string()).expect("Cannot add witness"); });
+        let mut holder = Holder::new("0", rh, issuer.get_proof_params());
+        let epoch = issuer.get_update_epoch();
+        (1..4).for_each(|i| { issuer.revoke_instant(&i.to_string(), RevocationReason::Unspecified).unwrap(); });
+        let updates = issuer.epoch_updates_since(epoch);
+        assert_eq!(updates.len(), 3);
+
+        // Reordered chain
+        let reordered = [updates[0].clone(), updates[2].clone(), updates[1].clone()];
+        assert_eq!(holder.batch_updates_from(epoch, &reordered).err(), Some(AccumulatorError::UnexpectedEpoch(epoch + 1)));
+
+        // Chain with a gap, or not starting from the holder's epoch
+        let gap = [updates[0].clone(), updates[2].clone()];
+        assert_eq!(holder.batch_updates_from(epoch, &gap).err(), Some(AccumulatorError::UnexpectedEpoch(epoch + 1)));
+        assert_eq!(holder.batch_updates_from(epoch, &updates[1..]).err(), Some(AccumulatorError::UnexpectedEpoch(epoch)));
+        assert!(!holder.test_membership_with(&issuer.get_proof_params()));
+
+        // Correct chain
+        holder.batch_updates_from(epoch, &updates).expect("Cannot update witness");
+        assert!(holder.test_membership_with(&issuer.get_proof_params()));
+    }
+
+    #[test]
+    fn holder_would_survive() {
+        // Setup issuer and holder
+        let mut issuer = Issuer::new(None);
+        let rh = issuer.add("holder").unwrap();
+        (0..4).for_each(|i| { issuer.add(i.to_string()).unwrap(); });
+        let mut holder = Holder::new("holder", rh, issuer.get_proof_params());
+
+        // Chain revoking the holder in the third epoch
+        let mut history: Vec<UpdatePolynomials> = (0..2)
+            .map(|i| issuer.revoke_instant(&i.to_string(), RevocationReason::Unspecified).unwrap())
+            .collect();
+        assert!(holder.would_survive(&history).is_ok());
+        history.push(issuer.revoke_elements_instant(&["2".to_string(), "holder".to_string()], RevocationReason::Unspecified).unwrap());
+        history.push(issuer.revoke_instant(&"3".to_string(), RevocationReason::Unspecified).unwrap());
+        assert_eq!(holder.would_survive(&history), Err(AccumulatorError::RevokedAt(2)));
+
+        // The dry run agrees with applying the chain
+        assert_eq!(holder.batch_updates(&history).err(), Some(AccumulatorError::Revoked));
+        assert!(holder.batch_updates(&history[..2]).is_ok());
+    }
+
+    #[test]
+    fn holder_multiple_credentials() {
+        // Setup issuer and a holder carrying three additional attributes
+        let mut issuer = Issuer::new(None);
+        let mut holder = Holder::new("holder", issuer.add("holder").unwrap(), issuer.get_proof_params());
+        for attr in ["age", "nationality", "license"] {
+            holder.add_credential(attr, issuer.add(attr).unwrap());
+        }
+        issuer.add("other").unwrap();
+        assert!(holder.proof_membership_for("unknown").is_none());
+
+        // Revoke one attribute and another holder, then update all the entries
+        let polys = issuer.revoke_elements_instant(&["license".to_string(), "other".to_string()], RevocationReason::Unspecified).unwrap();
+        holder.batch_update(&polys).expect("Cannot update witness");
+        holder.update_public_params(issuer.get_proof_params());
+        let ver = Verifier::new(issuer.get_proof_params());
+
+        // The revoked attribute is dropped, while the holder and the other two still prove
+        assert!(holder.get_credential("license").is_none());
+        assert!(holder.proof_membership_for("license").is_none());
+        assert!(ver.verify(holder.proof_membership_for("age").unwrap()));
+        assert!(ver.verify(holder.proof_membership_for("nationality").unwrap()));
+        assert!(ver.verify(holder.proof_membership_cached()));
+
+        // Sequential updates apply to all the entries too
+        let update = issuer.revoke_elements_instant_update(&["age".to_string()], RevocationReason::Unspecified).unwrap();
+        holder.update(&update.deletions).expect("Cannot update witness");
+        holder.update_public_params(issuer.get_proof_params());
+        let ver = Verifier::new(issuer.get_proof_params());
+        assert!(holder.get_credential("age").is_none());
+        assert!(ver.verify(holder.proof_membership_for("nationality").unwrap()));
+        assert!(ver.verify(holder.proof_membership_cached()));
+
+        // A key rotation rotates the remaining credential
+        let rekey = issuer.rotate_key(SecretKey::new(None));
+        holder.apply_rekey(&rekey).expect("Cannot rotate witness");
+        let ver = Verifier::new(rekey.params);
+        assert!(ver.verify(holder.proof_membership_for("nationality").unwrap()));
+        assert!(ver.verify(holder.proof_membership_cached()));
+    }
+
+    #[test]
+    fn holder_credentials_flush_and_failure() {
+        // Setup issuer and a holder carrying two additional attributes
+        let mut issuer = Issuer::new(None);
+        let mut holder = Holder::new("holder", issuer.add("holder").unwrap(), issuer.get_proof_params());
+        for attr in ["age", "license"] {
+            holder.add_credential(attr, issuer.add(attr).unwrap());
+        }
+        issuer.add("other").unwrap();
+
+        // Deferred updates also update the credentials when flushed
+        let polys = issuer.revoke_elements_instant(&["license".to_string(), "other".to_string()], RevocationReason::Unspecified).unwrap();
+        holder.defer_update(polys);
+        holder.flush_updates().expect("Cannot update witness");
+        holder.update_public_params(issuer.get_proof_params());
+        let ver = Verifier::new(issuer.get_proof_params());
+        assert!(holder.get_credential("license").is_none());
+        assert!(ver.verify(holder.proof_membership_for("age").unwrap()));
+        assert!(ver.verify(holder.proof_membership_cached()));
+
+        // If the holder's own update fails, neither its witness nor the credentials are modified
+        let (w, age) = (holder.w, holder.get_credential("age"));
+        let pseudos = ["holder".to_string(), "age".to_string()];
+        let update = issuer.revoke_elements_instant_update(&pseudos, RevocationReason::Unspecified).unwrap();
+        assert_eq!(holder.update(&update.deletions), Err(AccumulatorError::Revoked));
+        assert_eq!(holder.batch_update(&update.polys), Err(AccumulatorError::Revoked));
+        holder.defer_update(update.polys);
+        assert!(holder.flush_updates().is_err());
+        assert_eq!(holder.w, w);
+        assert_eq!(holder.get_credential("age"), age);
     }
 }
@@ -1,19 +1,350 @@
 use accumulator::{
-    accumulator::{Accumulator, Element}, key::{PublicKey, SecretKey}, proof::ProofParamsPublic, window_mul, witness::MembershipWitness, Coefficient
+    accumulator::{Accumulator, AccumulatorDiff, CoefficientStream, Element}, key::{PublicKey, SecretKey}, hash_to_g1, pairing_check, proof::{ProofParamsPublic, RevokedSetCommitment, RevokedSetOpening, TransitionProof}, window_mul, witness::{Deletion, MembershipWitness, NonMembershipWitness}, AccumulatorError, Coefficient, Polynomial
 };
 
-use bls12_381_plus::{G1Projective, Scalar};
+use bls12_381_plus::{G1Affine, G1Projective, G2Affine, Scalar};
 use serde::{Deserialize, Serialize};
-use std::collections::{hash_map::Entry, HashMap};
+use std::{collections::{hash_map::Entry, BTreeMap, HashMap, HashSet}, sync::RwLock};
+use zeroize::Zeroizing;
+use crate::Updatable;
 
 
 /// Represents a pair or update polynomials (\omega(x), dD(x))
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// 
+/// Equality and hashing follow the ordered compressed bytes of deletions and coefficients,
+/// consistently with serialization, so update polynomials can be used as cache keys.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct UpdatePolynomials {
     pub deletions: Vec<Element>,
     pub omegas: Vec<Coefficient>,
 }
 
+impl UpdatePolynomials {
+    /// Serialized size of the two vector length prefixes.
+    const HEADER_BYTES: usize = 16;
+    /// Serialized size of each deletion together with its coefficient.
+    const DELETION_BYTES: usize = Element::BYTES + Coefficient::BYTES;
+
+    /// Returns the size in bytes of the serialized update polynomials.
+    pub fn size(&self) -> usize {
+        Self::HEADER_BYTES + self.deletions.len() * Element::BYTES + self.omegas.len() * Coefficient::BYTES
+    }
+
+    /// Returns the compact byte encoding of the update polynomials, for distribution to holders:
+    /// the varint (LEB128) lengths of the deletions and of the coefficients, followed by the 32-byte big-endian deletions
+    /// and the 48-byte compressed coefficients.
+    /// 
+    /// Compared to `bincode`, which already stores the points compressed, this only shortens the two 8-byte length prefixes.
+    /// The payload is the encoding of uniformly distributed field elements and points, which generic compressors cannot shrink further.
+    pub fn to_compressed_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.size());
+        write_varint(&mut bytes, self.deletions.len());
+        write_varint(&mut bytes, self.omegas.len());
+        self.deletions.iter().for_each(|d| bytes.extend_from_slice(&d.to_bytes()));
+        self.omegas.iter().for_each(|c| bytes.extend_from_slice(&c.to_bytes()));
+        bytes
+    }
+
+    /// Parses update polynomials encoded by `to_compressed_bytes`.
+    /// 
+    /// Returns `AccumulatorError::BadEncoding` if a length is malformed or does not match the payload length,
+    /// a deletion is not canonical, or a coefficient is not a valid compressed point in the prime order subgroup.
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<Self, AccumulatorError> {
+        let (num_deletions, bytes) = read_varint(bytes)?;
+        let (num_omegas, bytes) = read_varint(bytes)?;
+        // Check the lengths against the payload before allocating
+        let expected = num_deletions.checked_mul(Element::BYTES)
+            .zip(num_omegas.checked_mul(Coefficient::BYTES))
+            .and_then(|(d, c)| d.checked_add(c));
+        if expected != Some(bytes.len()) {
+            return Err(AccumulatorError::BadEncoding);
+        }
+
+        let (deletions, omegas) = bytes.split_at(num_deletions * Element::BYTES);
+        let deletions = deletions.chunks_exact(Element::BYTES)
+            .map(Element::try_from_bytes)
+            .collect::<Result<Vec<Element>, AccumulatorError>>()?;
+        let omegas = omegas.chunks_exact(Coefficient::BYTES)
+            .map(|c| {
+                let c = <&[u8; Coefficient::BYTES]>::try_from(c).map_err(|_| AccumulatorError::BadEncoding)?;
+                Option::<G1Affine>::from(G1Affine::from_compressed(c))
+                    .map(|p| Coefficient(G1Projective::from(p)))
+                    .ok_or(AccumulatorError::BadEncoding)
+            })
+            .collect::<Result<Vec<Coefficient>, AccumulatorError>>()?;
+        Ok(UpdatePolynomials { deletions, omegas })
+    }
+
+    /// Sorts the deletions by their byte encoding, so that update polynomials revoking the same elements 
+    /// in a different order become equal and serialize (hence sign or hash, e.g., in a `DeletionCommitment`) identically.
+    /// 
+    /// The coefficients do not depend on the order of the deletions: \omega(x) = (V - dD(x)V') / (x + α), 
+    /// where dD(x) = ∏ (yD_i - x) and V' = V / ∏ (yD_i + α) are symmetric in the deletions, hence they are left unchanged 
+    /// and `Holder::batch_update` gives the same witness before and after canonicalization.
+    pub fn canonicalize(&mut self) {
+        self.deletions.sort_unstable_by_key(|d| d.to_bytes());
+    }
+
+    /// Splits the update polynomials into a sequence of smaller update polynomials,
+    /// each of which serializes to at most `max_bytes` (at least one deletion is always kept per piece).
+    ///
+    /// Each piece is a valid update for the accumulator obtained after applying the previous pieces,
+    /// hence calling `Holder::batch_updates` on the returned pieces reproduces the update of the whole.
+    /// Splitting only requires public values: writing dD_k(x) = ∏ 1..k (yD_i - x), we have
+    /// \omega(x) = \omega_1(x) + dD_k(x) * \omega_2(x), so the pieces are the remainder and quotient of \omega(x) / dD_k(x).
+    ///
+    /// NOTE: each cut costs about `k*(m-k)` point multiplications for `m` deletions, 
+    /// hence splitting in many small pieces is quadratic in the number of deletions.
+    /// If the secret key is available, revoking in smaller batches is much cheaper.
+    pub fn split(&self, max_bytes: usize) -> Vec<UpdatePolynomials> {
+        let chunk_size = usize::max(1, max_bytes.saturating_sub(Self::HEADER_BYTES) / Self::DELETION_BYTES);
+
+        let mut pieces = Vec::with_capacity(self.deletions.len().div_ceil(chunk_size));
+        let mut rest = self.clone();
+        while rest.deletions.len() > chunk_size {
+            let (head, tail) = rest.split_at(chunk_size);
+            pieces.push(head);
+            rest = tail;
+        }
+        pieces.push(rest);
+        pieces
+    }
+
+    /// Merges these update polynomials with the update polynomials `other` received, e.g., from another replica of the issuer,
+    /// which apply after (a suffix of) these ones, returning a single update for the union of the deletions.
+    /// 
+    /// The leading deletions of `other` that are also in `self` must be the last deletions of `self`, and both updates must agree 
+    /// on the coefficients of this common transition, which are only applied once. 
+    /// Without common deletions, `other` must apply to the accumulator reached by `self`: writing dD(x) = ∏ (yD_i - x) over the deletions of `self`,
+    /// the merged polynomial is \omega(x) = \omega_self(x) + dD(x) * \omega_other(x), the inverse of `split`.
+    /// 
+    /// Returns `AccumulatorError::ConflictingUpdates` if the common deletions are not contiguous or the common coefficients differ.
+    /// NOTE: since the accumulator values are not part of the update polynomials, two disjoint updates applying to the same accumulator
+    /// cannot be detected and give an invalid merged update, hence `Holder::test_membership` should be checked after applying it.
+    pub fn merge(&self, other: &UpdatePolynomials) -> Result<UpdatePolynomials, AccumulatorError> {
+        let own: HashSet<&Element> = self.deletions.iter().collect();
+        let k = other.deletions.iter().take_while(|d| own.contains(d)).count();
+        if other.deletions[k..].iter().any(|d| own.contains(d)) || k > self.deletions.len() {
+            return Err(AccumulatorError::ConflictingUpdates);
+        }
+        if k == 0 {
+            return Ok(self.then(other));
+        }
+
+        // Common transition: the last k deletions of self and the first k deletions of other
+        let (_, mut common) = self.split_at(self.deletions.len() - k);
+        let (mut other_common, rest) = other.split_at(k);
+        common.canonicalize();
+        other_common.canonicalize();
+        if common != other_common {
+            return Err(AccumulatorError::ConflictingUpdates);
+        }
+        Ok(match rest.deletions.is_empty() {
+            true => self.clone(),
+            false => self.then(&rest),
+        })
+    }
+
+    /// Aggregates the update polynomials `polys` of consecutive epochs, in the order they were issued, into a single update 
+    /// equivalent to applying them in sequence, e.g., to cache a catch-up update for holders that missed several epochs.
+    /// 
+    /// As in `merge`, each update must apply to the accumulator reached by the previous ones. 
+    /// Returns `AccumulatorError::EmptyInput` if `polys` is empty and `AccumulatorError::LengthMismatch` 
+    /// if any of the updates does not have one coefficient per deletion.
+    pub fn aggregate(polys: &[UpdatePolynomials]) -> Result<UpdatePolynomials, AccumulatorError> {
+        let (first, rest) = polys.split_first().ok_or(AccumulatorError::EmptyInput)?;
+        if polys.iter().any(|p| p.deletions.len() != p.omegas.len()) {
+            return Err(AccumulatorError::LengthMismatch);
+        }
+        Ok(rest.iter().fold(first.clone(), |aggregate, next| aggregate.then(next)))
+    }
+
+    /// Checks that these update polynomials, e.g., received from an untrusted distribution channel, are well-formed 
+    /// before applying them to a witness (see `Holder::batch_update_validated`).
+    /// 
+    /// Returns `AccumulatorError::LengthMismatch` if there is not one coefficient per deletion,
+    /// and `AccumulatorError::InvalidCoefficient` if any coefficient is not in the prime-order subgroup (see `Coefficient::validate`).
+    pub fn validate(&self) -> Result<(), AccumulatorError> {
+        if self.deletions.len() != self.omegas.len() {
+            return Err(AccumulatorError::LengthMismatch);
+        }
+        self.omegas.iter().try_for_each(Coefficient::validate)
+    }
+
+    /// Splits the update polynomials after the first `k` deletions, with `0 < k <= m` for `m` deletions, 
+    /// as the remainder and quotient of \omega(x) / dD_k(x) (see `split`). If `k == m`, the second piece is empty.
+    fn split_at(&self, k: usize) -> (UpdatePolynomials, UpdatePolynomials) {
+        let (head, tail) = self.deletions.split_at(k);
+        let (quotient, remainder) = poly_div(&self.omegas, &deletions_poly(head));
+        (
+            UpdatePolynomials { deletions: head.to_vec(), omegas: remainder },
+            UpdatePolynomials { deletions: tail.to_vec(), omegas: quotient },
+        )
+    }
+
+    /// Concatenates these update polynomials with the update polynomials `next` for the accumulator reached by these ones,
+    /// computing \omega(x) = \omega_self(x) + dD(x) * \omega_next(x), with dD(x) = ∏ (yD_i - x) over the deletions of `self`.
+    fn then(&self, next: &UpdatePolynomials) -> UpdatePolynomials {
+        let d_d = deletions_poly(&self.deletions);
+        let mut omegas: Vec<G1Projective> = self.omegas.iter().map(|c| c.0).collect();
+        omegas.resize(self.omegas.len() + next.omegas.len(), G1Projective::IDENTITY);
+        for (j, c) in next.omegas.iter().enumerate() {
+            // Efficiently compute [c*dD_0, ..., c*dD_m] using window multiplication
+            window_mul(c.0, d_d.0.clone())
+                .into_iter()
+                .enumerate()
+                .for_each(|(i, v)| omegas[i + j] += v);
+        }
+        UpdatePolynomials {
+            deletions: self.deletions.iter().chain(next.deletions.iter()).copied().collect(),
+            omegas: omegas.into_iter().map(Coefficient).collect(),
+        }
+    }
+}
+
+/// Appends the unsigned LEB128 encoding of `value` to `bytes`.
+fn write_varint(bytes: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        bytes.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+/// Reads an unsigned LEB128 value from the start of `bytes`, returning it together with the remaining bytes.
+/// Returns `AccumulatorError::BadEncoding` if the value is truncated, overflows `usize`, or is not minimally encoded.
+fn read_varint(bytes: &[u8]) -> Result<(usize, &[u8]), AccumulatorError> {
+    let mut value = 0usize;
+    for (i, b) in bytes.iter().enumerate() {
+        let shift = 7 * i as u32;
+        let low = (b & 0x7f) as usize;
+        if shift >= usize::BITS || (low << shift) >> shift != low {
+            return Err(AccumulatorError::BadEncoding);
+        }
+        value |= low << shift;
+        if b & 0x80 == 0 {
+            // Reject trailing zero groups, so that each value has a single encoding
+            if i > 0 && *b == 0 {
+                return Err(AccumulatorError::BadEncoding);
+            }
+            return Ok((value, &bytes[i + 1..]));
+        }
+    }
+    Err(AccumulatorError::BadEncoding)
+}
+
+/// Returns the polynomial dD(x) = ∏ (yD_i - x) over the deletions `deletions`.
+fn deletions_poly(deletions: &[Element]) -> Polynomial {
+    let mut d_d = Polynomial::with_capacity(deletions.len() + 1);
+    d_d.push(Scalar::ONE);
+    deletions.iter().for_each(|y| d_d *= &[y.0, -Scalar::ONE]);
+    d_d
+}
+
+/// Represents the same revocation update in both batch form, i.e. the update polynomials,
+/// and sequential form, i.e. the accumulator value after each single deletion.
+/// 
+/// Both forms take about 80 bytes per deletion (an element with either a coefficient or an accumulator), 
+/// hence holders should fetch only the form they use. The batch form is cheaper to apply for many deletions (a single MSM), 
+/// while the sequential form costs one inversion and one point multiplication per deletion but needs no MSM,
+/// which suits light clients and small updates.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Update {
+    pub polys: UpdatePolynomials,
+    pub deletions: Vec<Deletion>,
+}
+
+/// Result of `Issuer::rotate_key`: the public parameters of the re-keyed accumulator 
+/// and the new witness of each member, valid under the new public key.
+/// 
+/// NOTE: each holder only needs its own witness, and the witnesses should not be disclosed to other parties.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RekeyUpdate {
+    pub params: ProofParamsPublic,
+    pub witnesses: HashMap<String, MembershipWitness>,
+}
+
+/// Update polynomials stamped with the update epoch they apply to, i.e., moving the accumulator from `epoch` to `epoch + 1`
+/// (see `Issuer::epoch_updates_since` and `Holder::batch_updates_from`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EpochUpdate {
+    pub epoch: u64,
+    pub polys: UpdatePolynomials,
+}
+
+/// An operation of the update stream followed by holders (see `Holder::apply_updates`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum UpdateOperation {
+    /// A batch of deletions, given as their update polynomials.
+    Deletions(UpdatePolynomials),
+    /// The element `elem` was added back with `Issuer::reinstate` to the accumulator `accumulator`, i.e., the value before the addition.
+    Addition { elem: Element, accumulator: Accumulator },
+}
+
+/// Delegation of the scope `scope` by a root issuer to the sub-issuer with public key `public_key` (see `Issuer::create_sub_issuer`),
+/// i.e., the root's BLS signature on the scope and the sub-issuer's public key.
+/// The signature is under the root's delegation key (see `Issuer::get_delegation_pk`), not its accumulator secret key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Delegation {
+    pub scope: String,
+    pub public_key: PublicKey,
+    sig: G1Projective,
+}
+
+impl Delegation {
+    /// Checks that this delegation was signed by the root issuer with delegation public key `root` (see `Issuer::get_delegation_pk`)
+    /// and that the public parameters `params` are those of the delegated sub-issuer, i.e., chain to `root`.
+    pub fn verify(&self, root: &PublicKey, params: &ProofParamsPublic) -> bool {
+        // e(sig, P~) - e(H(scope, Q_sub~), Q_root~) == 0_{G_t}
+        params.get_public_key() == self.public_key 
+            && pairing_check(&[
+                (G1Affine::from(self.sig), -G2Affine::generator()),
+                (G1Affine::from(delegation_message(&self.scope, &self.public_key)), G2Affine::from(root.0)),
+            ])
+    }
+}
+
+/// Domain separator deriving the delegation key from the root's accumulator secret key.
+const DELEGATION_KEY_DOMAIN: &[u8] = b"Sub-Issuer Delegation Key";
+
+/// Hashes the scope `scope` and the sub-issuer's public key `public_key` to the message signed by `Delegation`.
+fn delegation_message(scope: &str, public_key: &PublicKey) -> G1Projective {
+    let mut data = b"Sub-Issuer Delegation".to_vec();
+    data.extend_from_slice(&(scope.len() as u64).to_be_bytes());
+    data.extend_from_slice(scope.as_bytes());
+    data.extend_from_slice(&public_key.to_bytes());
+    hash_to_g1(data)
+}
+
+/// Divides the polynomial with coefficients `omegas` by the polynomial `divisor`, 
+/// returning the coefficients of the quotient and of the remainder.
+fn poly_div(omegas: &[Coefficient], divisor: &Polynomial) -> (Vec<Coefficient>, Vec<Coefficient>) {
+    let d = divisor.degree();
+    let mut rem: Vec<G1Projective> = omegas.iter().map(|c| c.0).collect();
+    if rem.len() <= d {
+        return (Vec::new(), omegas.to_vec());
+    }
+
+    let lead_inv = divisor.0[d].invert().unwrap();
+    let mut quotient = vec![G1Projective::IDENTITY; rem.len() - d];
+    for i in (0..quotient.len()).rev() {
+        let q = rem[i + d] * lead_inv;
+        // Efficiently compute [q*divisor_0, ..., q*divisor_(d-1)] using window multiplication
+        window_mul(q, divisor.0[..d].to_vec())
+            .into_iter()
+            .enumerate()
+            .for_each(|(j, v)| rem[i + j] -= v);
+        quotient[i] = q;
+    }
+    rem.truncate(d);
+
+    (
+        quotient.into_iter().map(Coefficient).collect(),
+        rem.into_iter().map(Coefficient).collect(),
+    )
+}
+
 /// Represents a pair (C, y) of membership witness, revocation ID 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct RevocationHandle {
@@ -22,14 +353,24 @@ pub struct RevocationHandle {
 }
 
 impl RevocationHandle {
+    /// Length in bytes of the serialized handle, as returned by `to_bytes`.
+    pub const BYTES: usize = Element::BYTES + MembershipWitness::BYTES;
 
     /// Creates a new RevocationHandle an accumulator value and the corrisponding secret key
-    fn new(accumulator: Accumulator, secret_key: &SecretKey) -> Self {
+    /// 
+    /// Returns `AccumulatorError::NonInvertible` if the random element has no witness (see `MembershipWitness::new`).
+    fn new(accumulator: Accumulator, secret_key: &SecretKey) -> Result<Self, AccumulatorError> {
         // Pick a random y
         let elem = Element::random();
         // Create a witness for y
-        let wit = MembershipWitness::new(&elem, accumulator, secret_key);
-        Self { elem, wit }
+        let wit = MembershipWitness::new(&elem, accumulator, secret_key)?;
+        Ok(Self { elem, wit })
+    }
+
+    /// Checks with the secret key `sk`, without pairings, that the witness C is valid for the element y against `acc`, 
+    /// i.e., C*(y + α) == V, e.g., for the issuer to confirm a handle before sending it.
+    pub fn verify_with_secret(&self, sk: &SecretKey, acc: &Accumulator) -> bool {
+        self.wit.0 * (self.elem.0 + sk.0) == acc.0
     }
 
     /// Returns the associated witness C
@@ -42,12 +383,246 @@ impl RevocationHandle {
         return self.elem;
     }
 
+    /// Returns the byte sequence y || C of the element and the compressed witness.
+    pub fn to_bytes(&self) -> [u8; Self::BYTES] {
+        let mut res = [0u8; Self::BYTES];
+        res[..Element::BYTES].copy_from_slice(&self.elem.to_bytes());
+        res[Element::BYTES..].copy_from_slice(&self.wit.to_bytes());
+        res
+    }
+
+    /// Parses a byte sequence produced by `to_bytes`.
+    /// 
+    /// Returns `AccumulatorError::BadEncoding` if the length is not `RevocationHandle::BYTES`, the element is not canonical,
+    /// or the witness is not a valid point (or is the identity).
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, AccumulatorError> {
+        if bytes.len() != Self::BYTES {
+            return Err(AccumulatorError::BadEncoding);
+        }
+        let (elem, wit) = bytes.split_at(Element::BYTES);
+        let elem = Element::try_from_bytes(elem)?;
+        let wit = MembershipWitness::from_bytes(wit)?;
+        if bool::from(wit.0.is_identity()) {
+            return Err(AccumulatorError::BadEncoding);
+        }
+        Ok(Self { elem, wit })
+    }
+
     /// Updates the witness with the input point
     fn update_witness(&mut self, new_wit: G1Projective) {
         self.wit.apply_update(new_wit);
     }
 }
 
+/// Reason recorded in the audit log when revoking an element.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RevocationReason {
+    Unspecified,
+    Expiry,
+    Compromise,
+    Administrative,
+}
+
+/// Audit log entry for a revoked element
+/// 
+/// Also records the accumulator value resulting from the deletion of the element, 
+/// so that the log can be replayed with the public key only (see `RevocationRecord::replay`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevocationRecord {
+    elem: Element,
+    reason: RevocationReason,
+    epoch: usize,
+    acc: Accumulator,
+}
+
+impl RevocationRecord {
+
+    /// Returns the revoked element y
+    pub fn get_elem(&self) -> Element {
+        self.elem
+    }
+
+    /// Returns the reason of the revocation
+    pub fn get_reason(&self) -> RevocationReason {
+        self.reason
+    }
+
+    /// Returns the epoch in which the element was revoked
+    pub fn get_epoch(&self) -> usize {
+        self.epoch
+    }
+
+    /// Returns the accumulator value right after the deletion of the element
+    pub fn get_accumulator(&self) -> Accumulator {
+        self.acc
+    }
+
+    /// Returns the id of the accumulator value right after the deletion of the element
+    pub fn get_accumulator_id(&self) -> Scalar {
+        self.acc.get_id()
+    }
+
+    /// Checks with the public key `pk` that the recorded accumulator is obtained by deleting the element from `previous`,
+    /// i.e., that the recorded accumulator is a membership witness of the element for `previous`.
+    pub fn verify_transition(&self, previous: Accumulator, pk: PublicKey) -> bool {
+        MembershipWitness(self.acc.0).verify(self.elem, pk, previous)
+    }
+
+    /// Replays the audit log `log` from the accumulator `initial`, verifying each transition with `verify_transition`.
+    /// 
+    /// Returns the accumulator reached after the last record, or `AccumulatorError::InvalidTransition` 
+    /// with the index of the first record that does not verify.
+    pub fn replay(initial: Accumulator, log: &[RevocationRecord], pk: PublicKey) -> Result<Accumulator, AccumulatorError> {
+        log.iter().enumerate().try_fold(initial, |previous, (i, record)| {
+            if record.verify_transition(previous, pk) {
+                Ok(record.acc)
+            } else {
+                Err(AccumulatorError::InvalidTransition(i))
+            }
+        })
+    }
+}
+
+/// Bookkeeping counters of an `Issuer`, see `Issuer::stats`.
+/// 
+/// Each enrollment and revocation is counted once, hence `added - revoked` is the number of current members.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IssuerStats {
+    /// Number of elements added with `add`, `add_elements` or `reinstate`.
+    pub added: usize,
+    /// Number of elements revoked, including the ones in the deletion list and the pending deferred deletions.
+    pub revoked: usize,
+    /// Id of the current accumulator value.
+    pub current_id: Scalar,
+}
+
+/// Full state of an `Issuer`, to persist it and restore it after a restart (see `Issuer::export_state`).
+/// 
+/// The accumulator secret key is kept apart from the rest of the state, so that the caller can encrypt it before storing.
+/// NOTE: the public part still contains the issued witnesses, which should not be disclosed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssuerState {
+    pub secret_key: SecretKey,
+    pub public: IssuerPublicState,
+}
+
+/// Part of the `IssuerState` without secret key material.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssuerPublicState {
+    acc_pk: PublicKey,
+    acc: Accumulator,
+    witnesses: HashMap<String, RevocationHandle>,
+    deletions: Vec<Element>,
+    epoch: usize,
+    scheduled: BTreeMap<usize, Vec<(String, RevocationReason)>>,
+    audit_log: Vec<RevocationRecord>,
+    deferred: Vec<Element>,
+    deferred_base: Accumulator,
+    added: usize,
+    revoked: usize,
+    revoked_pseudos: HashSet<String>,
+    seq: u64,
+    changes: HashMap<String, u64>,
+    rewritten_at: u64,
+    update_epoch: u64,
+    update_history: Vec<UpdatePolynomials>,
+}
+
+impl IssuerState {
+    ///Applies the delta `delta`, returned by `Issuer::snapshot_delta`, to this snapshot taken at the checkpoint `delta.since`,
+    ///giving the snapshot at the checkpoint of the delta. Successive deltas can be applied in order.
+    ///
+    ///Returns `AccumulatorError::ConflictingUpdates` if this snapshot was not taken at `delta.since`, 
+    ///or `AccumulatorError::PublicKeyMismatch` if the key was rotated since, in which case a full snapshot is needed,
+    ///since deltas do not contain the secret key. In both cases, the snapshot is not modified.
+    pub fn restore_delta(&mut self, delta: &SnapshotDelta) -> Result<(), AccumulatorError> {
+        let public = &mut self.public;
+        if public.seq != delta.since.seq || public.audit_log.len() != delta.since.audit_len {
+            return Err(AccumulatorError::ConflictingUpdates);
+        }
+        if self.secret_key.public_key() != delta.acc_pk {
+            return Err(AccumulatorError::PublicKeyMismatch);
+        }
+
+        for (pseudo, seq, rh) in &delta.members {
+            match rh {
+                Some(rh) => {
+                    public.witnesses.insert(pseudo.clone(), *rh);
+                    public.revoked_pseudos.remove(pseudo);
+                }
+                None => {
+                    public.witnesses.remove(pseudo);
+                    public.revoked_pseudos.insert(pseudo.clone());
+                }
+            }
+            public.changes.insert(pseudo.clone(), *seq);
+        }
+        public.acc = delta.acc;
+        public.deletions = delta.deletions.clone();
+        public.epoch = delta.epoch;
+        public.scheduled = delta.scheduled.clone();
+        public.audit_log.extend_from_slice(&delta.audit_log);
+        public.deferred = delta.deferred.clone();
+        public.deferred_base = delta.deferred_base;
+        public.added = delta.added;
+        public.revoked = delta.revoked;
+        public.seq = delta.checkpoint.seq;
+        public.rewritten_at = delta.rewritten_at;
+        if public.update_epoch + delta.update_history.len() as u64 != delta.update_epoch {
+            public.update_history.clear();
+        }
+        public.update_history.extend_from_slice(&delta.update_history);
+        public.update_epoch = delta.update_epoch;
+        Ok(())
+    }
+}
+
+/// Position of an `Issuer` in its sequence of changes, see `Issuer::checkpoint`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotCheckpoint {
+    seq: u64,
+    audit_len: usize,
+    update_epoch: u64,
+}
+
+/// Changes of an `Issuer` between two checkpoints, see `Issuer::snapshot_delta`.
+/// 
+/// NOTE: like `IssuerPublicState`, the delta contains the issued witnesses of the changed members, which should not be disclosed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotDelta {
+    since: SnapshotCheckpoint,
+    checkpoint: SnapshotCheckpoint,
+    acc_pk: PublicKey,
+    acc: Accumulator,
+    deletions: Vec<Element>,
+    epoch: usize,
+    scheduled: BTreeMap<usize, Vec<(String, RevocationReason)>>,
+    audit_log: Vec<RevocationRecord>,
+    deferred: Vec<Element>,
+    deferred_base: Accumulator,
+    added: usize,
+    revoked: usize,
+    rewritten_at: u64,
+    update_epoch: u64,
+    /// Update polynomials published since the checkpoint, or the whole retained history if it was reset since
+    update_history: Vec<UpdatePolynomials>,
+    /// Changed members with the sequence number of their last change, and their handle, or `None` if revoked
+    members: Vec<(String, u64, Option<RevocationHandle>)>,
+}
+
+impl SnapshotDelta {
+    /// Returns the checkpoint reached by applying this delta, from which the next delta must be taken.
+    pub fn get_checkpoint(&self) -> SnapshotCheckpoint {
+        self.checkpoint
+    }
+
+    /// Returns the number of members added, revoked, or whose stored witness changed in this delta.
+    pub fn num_members(&self) -> usize {
+        self.members.len()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Issuer {
     acc_sk: SecretKey,
@@ -55,13 +630,40 @@ pub struct Issuer {
     acc: Accumulator,
     witnesses: HashMap<String, RevocationHandle>,
     deletions: Vec<Element>,
+    epoch: usize,
+    scheduled: BTreeMap<usize, Vec<(String, RevocationReason)>>,
+    audit_log: Vec<RevocationRecord>,
+    deferred: Vec<Element>,
+    deferred_base: Accumulator,
+    added: usize,
+    revoked: usize,
+    revoked_pseudos: HashSet<String>,
+    seq: u64,
+    changes: HashMap<String, u64>,
+    rewritten_at: u64,
+    update_epoch: u64,
+    update_history: Vec<UpdatePolynomials>,
+    max_batch: usize,
 }
 
 impl Issuer {
+    ///Default maximum number of deletions of a single update, see `set_max_batch`.
+    pub const DEFAULT_MAX_BATCH: usize = 1 << 20;
+
     ///Creates a new `Issuer` instance.
     ///Generates the accumulator's secret key using the provided seed.  
     pub fn new(seed: Option<&[u8]>) -> Self {
-        let acc_sk = SecretKey::new(seed);
+        Self::from_secret_key(SecretKey::new(seed))
+    }
+
+    ///Same as `new`, but rejects a degenerate seed (empty or all-zero) or secret key with `AccumulatorError::InvalidSecretKey`
+    ///(see `SecretKey::try_new`), e.g., when the seed is supplied by the caller.
+    pub fn try_new(seed: Option<&[u8]>) -> Result<Self, AccumulatorError> {
+        Ok(Self::from_secret_key(SecretKey::try_new(seed)?))
+    }
+
+    ///Creates a new `Issuer` instance with the accumulator's secret key `acc_sk` and a random accumulator.
+    fn from_secret_key(acc_sk: SecretKey) -> Self {
         let acc_pk = PublicKey::from(&acc_sk);
         let acc = Accumulator::random(rand_core::OsRng {});
         Self {
@@ -70,109 +672,605 @@ impl Issuer {
             acc,
             witnesses: HashMap::new(),
             deletions: Vec::new(),
+            epoch: 0,
+            scheduled: BTreeMap::new(),
+            audit_log: Vec::new(),
+            deferred: Vec::new(),
+            deferred_base: acc,
+            added: 0,
+            revoked: 0,
+            revoked_pseudos: HashSet::new(),
+            seq: 0,
+            changes: HashMap::new(),
+            rewritten_at: 0,
+            update_epoch: 0,
+            update_history: Vec::new(),
+            max_batch: Self::DEFAULT_MAX_BATCH,
+        }
+    }
+
+    ///Same as `new`, but reserves room for `capacity` members (see `reserve`).
+    pub fn with_capacity(seed: Option<&[u8]>, capacity: usize) -> Self {
+        let mut issuer = Self::new(seed);
+        issuer.reserve(capacity);
+        issuer
+    }
+
+    ///Reserves room for at least `additional` more members in the internal maps, 
+    ///so that enrolling them (e.g., in bulk with `add_elements`) does not reallocate and rehash the maps.
+    pub fn reserve(&mut self, additional: usize) {
+        self.witnesses.reserve(additional);
+        self.changes.reserve(additional);
+    }
+
+    ///Sets the maximum number of deletions `max_batch` of a single update computed by `revoke_elements_instant` 
+    ///or by `update` from the deletion list filled by `revoke_elements`, which otherwise return `AccumulatorError::BatchTooLarge`
+    ///instead of allocating and computing the coefficients, e.g., to protect an issuer endpoint from oversized requests.
+    ///Larger revocations must be split by the caller (or streamed with `revoke_elements_streaming`).
+    ///
+    ///The default is `DEFAULT_MAX_BATCH`. The limit is a runtime setting, which is not part of the exported state.
+    pub fn set_max_batch(&mut self, max_batch: usize) {
+        self.max_batch = max_batch;
+    }
+
+    ///Returns the maximum number of deletions of a single update, see `set_max_batch`.
+    pub fn get_max_batch(&self) -> usize {
+        self.max_batch
+    }
+
+    ///Restores an `Issuer` from the state `state` returned by `export_state`.
+    ///
+    ///Returns `AccumulatorError::PublicKeyMismatch` if the secret key does not match the stored public key,
+    ///or `AccumulatorError::DegenerateAccumulator` if the stored accumulator is the identity.
+    pub fn from_state(state: IssuerState) -> Result<Self, AccumulatorError> {
+        let IssuerState { secret_key, public } = state;
+        if secret_key.public_key() != public.acc_pk {
+            return Err(AccumulatorError::PublicKeyMismatch);
+        }
+        if public.acc.is_degenerate() {
+            return Err(AccumulatorError::DegenerateAccumulator);
+        }
+        Ok(Self {
+            acc_sk: secret_key,
+            acc_pk: public.acc_pk,
+            acc: public.acc,
+            witnesses: public.witnesses,
+            deletions: public.deletions,
+            epoch: public.epoch,
+            scheduled: public.scheduled,
+            audit_log: public.audit_log,
+            deferred: public.deferred,
+            deferred_base: public.deferred_base,
+            added: public.added,
+            revoked: public.revoked,
+            revoked_pseudos: public.revoked_pseudos,
+            seq: public.seq,
+            changes: public.changes,
+            rewritten_at: public.rewritten_at,
+            update_epoch: public.update_epoch,
+            update_history: public.update_history,
+            max_batch: Self::DEFAULT_MAX_BATCH,
+        })
+    }
+
+    ///Exports the full state of this issuer, which can be restored with `from_state`.
+    pub fn export_state(&self) -> IssuerState {
+        IssuerState {
+            secret_key: self.acc_sk.clone(),
+            public: IssuerPublicState {
+                acc_pk: self.acc_pk,
+                acc: self.acc,
+                witnesses: self.witnesses.clone(),
+                deletions: self.deletions.clone(),
+                epoch: self.epoch,
+                scheduled: self.scheduled.clone(),
+                audit_log: self.audit_log.clone(),
+                deferred: self.deferred.clone(),
+                deferred_base: self.deferred_base,
+                added: self.added,
+                revoked: self.revoked,
+                revoked_pseudos: self.revoked_pseudos.clone(),
+                seq: self.seq,
+                changes: self.changes.clone(),
+                rewritten_at: self.rewritten_at,
+                update_epoch: self.update_epoch,
+                update_history: self.update_history.clone(),
+            },
+        }
+    }
+
+    ///Returns a checkpoint of the current state, to be stored together with a snapshot taken by `export_state`,
+    ///so that later changes can be backed up incrementally with `snapshot_delta`.
+    pub fn checkpoint(&self) -> SnapshotCheckpoint {
+        SnapshotCheckpoint { seq: self.seq, audit_len: self.audit_log.len(), update_epoch: self.update_epoch }
+    }
+
+    ///Returns the changes since the checkpoint `since`, to be applied to the snapshot taken at `since` with `IssuerState::restore_delta`.
+    ///
+    ///The delta contains the members added, revoked, or whose stored witness changed since `since`, the new audit log entries,
+    ///and the rest of the state (accumulator, deletion list, scheduled and deferred revocations, counters), which does not grow with the members.
+    ///NOTE: `update_periodic` and the key rotations rewrite all the stored witnesses, hence the next delta contains all the members.
+    pub fn snapshot_delta(&self, since: &SnapshotCheckpoint) -> SnapshotDelta {
+        let rewritten = self.rewritten_at > since.seq;
+        let members = self.changes
+            .iter()
+            .filter(|(pseudo, seq)| **seq > since.seq || (rewritten && self.witnesses.contains_key(*pseudo)))
+            .map(|(pseudo, seq)| (pseudo.clone(), *seq, self.witnesses.get(pseudo).copied()))
+            .collect();
+        SnapshotDelta {
+            since: *since,
+            checkpoint: self.checkpoint(),
+            acc_pk: self.acc_pk,
+            acc: self.acc,
+            deletions: self.deletions.clone(),
+            epoch: self.epoch,
+            scheduled: self.scheduled.clone(),
+            audit_log: self.audit_log.get(since.audit_len..).unwrap_or_default().to_vec(),
+            deferred: self.deferred.clone(),
+            deferred_base: self.deferred_base,
+            added: self.added,
+            revoked: self.revoked,
+            rewritten_at: self.rewritten_at,
+            update_epoch: self.update_epoch,
+            update_history: self.updates_since(since.update_epoch.max(self.first_update_epoch())),
+            members,
         }
     }
 
+    ///Records that the member `pseudo` was added, revoked, or that its stored witness changed.
+    fn record_change(&mut self, pseudo: &str) {
+        self.seq += 1;
+        self.changes.insert(pseudo.to_string(), self.seq);
+    }
+
+    ///Records that the stored witnesses of all the members changed.
+    ///Since holders cannot catch up with such a change by replaying update polynomials, 
+    ///it also moves to the next update epoch and drops the update history.
+    fn record_rewrite(&mut self) {
+        self.seq += 1;
+        self.rewritten_at = self.seq;
+        self.update_epoch += 1;
+        self.update_history.clear();
+    }
+
+    ///Records an update which moves the accumulator to the next update epoch but is not retained, e.g., because it was streamed.
+    ///Since `updates_since` cannot serve the earlier epochs anymore, it also drops the update history.
+    fn record_unretained_update(&mut self) {
+        self.update_epoch += 1;
+        self.update_history.clear();
+    }
+
+    ///Records the published update polynomials `polys`, which move the accumulator to the next update epoch.
+    fn record_update(&mut self, polys: &UpdatePolynomials) {
+        self.update_epoch += 1;
+        self.update_history.push(polys.clone());
+    }
+
     /// Add a new witness to the list of witnesses
     /// 
     /// If the value is not present, prouces a new instance of `Revocation Handle`.
-    /// Otherwise, does nothing and returns `None`
-    pub fn add<T: Into<String>>(&mut self, pseudo: T) -> Option<RevocationHandle> {
+    /// Otherwise, does nothing and returns `AccumulatorError::DuplicatePseudonym`.
+    /// Also returns `AccumulatorError::NonInvertible`, without adding the pseudonym, if the witness cannot be computed 
+    /// (see `MembershipWitness::new`).
+    pub fn add<T: Into<String>>(&mut self, pseudo: T) -> Result<RevocationHandle, AccumulatorError> {
         let pseudo: String = pseudo.into();
         match self.witnesses.entry(pseudo.clone()) {
-            Entry::Occupied(_) => return None,
+            Entry::Occupied(_) => Err(AccumulatorError::DuplicatePseudonym),
             Entry::Vacant(v) => {
-                let r = v.insert(RevocationHandle::new(self.acc, &self.acc_sk));
-                return Some(*r);
+                let r = *v.insert(RevocationHandle::new(self.acc, &self.acc_sk)?);
+                debug_assert!(r.verify_with_secret(&self.acc_sk, &self.acc));
+                self.added += 1;
+                self.revoked_pseudos.remove(&pseudo);
+                self.record_change(&pseudo);
+                Ok(r)
             }
         }
     }
 
 
+    ///Same as `add`, but the element is the hash of the pseudonym, i.e., `Element::hash(pseudo)`, instead of a random element,
+    ///so that holders can check their element derives from the expected pseudonym (see `Holder::verify_element_matches`).
+    ///
+    ///Returns `AccumulatorError::DuplicatePseudonym` if the pseudonym is already present or was revoked, 
+    ///since its element would be the deleted one.
+    pub fn add_derived<T: Into<String>>(&mut self, pseudo: T) -> Result<RevocationHandle, AccumulatorError> {
+        let pseudo: String = pseudo.into();
+        let elem = Element::hash(pseudo.as_bytes());
+        if self.witnesses.contains_key(&pseudo) || self.revoked_pseudos.contains(&pseudo) || self.deletions.contains(&elem) {
+            return Err(AccumulatorError::DuplicatePseudonym);
+        }
+        let r = RevocationHandle { elem, wit: MembershipWitness::new(&elem, self.acc, &self.acc_sk)? };
+        debug_assert!(r.verify_with_secret(&self.acc_sk, &self.acc));
+        self.added += 1;
+        self.record_change(&pseudo);
+        self.witnesses.insert(pseudo, r);
+        Ok(r)
+    }
+
+    ///Adds back to the accumulator the element `elem` (e.g., of a revoked holder re-enrolling) associated with the pseudonym `pseudo`.
+    ///Note that this operation MODIFIES the accumulator value: holders must update their witness
+    ///with `MembershipWitness::add_update_assign` using the reinstated element and the previous accumulator.
+    ///    
+    ///If neither the pseudonym nor the element are present (or waiting in the deletion list), produces a new `RevocationHandle`.
+    ///Otherwise, does nothing and returns `None`
+    pub fn reinstate<T: Into<String>>(&mut self, pseudo: T, elem: Element) -> Option<RevocationHandle> {
+        let pseudo: String = pseudo.into();
+        if self.witnesses.contains_key(&pseudo) || self.deletions.contains(&elem) || self.witnesses.values().any(|rh| rh.elem == elem) {
+            return None;
+        }
+        let wit = self.acc.add_assign(&self.acc_sk, elem);
+        let rh = RevocationHandle { elem, wit };
+        self.added += 1;
+        self.revoked_pseudos.remove(&pseudo);
+        self.record_change(&pseudo);
+        self.witnesses.insert(pseudo, rh);
+        Some(rh)
+    }
+
+    ///Adds a new witness for each pseudonym in `pseudos` to the list of witnesses.
+    ///The inverses (𝛼+y_i)^-1 are batched in a single field inversion and the witnesses are computed with window multiplication,
+    ///which is considerably faster than calling `add` for each pseudonym.
+    ///
+    ///Additions are witness-preserving: the new witnesses are computed for the current accumulator value, 
+    ///which is NOT modified, hence existing holders need no update (unlike `reinstate`). 
+    ///
+    ///If all the pseudonyms are new and distinct, returns the new instances of `RevocationHandle` in the same order.
+    ///Otherwise, does nothing and returns `AccumulatorError::DuplicatePseudonym`.
+    pub fn add_elements<T: Into<String>>(&mut self, pseudos: impl IntoIterator<Item = T>) -> Result<Vec<RevocationHandle>, AccumulatorError> {
+        let pseudos: Vec<String> = pseudos.into_iter().map(Into::into).collect();
+        let mut distinct = HashSet::with_capacity(pseudos.len());
+        if pseudos.iter().any(|pseudo| self.witnesses.contains_key(pseudo) || !distinct.insert(pseudo)) {
+            return Err(AccumulatorError::DuplicatePseudonym);
+        }
+
+        self.reserve(pseudos.len());
+
+        // C_i = V*(𝛼+y_i)^-1
+        let elems: Vec<Element> = (0..pseudos.len()).map(|_| Element::random()).collect();
+        let coefficients = self.acc_sk.batch_inverses(&elems).into_iter().map(|c| c.0).collect();
+        let wits = window_mul(self.acc.0, coefficients);
+
+        let rhs: Vec<RevocationHandle> = elems.into_iter()
+            .zip(wits)
+            .map(|(elem, wit)| RevocationHandle { elem, wit: MembershipWitness(wit) })
+            .collect();
+        self.added += pseudos.len();
+        pseudos.iter().for_each(|pseudo| {
+            self.revoked_pseudos.remove(pseudo);
+            self.record_change(pseudo);
+        });
+        self.witnesses.extend(pseudos.into_iter().zip(rhs.iter().copied()));
+        Ok(rhs)
+    }
+
+    ///Same as `add_elements`, but returns the new instances of `RevocationHandle` keyed by their pseudonym,
+    ///so that the caller does not need to match them with the pseudonyms by position.
+    pub fn add_map<T: Into<String>>(&mut self, pseudos: impl IntoIterator<Item = T>) -> Result<HashMap<String, RevocationHandle>, AccumulatorError> {
+        let pseudos: Vec<String> = pseudos.into_iter().map(Into::into).collect();
+        let rhs = self.add_elements(pseudos.iter().cloned())?;
+        Ok(pseudos.into_iter().zip(rhs).collect())
+    }
+
+    ///Returns the current `RevocationHandle` of the member with pseudonym `pseudo`, or `None` if it is not a member,
+    ///e.g., to re-send a holder its witness.
+    pub fn handle_for(&self, pseudo: &str) -> Option<RevocationHandle> {
+        self.witnesses.get(pseudo).copied()
+    }
+
     ///Removes the element associated with the psedonym `pseudo` from the list of witnesses, and adds it to the deletion list.
     ///Note that the accumulator value is NOT modified by this operation.
     ///    
     ///If the value is present, returns the old `RevocationHandle`.
     ///Otherwise, does nothing and returns `None`
     pub fn revoke(&mut self, pseudo: &String) -> Option<RevocationHandle> {
-        let rh = self.witnesses.remove(pseudo)?;
+        let rh = self.take_revoked(pseudo)?;
         self.deletions.push(rh.get_elem());       
         return Some(rh);
     }
 
-    ///Deletes the element associated with `pseudo` from the accumulator and the list of witnesses.
+    ///Deletes the element associated with `pseudo` from the accumulator and the list of witnesses,
+    ///recording the revocation `reason` in the audit log.
     ///Note that this operation MODIFIES the accumulator value.
     ///    
     ///If present, returns the update polynomials for the deleted element. 
     ///Otherwise, does nothing and returns `None`
-    pub fn revoke_instant(&mut self, pseudo: &String) -> Option<UpdatePolynomials> {
-        let rh = self.witnesses.remove(pseudo)?;  
+    pub fn revoke_instant(&mut self, pseudo: &String, reason: RevocationReason) -> Option<UpdatePolynomials> {
+        let rh = self.take_revoked(pseudo)?;
         let deletions = vec![rh.elem];
         let omegas = self.acc.update_assign(&self.acc_sk, deletions.as_slice());
-        return Some(UpdatePolynomials{deletions, omegas});
+        self.audit_log.push(RevocationRecord{elem: rh.elem, reason, epoch: self.epoch, acc: self.acc});
+        let polys = UpdatePolynomials{deletions, omegas};
+        self.record_update(&polys);
+        Some(polys)
     }
 
     ///Removes the elements associated with the psedonyms `pseudos` from the list of witnesses, and adds them to the deletion list.
     ///Note that the accumulator value is NOT modified by this operation.
     ///    
     ///Does nothing for all the pseudonyms that are not associated to any accumulated element.
-    pub fn revoke_elements(&mut self, pseudos: &[String]) {
+    ///Returns `AccumulatorError::BatchTooLarge`, without modifying the deletion list, if the deletion list would exceed 
+    ///the maximum batch size (see `set_max_batch`).
+    pub fn revoke_elements(&mut self, pseudos: &[String]) -> Result<(), AccumulatorError> {
+        let existing = pseudos.iter().filter(|pseudo| self.witnesses.contains_key(*pseudo)).count();
+        if self.deletions.len() + existing > self.max_batch {
+            return Err(AccumulatorError::BatchTooLarge(self.max_batch));
+        }
         let mut existing_elements: Vec<Element> = Vec::with_capacity(pseudos.len());
         pseudos.iter().for_each(|pseudo| {
-            if let Some(rh) = self.witnesses.remove(pseudo) {
+            if let Some(rh) = self.take_revoked(pseudo) {
                 existing_elements.push(rh.elem);
             }
         });
         self.deletions.append(&mut existing_elements);
+        Ok(())
     }
 
-    ///Deletes the elements associated with the psedonyms `pseudos` from the accumulator and the list of witnesses.
+    ///Deletes the elements associated with the psedonyms `pseudos` from the accumulator and the list of witnesses,
+    ///recording the revocation `reason` of each element in the audit log.
     ///Note that this operation MODIFIES the accumulator value.
     ///    
-    ///If some elements are present, returns the update polynomials for the deleted elements. 
-    ///Otherwise, does nothing and returns `None`
-    pub fn revoke_elements_instant(&mut self, pseudos: &[String]) -> Option<UpdatePolynomials>{
-        let mut deletions: Vec<Element> = Vec::with_capacity(pseudos.len());
-        
-        // Fill list of deletions with all the elements associated to existing pseudonyms
-        pseudos.iter().for_each(|pseudo| {
-            if let Some(rh) = self.witnesses.remove(pseudo) {
-                deletions.push(rh.elem);
+    ///Returns the update polynomials for the deleted elements, sorted in canonical order (see `UpdatePolynomials::canonicalize`),
+    ///so that revoking the same set in any order yields byte-identical update polynomials.
+    ///If `pseudos` is empty, contains a duplicate, or contains a pseudonym not associated to any element (e.g., already revoked),
+    ///does nothing and returns the corresponding `AccumulatorError` naming the offending pseudonym.
+    ///Also returns `AccumulatorError::BatchTooLarge` if `pseudos` exceeds the maximum batch size (see `set_max_batch`).
+    pub fn revoke_elements_instant(&mut self, pseudos: &[String], reason: RevocationReason) -> Result<UpdatePolynomials, AccumulatorError>{
+        if pseudos.len() > self.max_batch {
+            return Err(AccumulatorError::BatchTooLarge(self.max_batch));
+        }
+        self.check_revocable(pseudos)?;
+        self.revoke_with_reasons(pseudos.iter().map(|pseudo| (pseudo, reason))).ok_or(AccumulatorError::EmptyInput)
+    }
+
+    ///Same as `revoke_elements_instant`, but returns the update coefficients lazily together with the deleted elements,
+    ///e.g., to stream the coefficients of a very large revocation to disk or network as they are computed 
+    ///(see `Accumulator::update_assign_streaming`). 
+    ///
+    ///The deletions are in canonical order, and collecting the coefficients gives the same `UpdatePolynomials` as `revoke_elements_instant`.
+    ///Unlike `revoke_elements_streaming`, which splits the revocation into several smaller updates, this gives a single update.
+    ///NOTE: the update is not retained in the history of `updates_since`, since this would hold all the coefficients in memory:
+    ///it moves to the next update epoch and drops the history, hence holders lagging behind it need a re-issued witness.
+    pub fn revoke_elements_lazy(&mut self, pseudos: &[String], reason: RevocationReason) -> Result<(Vec<Element>, CoefficientStream), AccumulatorError>{
+        self.check_revocable(pseudos)?;
+        let mut deletions = self.take_revocations(pseudos.iter().map(|pseudo| (pseudo, reason)));
+        let coefficients = self.acc.update_assign_streaming(&self.acc_sk, &deletions);
+        deletions.sort_unstable_by_key(|d| d.to_bytes());
+        self.record_unretained_update();
+        Ok((deletions, coefficients))
+    }
+
+    ///Same as `revoke_elements_instant`, but revokes the elements `elems` directly instead of their pseudonyms,
+    ///e.g., for systems tracking the elements of deserialized credentials.
+    ///
+    ///Returns `AccumulatorError::UnknownElement` if any element is not associated to a pseudonym (never added or already revoked),
+    ///and the errors of `revoke_elements_instant` otherwise. On error, nothing is revoked.
+    pub fn revoke_elements_by_value(&mut self, elems: &[Element], reason: RevocationReason) -> Result<UpdatePolynomials, AccumulatorError>{
+        let requested: HashSet<&Element> = elems.iter().collect();
+        let pseudos: HashMap<Element, String> = self.witnesses
+            .iter()
+            .filter(|(_, rh)| requested.contains(&rh.elem))
+            .map(|(pseudo, rh)| (rh.elem, pseudo.clone()))
+            .collect();
+        let pseudos = elems.iter()
+            .map(|elem| pseudos.get(elem).cloned().ok_or(AccumulatorError::UnknownElement))
+            .collect::<Result<Vec<String>, AccumulatorError>>()?;
+        self.revoke_elements_instant(&pseudos, reason)
+    }
+
+    ///Same as `revoke_elements_instant`, but also returns a hiding commitment to the set of revoked elements,
+    ///to be published, e.g., in a transparency log, and its opening, to be kept by the issuer for later audits.
+    ///
+    ///NOTE: the update polynomials contain the deleted elements, hence the commitment only hides them from parties 
+    ///not receiving the updates.
+    pub fn revoke_elements_instant_committed(&mut self, pseudos: &[String], reason: RevocationReason) -> Result<(UpdatePolynomials, RevokedSetCommitment, RevokedSetOpening), AccumulatorError>{
+        let polys = self.revoke_elements_instant(pseudos, reason)?;
+        let (commitment, opening) = RevokedSetCommitment::commit(&polys.deletions);
+        Ok((polys, commitment, opening))
+    }
+
+    ///Deletes the elements associated with the psedonyms `pseudos` from the accumulator and the list of witnesses,
+    ///recording the revocation `reason` of each element in the audit log, without computing the update polynomials.
+    ///Note that this operation MODIFIES the accumulator value.
+    ///
+    ///The deletions are kept pending until `flush_update_polynomials` computes a single update for all of them,
+    ///which is cheaper than computing one update per batch when updates are only published periodically.
+    ///The pseudonyms are checked as in `revoke_elements_instant`.
+    ///NOTE: the other operations modifying the accumulator value (e.g., `revoke_instant` or `update`) must not be called 
+    ///while deletions are pending, since the flushed update applies to the accumulator before the first pending deletion.
+    pub fn revoke_deferred(&mut self, pseudos: &[String], reason: RevocationReason) -> Result<(), AccumulatorError> {
+        self.check_revocable(pseudos)?;
+        if self.deferred.is_empty() {
+            self.deferred_base = self.acc;
+        }
+        for pseudo in pseudos {
+            if let Some(rh) = self.take_revoked(pseudo) {
+                self.deferred.push(rh.elem);
+                self.acc.remove_assign(&self.acc_sk, rh.elem);
+                self.audit_log.push(RevocationRecord{elem: rh.elem, reason, epoch: self.epoch, acc: self.acc});
             }
-        });
+        }
+        Ok(())
+    }
 
-        // Return None if no element was deleted
-        if deletions.is_empty(){
+    ///Computes the update polynomials for all the deletions of `revoke_deferred` since the last flush, 
+    ///which bring witnesses from the accumulator before the first pending deletion to the current accumulator.
+    ///Applying them gives the same witnesses as applying the update polynomials of each batch.
+    ///
+    ///Returns `None` if no deletion is pending.
+    pub fn flush_update_polynomials(&mut self) -> Option<UpdatePolynomials> {
+        if self.deferred.is_empty() {
             return None;
         }
+        let deletions = std::mem::take(&mut self.deferred);
+        let omegas = self.deferred_base.update_assign(&self.acc_sk, &deletions);
+        debug_assert_eq!(self.deferred_base, self.acc);
+        let mut polys = UpdatePolynomials{deletions, omegas};
+        polys.canonicalize();
+        self.record_update(&polys);
+        Some(polys)
+    }
 
-        // Update accumulator and compute update poly
-        let omegas = self.acc.update_assign(&self.acc_sk, deletions.as_slice());
-        Some(UpdatePolynomials{deletions, omegas})
+    ///Same as `revoke_elements_instant`, but returns the update in both batch and sequential forms (see `Update`).
+    ///Computing the sequential form costs one additional point multiplication per deleted element.
+    pub fn revoke_elements_instant_update(&mut self, pseudos: &[String], reason: RevocationReason) -> Result<Update, AccumulatorError>{
+        let mut acc = self.acc;
+        let polys = self.revoke_elements_instant(pseudos, reason)?;
+        let deletions = acc.remove_batch(&self.acc_sk, &polys.deletions);
+        Ok(Update { polys, deletions })
     }
-    
-    ///Performs a batch deletion of all the elements stored in the `deletions` list. 
-    ///Note that this operation modifies the accumulator value and empties the list of deletions.
-    ///    
-    ///If the `deletions` list is not empty, returns the update polynomials.
-    ///Otherwise does nothing and returns `None`.
-    pub fn update(&mut self) -> Option<UpdatePolynomials>{ 
-        // If no deletions return `None`
-        if self.deletions.is_empty() {
-            return None
+
+    ///Returns a non-membership witness for the element `elem` against the current accumulator, 
+    ///e.g., to let an auditor prove with a `NonMembershipProof` that a revoked element is no longer accumulated.
+    ///
+    ///Returns `AccumulatorError::ElementIsMember` if `elem` is associated to one of the current pseudonyms.
+    ///NOTE: the witness must be updated with the deletions of later revocations, see `NonMembershipWitness::update_assign`.
+    pub fn non_membership_witness(&self, elem: Element) -> Result<NonMembershipWitness, AccumulatorError> {
+        if self.witnesses.values().any(|rh| rh.elem == elem) {
+            return Err(AccumulatorError::ElementIsMember);
         }
-        //Compute update polys
-        let omegas = self.acc.update_assign(&self.acc_sk, self.deletions.as_slice());
-        let polys = UpdatePolynomials{deletions: self.deletions.clone(), omegas};
-        //Clear list of deletions
-        self.deletions.clear();
-        return Some(polys)
+        NonMembershipWitness::new(&elem, self.acc, &self.acc_sk)
     }
 
-    ///Performs a periodic update. Revokes any element left in the list of deletions and update all witnesses
-    pub fn update_periodic(&mut self){
-        
-        // Revoke any elements to be revoked
+    ///Proves that the accumulator `new` derives from `old` by deleting `deletions`, e.g., the deletions of an `UpdatePolynomials`,
+    ///so that light clients can check the transition with a constant number of pairings (see `TransitionProof`).
+    ///The proof is signed with a key derived from the trapdoor and verifies under `get_transition_pk`.
+    ///
+    ///Returns `AccumulatorError::ProofSelfCheckFailed` if `new` does not derive from `old`.
+    pub fn prove_transition(&self, old: Accumulator, new: Accumulator, deletions: &[Element]) -> Result<TransitionProof, AccumulatorError> {
+        TransitionProof::new(&self.acc_sk, old, new, deletions)
+    }
+
+    ///Deletes the elements associated with the psedonyms `pseudos` from the accumulator and the list of witnesses
+    ///in consecutive batches of at most `chunk_size` pseudonyms, recording the revocation `reason` of each element in the audit log.
+    ///Note that this operation MODIFIES the accumulator value.
+    ///    
+    ///The update polynomials of each batch are passed to `sink` as soon as they are computed, 
+    ///bounding the issuer's peak memory, and must be applied by holders in the same order (e.g., with `Holder::batch_updates`).
+    ///The whole list `pseudos` is checked as in `revoke_elements_instant` before revoking the first batch.
+    pub fn revoke_elements_streaming(&mut self, pseudos: &[String], reason: RevocationReason, chunk_size: usize, mut sink: impl FnMut(UpdatePolynomials)) -> Result<(), AccumulatorError> {
+        self.check_revocable(pseudos)?;
+        for chunk in pseudos.chunks(chunk_size.max(1)) {
+            sink(self.revoke_elements_instant(chunk, reason)?);
+        }
+        Ok(())
+    }
+
+    ///Checks that `pseudos` is not empty and that its pseudonyms are distinct and associated to accumulated elements.
+    fn check_revocable(&self, pseudos: &[String]) -> Result<(), AccumulatorError> {
+        if pseudos.is_empty() {
+            return Err(AccumulatorError::EmptyInput);
+        }
+        let mut distinct = HashSet::with_capacity(pseudos.len());
+        for pseudo in pseudos {
+            if !self.witnesses.contains_key(pseudo) {
+                return Err(AccumulatorError::UnknownPseudonym(pseudo.clone()));
+            }
+            if !distinct.insert(pseudo) {
+                return Err(AccumulatorError::DuplicateRevocation(pseudo.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    ///Removes the given psedonyms from the list of witnesses, returning the associated elements to delete from the accumulator,
+    ///and records the associated revocation reasons in the audit log, with the intermediate accumulator after each deletion.
+    ///Note that the accumulator value is NOT modified by this operation.
+    fn take_revocations<'a>(&mut self, revocations: impl Iterator<Item = (&'a String, RevocationReason)>) -> Vec<Element> {
+        let mut deletions: Vec<Element> = Vec::with_capacity(revocations.size_hint().0);
+        let mut acc = self.acc;
+        
+        // Fill list of deletions with all the elements associated to existing pseudonyms
+        for (pseudo, reason) in revocations {
+            if let Some(rh) = self.take_revoked(pseudo) {
+                deletions.push(rh.elem);
+                acc.remove_assign(&self.acc_sk, rh.elem);
+                self.audit_log.push(RevocationRecord{elem: rh.elem, reason, epoch: self.epoch, acc});
+            }
+        }
+        deletions
+    }
+
+    ///Removes the pseudonym `pseudo` from the list of witnesses, recording it as revoked for `stats` and `is_revoked`.
+    fn take_revoked(&mut self, pseudo: &String) -> Option<RevocationHandle> {
+        let rh = self.witnesses.remove(pseudo)?;
+        self.revoked += 1;
+        self.revoked_pseudos.insert(pseudo.clone());
+        self.record_change(pseudo);
+        Some(rh)
+    }
+
+    ///Deletes the elements associated with the given psedonyms from the accumulator and the list of witnesses,
+    ///recording the associated revocation reasons in the audit log.
+    fn revoke_with_reasons<'a>(&mut self, revocations: impl Iterator<Item = (&'a String, RevocationReason)>) -> Option<UpdatePolynomials>{
+        let deletions = self.take_revocations(revocations);
+
+        // Return None if no element was deleted
+        if deletions.is_empty(){
+            return None;
+        }
+
+        // Update accumulator and compute update poly, with the deletions in canonical order
+        let omegas = self.acc.update_assign(&self.acc_sk, deletions.as_slice());
+        debug_assert_eq!(Some(self.acc), self.audit_log.last().map(|r| r.acc));
+        let mut polys = UpdatePolynomials{deletions, omegas};
+        polys.canonicalize();
+        self.record_update(&polys);
+        Some(polys)
+    }
+    
+    ///Schedules the revocation of the element associated with the pseudonym `pseudo` at epoch `effective_epoch`,
+    ///recording the revocation `reason` in the audit log once effective.
+    ///Note that the element stays in the accumulator until `roll_epoch` reaches `effective_epoch`.
+    ///    
+    ///If the value is present, returns its current `RevocationHandle`.
+    ///Otherwise, does nothing and returns `None`
+    pub fn schedule_revocation(&mut self, pseudo: &String, effective_epoch: usize, reason: RevocationReason) -> Option<RevocationHandle> {
+        let rh = *self.witnesses.get(pseudo)?;
+        self.scheduled.entry(effective_epoch).or_default().push((pseudo.clone(), reason));
+        Some(rh)
+    }
+
+    ///Moves to the next epoch and deletes from the accumulator all the elements whose scheduled revocation is now effective.
+    ///Note that this operation MODIFIES the accumulator value.
+    ///    
+    ///If some scheduled elements are still present, returns the update polynomials for the deleted elements. 
+    ///Otherwise, returns `None`
+    pub fn roll_epoch(&mut self) -> Option<UpdatePolynomials> {
+        self.epoch += 1;
+        
+        // Keep only the revocations scheduled for future epochs
+        let pending = self.scheduled.split_off(&(self.epoch + 1));
+        let due: Vec<(String, RevocationReason)> = std::mem::replace(&mut self.scheduled, pending)
+            .into_values()
+            .flatten()
+            .collect();
+        self.revoke_with_reasons(due.iter().map(|(pseudo, reason)| (pseudo, *reason)))
+    }
+
+    ///Performs a batch deletion of all the elements stored in the `deletions` list. 
+    ///Note that this operation modifies the accumulator value and empties the list of deletions.
+    ///    
+    ///If the `deletions` list is not empty, returns the update polynomials.
+    ///Otherwise does nothing and returns `None`.
+    pub fn update(&mut self) -> Option<UpdatePolynomials>{ 
+        // If no deletions return `None`
+        if self.deletions.is_empty() {
+            return None
+        }
+        //Compute update polys
+        let omegas = self.acc.update_assign(&self.acc_sk, self.deletions.as_slice());
+        let mut polys = UpdatePolynomials{deletions: self.deletions.clone(), omegas};
+        polys.canonicalize();
+        //Clear list of deletions
+        self.deletions.clear();
+        self.record_update(&polys);
+        return Some(polys)
+    }
+
+    ///Performs a periodic update. Revokes any element left in the list of deletions and update all witnesses
+    pub fn update_periodic(&mut self){
+        
+        // Revoke any elements to be revoked
         if !self.deletions.is_empty(){
             self.acc.remove_elements_assign(&self.acc_sk, self.deletions.as_slice());
             self.deletions.clear();
@@ -192,8 +1290,52 @@ impl Issuer {
             .iter_mut()
             .enumerate()
             .for_each(|(i, (_, rh))| rh.update_witness(new_wits[i]));
+        self.record_rewrite();
     }
 
+    ///Recomputes the witnesses of all current members against the current accumulator, 
+    ///e.g., to reissue them after restoring the issuer from a snapshot.
+    ///The inverses (𝛼+y_i)^-1 are batched in a single field inversion and the witnesses are computed with window multiplication.
+    pub fn regenerate_all_witnesses(&self) -> HashMap<String, MembershipWitness> {
+        let (pseudos, elems): (Vec<&String>, Vec<Element>) = self.witnesses
+            .iter()
+            .map(|(pseudo, rh)| (pseudo, rh.elem))
+            .unzip();
+        let coefficients = self.acc_sk.batch_inverses(&elems).into_iter().map(|c| c.0).collect();
+        let wits = window_mul(self.acc.0, coefficients);
+        pseudos.into_iter().cloned().zip(wits.into_iter().map(MembershipWitness)).collect()
+    }
+
+    ///Re-keys the accumulator to the secret key `new_sk`, e.g., if the current key is suspected compromised:
+    ///the accumulator value is replaced by a fresh random one and the witnesses of all current members are re-issued under `new_sk`.
+    ///
+    ///The new witnesses do not depend on the old key or accumulator, hence witnesses forged with a leaked key are useless after the rotation.
+    ///
+    ///NOTE: this re-issuance is the only rotation mechanism. Keeping the accumulator value and sending each holder
+    ///the factor (y + α)/(y + α') converting its witness cannot be made safe: a holder knows its own y, 
+    ///hence each factor gives a linear equation in α and α', and two colluding holders can solve for both secret keys.
+    ///No holder-independent switch exists either, since the factor depends on y.
+    ///The deletions still waiting in the deletion list or deferred by `revoke_deferred` are dropped, 
+    ///since their elements are not re-issued. The returned `RekeyUpdate` must be delivered to each holder
+    ///(see `Holder::apply_rekey`), which is as expensive as re-issuing its witness.
+    pub fn rotate_key(&mut self, new_sk: SecretKey) -> RekeyUpdate {
+        self.acc_pk = PublicKey::from(&new_sk);
+        self.acc_sk = new_sk;
+        self.acc = Accumulator::random(rand_core::OsRng {});
+        self.deletions.clear();
+        self.deferred.clear();
+        self.deferred_base = self.acc;
+
+        let witnesses = self.regenerate_all_witnesses();
+        witnesses.iter().for_each(|(pseudo, wit)| self.witnesses.get_mut(pseudo).expect("Missing pseudonym").wit = *wit);
+        self.record_rewrite();
+        RekeyUpdate { params: self.get_proof_params(), witnesses }
+    }
+
+    ///Returns the proof parameters for the current accumulator and public key.
+    ///
+    ///NOTE: the parameters are not cached, since building them only copies the two points
+    ///and a cache would have to be invalidated by every operation modifying the accumulator or the key.
     pub fn get_proof_params(&self) -> ProofParamsPublic {
         ProofParamsPublic::new(&self.acc, &self.acc_pk)
     }
@@ -219,6 +1361,442 @@ impl Issuer {
     pub fn get_pk(&self) -> PublicKey {
         return self.acc_pk.clone();
     }
+
+    ///Returns the public key verifying the transition proofs of `prove_transition`, distinct from the accumulator public key.
+    pub fn get_transition_pk(&self) -> PublicKey {
+        TransitionProof::verification_key(&self.acc_sk)
+    }
+
+    ///Creates a sub-issuer for the scope `scope` (e.g., a regional authority), with its own accumulator 
+    ///and a secret key derived from this issuer's secret key and the scope, hence the same scope always gets the same key.
+    ///
+    ///The sub-issuer is independent: its members are only valid for its own accumulator. 
+    ///Verifiers confirm that its public parameters chain to this (root) issuer with the `Delegation` returned by `delegate`.
+    pub fn create_sub_issuer(&self, scope: &str) -> Issuer {
+        Issuer::new(Some(&self.sub_issuer_seed(scope)))
+    }
+
+    ///Returns the public key verifying the delegations of `delegate`, distinct from the accumulator public key.
+    pub fn get_delegation_pk(&self) -> PublicKey {
+        self.acc_sk.derive(DELEGATION_KEY_DOMAIN).public_key()
+    }
+
+    ///Signs the delegation of the scope `scope` to the sub-issuer returned by `create_sub_issuer` for the same scope.
+    ///The delegation is signed with a key derived from the accumulator secret key and verifies under `get_delegation_pk`.
+    pub fn delegate(&self, scope: &str) -> Delegation {
+        let public_key = PublicKey::from(&SecretKey::new(Some(&self.sub_issuer_seed(scope))));
+        let sig = delegation_message(scope, &public_key) * self.acc_sk.derive(DELEGATION_KEY_DOMAIN).0;
+        Delegation { scope: scope.to_string(), public_key, sig }
+    }
+
+    ///Derives the seed of the secret key of the sub-issuer for the scope `scope`.
+    ///The seed contains the secret key bytes, hence it is allocated once and cleared on drop.
+    fn sub_issuer_seed(&self, scope: &str) -> Zeroizing<Vec<u8>> {
+        const PREFIX: &[u8] = b"Sub-Issuer Key";
+        let sk = Zeroizing::new(self.acc_sk.to_bytes());
+        let mut seed = Zeroizing::new(Vec::with_capacity(PREFIX.len() + sk.len() + scope.len()));
+        seed.extend_from_slice(PREFIX);
+        seed.extend_from_slice(sk.as_ref());
+        seed.extend_from_slice(scope.as_bytes());
+        seed
+    }
+
+    pub fn get_epoch(&self) -> usize {
+        self.epoch
+    }
+
+    ///Returns the current update epoch, which starts at 0 and increases by one with every published update polynomials 
+    ///(see `updates_since`) and every re-issuance of all the witnesses (`update_periodic` and the key rotations).
+    ///
+    ///Unlike the scheduling epoch of `get_epoch`, it changes with every modification of the accumulator value visible to holders,
+    ///hence holders can record it with their witness to request the updates they missed.
+    pub fn get_update_epoch(&self) -> u64 {
+        self.update_epoch
+    }
+
+    ///Compares the accumulator `acc` at the update epoch `epoch`, e.g., the one targeted by a holder's update,
+    ///with the current accumulator at the current update epoch (see `Accumulator::diff_epochs`).
+    pub fn diff_accumulator(&self, acc: &Accumulator, epoch: u64) -> AccumulatorDiff {
+        acc.diff_epochs(epoch, &self.acc, self.update_epoch)
+    }
+
+    ///Returns the update polynomials published since the update epoch `epoch` (see `get_update_epoch`), in the order they were issued:
+    ///the i-th one moves the accumulator from epoch `epoch + i` to `epoch + i + 1`, and a holder whose witness is valid at `epoch`
+    ///catches up by applying them with `Holder::batch_updates`.
+    ///
+    ///Returns an empty vector if `epoch` is the current or a future epoch, or if the witnesses were re-issued since `epoch`,
+    ///in which case `epoch < get_update_epoch()` and the holder needs a re-issued witness (e.g., a `RekeyUpdate`).
+    ///NOTE: the history since the last re-issuance is retained in full, hence grows with the number of published updates.
+    pub fn updates_since(&self, epoch: u64) -> Vec<UpdatePolynomials> {
+        let first = self.first_update_epoch();
+        if epoch < first || epoch >= self.update_epoch {
+            return Vec::new();
+        }
+        self.update_history[(epoch - first) as usize..].to_vec()
+    }
+
+    ///Same as `updates_since`, but stamps each update polynomials with the update epoch it applies to,
+    ///so that holders can check with `Holder::batch_updates_from` that no epoch is missing or reordered.
+    pub fn epoch_updates_since(&self, epoch: u64) -> Vec<EpochUpdate> {
+        self.updates_since(epoch).into_iter()
+            .zip(epoch..)
+            .map(|(polys, epoch)| EpochUpdate { epoch, polys })
+            .collect()
+    }
+
+    ///Returns the update epoch from which the retained update history starts.
+    fn first_update_epoch(&self) -> u64 {
+        self.update_epoch - self.update_history.len() as u64
+    }
+
+    /// Returns the audit log of all the instant and scheduled revocations, in revocation order.
+    pub fn get_audit_log(&self) -> &[RevocationRecord] {
+        &self.audit_log
+    }
+
+    ///Exports the audit log, e.g., to hand it over to an independent auditor holding the public key.
+    ///
+    ///The auditor replays it with `RevocationRecord::replay` from the accumulator published before the first revocation,
+    ///and must reach the current accumulator id.
+    ///NOTE: the deletions through `revoke` and `update` are not recorded, and `rotate_key` replaces the accumulator,
+    ///hence the log can only be replayed over periods without such operations.
+    pub fn export_audit_log(&self) -> Vec<RevocationRecord> {
+        self.audit_log.clone()
+    }
+
+    ///Returns the elements revoked since the epoch `since_epoch` (included), to be distributed to verifiers as a blocklist
+    ///(see `Verifier::verify_disclosed`).
+    ///
+    ///Contains the elements recorded in the audit log from `since_epoch` on, and the elements still waiting in the deletion list,
+    ///which count as revoked in the current epoch although the accumulator does not reflect their deletion yet.
+    pub fn recent_revocations(&self, since_epoch: usize) -> Vec<Element> {
+        let mut elements: Vec<Element> = self.audit_log
+            .iter()
+            .filter(|record| record.epoch >= since_epoch)
+            .map(|record| record.elem)
+            .collect();
+        if since_epoch <= self.epoch {
+            elements.extend_from_slice(&self.deletions);
+        }
+        elements
+    }
+
+    ///Returns the number of elements added and revoked so far, together with the current accumulator id.
+    pub fn stats(&self) -> IssuerStats {
+        IssuerStats { added: self.added, revoked: self.revoked, current_id: self.get_accumulator_id() }
+    }
+
+    ///Returns true if the element associated with the pseudonym `pseudo` was revoked, by any of the revocation methods,
+    ///and the pseudonym was not enrolled again since.
+    ///Elements in the deletion list (see `revoke`) are already revoked, even if the accumulator is not yet updated.
+    pub fn is_revoked(&self, pseudo: &str) -> bool {
+        self.revoked_pseudos.contains(pseudo)
+    }
+}
+
+///The issuer is the authority of its accumulator: adopting a received value would invalidate the witnesses it computed 
+///with its secret key, hence both methods leave the issuer unchanged. This lets generic code push parameters to a set of entities 
+///that includes the issuer itself (see `propagate_params`).
+impl Updatable for Issuer {
+
+    ///Ignores the public parameters `new_pp`: the issuer's own parameters are always current (see `get_proof_params`).
+    fn update_public_params(&mut self, _new_pp: ProofParamsPublic) {}
+
+    ///Ignores the accumulator `new_acc`: the issuer's own accumulator is always current (see `get_accumulator`).
+    fn update_accumulator(&mut self, _new_acc: Accumulator) {}
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl Issuer {
+    ///Creates an `Issuer` with `n` members with pseudonyms "0", ..., "n-1", and returns it with their `Holder`s in the same order,
+    ///e.g., to set up realistic scenarios in tests and benchmarks.
+    ///
+    ///Only available with the `testing` feature.
+    ///```
+    ///use entities::prelude::*;
+    ///
+    ///let (mut issuer, mut holders) = Issuer::with_members(1000);
+    ///let polys = issuer.revoke_instant(&"0".to_string(), RevocationReason::Unspecified).unwrap();
+    ///assert!(holders[0].batch_update(&polys).is_err());
+    ///holders[1].batch_update(&polys).unwrap();
+    ///assert!(holders[1].test_membership_with(&issuer.get_proof_params()));
+    ///```
+    pub fn with_members(n: usize) -> (Issuer, Vec<crate::Holder>) {
+        let mut issuer = Issuer::new(None);
+        let rhs = issuer.add_elements((0..n).map(|i| i.to_string())).expect("Distinct pseudonyms");
+        let pp = issuer.get_proof_params();
+        let holders = rhs.into_iter().enumerate().map(|(i, rh)| crate::Holder::new(i.to_string(), rh, pp)).collect();
+        (issuer, holders)
+    }
+}
+
+// The issuer and the values it returns can be shared and moved across threads, e.g., by `SyncIssuer`
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Issuer>();
+    assert_send_sync::<SyncIssuer>();
+    assert_send_sync::<ShardedIssuer>();
+    assert_send_sync::<RevocationHandle>();
+    assert_send_sync::<RevocationRecord>();
+    assert_send_sync::<UpdatePolynomials>();
+    assert_send_sync::<Update>();
+};
+
+///Thread-safe `Issuer` for a shared revocation service, guarded by a `RwLock`.
+///
+///Read-only operations (e.g., `get_proof_params`) take the read lock and do not block each other,
+///while the operations modifying the issuer take the write lock.
+///Each operation is applied atomically, hence readers never observe a half-applied revocation.
+///Use `read` and `write` to perform several operations atomically.
+#[derive(Debug)]
+pub struct SyncIssuer(RwLock<Issuer>);
+
+impl SyncIssuer {
+    ///Creates a new `SyncIssuer` instance wrapping `Issuer::new(seed)`.
+    pub fn new(seed: Option<&[u8]>) -> Self {
+        Self::from(Issuer::new(seed))
+    }
+
+    ///Runs `f` holding the read lock.
+    pub fn read<R>(&self, f: impl FnOnce(&Issuer) -> R) -> R {
+        f(&self.0.read().expect("Poisoned issuer lock"))
+    }
+
+    ///Runs `f` holding the write lock.
+    pub fn write<R>(&self, f: impl FnOnce(&mut Issuer) -> R) -> R {
+        f(&mut self.0.write().expect("Poisoned issuer lock"))
+    }
+
+    ///Same as `Issuer::add`.
+    pub fn add<T: Into<String>>(&self, pseudo: T) -> Result<RevocationHandle, AccumulatorError> {
+        self.write(|iss| iss.add(pseudo))
+    }
+
+    ///Same as `Issuer::add_derived`.
+    pub fn add_derived<T: Into<String>>(&self, pseudo: T) -> Result<RevocationHandle, AccumulatorError> {
+        self.write(|iss| iss.add_derived(pseudo))
+    }
+
+    ///Same as `Issuer::add_elements`.
+    pub fn add_elements<T: Into<String>>(&self, pseudos: impl IntoIterator<Item = T>) -> Result<Vec<RevocationHandle>, AccumulatorError> {
+        self.write(|iss| iss.add_elements(pseudos))
+    }
+
+    ///Same as `Issuer::add_map`.
+    pub fn add_map<T: Into<String>>(&self, pseudos: impl IntoIterator<Item = T>) -> Result<HashMap<String, RevocationHandle>, AccumulatorError> {
+        self.write(|iss| iss.add_map(pseudos))
+    }
+
+    ///Same as `Issuer::handle_for`.
+    pub fn handle_for(&self, pseudo: &str) -> Option<RevocationHandle> {
+        self.read(|iss| iss.handle_for(pseudo))
+    }
+
+    ///Same as `Issuer::revoke`.
+    pub fn revoke(&self, pseudo: &String) -> Option<RevocationHandle> {
+        self.write(|iss| iss.revoke(pseudo))
+    }
+
+    ///Same as `Issuer::revoke_instant`.
+    pub fn revoke_instant(&self, pseudo: &String, reason: RevocationReason) -> Option<UpdatePolynomials> {
+        self.write(|iss| iss.revoke_instant(pseudo, reason))
+    }
+
+    ///Same as `Issuer::revoke_elements`.
+    pub fn revoke_elements(&self, pseudos: &[String]) -> Result<(), AccumulatorError> {
+        self.write(|iss| iss.revoke_elements(pseudos))
+    }
+
+    ///Same as `Issuer::set_max_batch`.
+    pub fn set_max_batch(&self, max_batch: usize) {
+        self.write(|iss| iss.set_max_batch(max_batch))
+    }
+
+    ///Same as `Issuer::revoke_elements_instant`.
+    pub fn revoke_elements_instant(&self, pseudos: &[String], reason: RevocationReason) -> Result<UpdatePolynomials, AccumulatorError> {
+        self.write(|iss| iss.revoke_elements_instant(pseudos, reason))
+    }
+
+    ///Same as `Issuer::revoke_elements_lazy`.
+    pub fn revoke_elements_lazy(&self, pseudos: &[String], reason: RevocationReason) -> Result<(Vec<Element>, CoefficientStream), AccumulatorError> {
+        self.write(|iss| iss.revoke_elements_lazy(pseudos, reason))
+    }
+
+    ///Same as `Issuer::revoke_elements_by_value`.
+    pub fn revoke_elements_by_value(&self, elems: &[Element], reason: RevocationReason) -> Result<UpdatePolynomials, AccumulatorError> {
+        self.write(|iss| iss.revoke_elements_by_value(elems, reason))
+    }
+
+    ///Same as `Issuer::revoke_deferred`.
+    pub fn revoke_deferred(&self, pseudos: &[String], reason: RevocationReason) -> Result<(), AccumulatorError> {
+        self.write(|iss| iss.revoke_deferred(pseudos, reason))
+    }
+
+    ///Same as `Issuer::flush_update_polynomials`.
+    pub fn flush_update_polynomials(&self) -> Option<UpdatePolynomials> {
+        self.write(|iss| iss.flush_update_polynomials())
+    }
+
+    ///Same as `Issuer::update`.
+    pub fn update(&self) -> Option<UpdatePolynomials> {
+        self.write(|iss| iss.update())
+    }
+
+    ///Same as `Issuer::roll_epoch`.
+    pub fn roll_epoch(&self) -> Option<UpdatePolynomials> {
+        self.write(|iss| iss.roll_epoch())
+    }
+
+    ///Same as `Issuer::get_proof_params`.
+    ///Returns the proof parameters for the current accumulator and public key.
+    ///
+    ///NOTE: the parameters are not cached, since building them only copies the two points
+    ///and a cache would have to be invalidated by every operation modifying the accumulator or the key.
+    pub fn get_proof_params(&self) -> ProofParamsPublic {
+        self.read(|iss| iss.get_proof_params())
+    }
+
+    ///Same as `Issuer::get_accumulator`.
+    pub fn get_accumulator(&self) -> Accumulator {
+        self.read(|iss| iss.get_accumulator())
+    }
+
+    ///Same as `Issuer::get_witnesses`.
+    pub fn get_witnesses(&self) -> HashMap<String, MembershipWitness> {
+        self.read(|iss| iss.get_witnesses())
+    }
+
+    ///Same as `Issuer::get_epoch`.
+    pub fn get_epoch(&self) -> usize {
+        self.read(|iss| iss.get_epoch())
+    }
+
+    ///Same as `Issuer::get_update_epoch`.
+    pub fn get_update_epoch(&self) -> u64 {
+        self.read(|iss| iss.get_update_epoch())
+    }
+
+    ///Same as `Issuer::diff_accumulator`.
+    pub fn diff_accumulator(&self, acc: &Accumulator, epoch: u64) -> AccumulatorDiff {
+        self.read(|iss| iss.diff_accumulator(acc, epoch))
+    }
+
+    ///Same as `Issuer::updates_since`.
+    pub fn updates_since(&self, epoch: u64) -> Vec<UpdatePolynomials> {
+        self.read(|iss| iss.updates_since(epoch))
+    }
+
+    ///Same as `Issuer::epoch_updates_since`.
+    pub fn epoch_updates_since(&self, epoch: u64) -> Vec<EpochUpdate> {
+        self.read(|iss| iss.epoch_updates_since(epoch))
+    }
+
+    ///Same as `Issuer::recent_revocations`.
+    pub fn recent_revocations(&self, since_epoch: usize) -> Vec<Element> {
+        self.read(|iss| iss.recent_revocations(since_epoch))
+    }
+
+    ///Same as `Issuer::stats`.
+    pub fn stats(&self) -> IssuerStats {
+        self.read(|iss| iss.stats())
+    }
+
+    ///Same as `Issuer::is_revoked`.
+    pub fn is_revoked(&self, pseudo: &str) -> bool {
+        self.read(|iss| iss.is_revoked(pseudo))
+    }
+}
+
+impl From<Issuer> for SyncIssuer {
+    fn from(iss: Issuer) -> Self {
+        Self(RwLock::new(iss))
+    }
+}
+
+///Issuer splitting its members across independent accumulators (shards), each managed by its own `Issuer`,
+///so that a revocation only changes the accumulator of one shard and only its holders need updating.
+///
+///Each pseudonym is mapped deterministically to a shard by hashing it (see `shard_of`), since the elements are drawn 
+///at enrollment and revocations are addressed by pseudonym. A holder proves membership against the `ProofParamsPublic` of its shard, 
+///and verifiers accepting all shards can use a `FederatedVerifier`, which selects the parameters by accumulator id.
+///
+///NOTE: each shard has its own key, and the accumulator id in a proof reveals the shard of the holder,
+///hence the anonymity set of a holder is its shard rather than all the members.
+#[derive(Debug, Clone)]
+pub struct ShardedIssuer {
+    shards: Vec<Issuer>,
+}
+
+impl ShardedIssuer {
+    ///Creates a new `ShardedIssuer` with `num_shards` shards, each with its own secret key. 
+    ///If `seed` is provided, the key of shard i is generated from `seed` followed by the big-endian bytes of i.
+    ///
+    ///Returns `AccumulatorError::EmptyInput` if `num_shards` is zero.
+    pub fn new(num_shards: usize, seed: Option<&[u8]>) -> Result<Self, AccumulatorError> {
+        if num_shards == 0 {
+            return Err(AccumulatorError::EmptyInput);
+        }
+        let shards = (0..num_shards as u64)
+            .map(|i| match seed {
+                Some(seed) => Issuer::new(Some(&[seed, &i.to_be_bytes()].concat())),
+                None => Issuer::new(None),
+            })
+            .collect();
+        Ok(Self { shards })
+    }
+
+    ///Returns the number of shards.
+    pub fn num_shards(&self) -> usize {
+        self.shards.len()
+    }
+
+    ///Returns the index of the shard of the pseudonym `pseudo`, which only depends on `pseudo` and the number of shards.
+    pub fn shard_of(&self, pseudo: &str) -> usize {
+        let hash = u64::from_be_bytes(Element::hash(pseudo.as_bytes()).short_id());
+        (hash % self.shards.len() as u64) as usize
+    }
+
+    ///Returns the issuer managing the shard `shard`, or `None` if the index is out of range.
+    pub fn get_shard(&self, shard: usize) -> Option<&Issuer> {
+        self.shards.get(shard)
+    }
+
+    ///Adds the pseudonym `pseudo` to its shard (see `Issuer::add`), 
+    ///returning the index of the shard together with the new `RevocationHandle`.
+    pub fn add<T: Into<String>>(&mut self, pseudo: T) -> Result<(usize, RevocationHandle), AccumulatorError> {
+        let pseudo: String = pseudo.into();
+        let shard = self.shard_of(&pseudo);
+        self.shards[shard].add(pseudo).map(|rh| (shard, rh))
+    }
+
+    ///Adds the element associated with `pseudo` to the deletion list of its shard (see `Issuer::revoke`).
+    pub fn revoke(&mut self, pseudo: &String) -> Option<RevocationHandle> {
+        let shard = self.shard_of(pseudo);
+        self.shards[shard].revoke(pseudo)
+    }
+
+    ///Deletes the element associated with `pseudo` from the accumulator of its shard (see `Issuer::revoke_instant`),
+    ///returning the index of the shard together with the update polynomials for its holders.
+    pub fn revoke_instant(&mut self, pseudo: &String, reason: RevocationReason) -> Option<(usize, UpdatePolynomials)> {
+        let shard = self.shard_of(pseudo);
+        self.shards[shard].revoke_instant(pseudo, reason).map(|polys| (shard, polys))
+    }
+
+    ///Updates the accumulator of each shard with its deletion list (see `Issuer::update`), 
+    ///returning the update polynomials of each shard, or `None` for the shards whose holders need no update.
+    pub fn update(&mut self) -> Vec<Option<UpdatePolynomials>> {
+        self.shards.iter_mut().map(Issuer::update).collect()
+    }
+
+    ///Returns the proof parameters of the shard `shard`, or `None` if the index is out of range.
+    pub fn get_proof_params(&self, shard: usize) -> Option<ProofParamsPublic> {
+        self.shards.get(shard).map(Issuer::get_proof_params)
+    }
+
+    ///Returns the proof parameters of all the shards, in shard order.
+    pub fn get_all_proof_params(&self) -> Vec<ProofParamsPublic> {
+        self.shards.iter().map(Issuer::get_proof_params).collect()
+    }
 }
 
 
@@ -227,8 +1805,9 @@ impl Issuer {
 mod tests {
     use super::*;
     use crate::holder::Holder;
-    use crate::verifier::Verifier;
-    use accumulator::{generate_fr, witness, SALT};
+    use crate::verifier::{FederatedVerifier, Verifier};
+    use crate::Updatable;
+    use accumulator::{DeletionCommitment, NonMembershipProof};
     use core::num;
     use std::time::{Instant, SystemTime};
     const ADD_SIZE: usize = 1000;
@@ -253,10 +1832,10 @@ mod tests {
         let rh = rh.expect("Cannot issue witness");
 
         //Holder
-        let holder = Holder::new(String::from("Holder"), rh, pp.clone());
+        let mut holder = Holder::new(String::from("Holder"), rh, pp);
         let t = Instant::now();
         let proof_params = iss.get_proof_params();
-        let proof = holder.proof_membership(None);
+        let proof = holder.proof_membership_cached();
         println!(
             "Time to create membership proof: {:?}",
             t.elapsed()
@@ -289,7 +1868,7 @@ mod tests {
 
         // Delete one of the elements and compute update
         let t = Instant::now();
-        let polys = issuer.revoke_instant(&0.to_string()).expect("Non existing element");
+        let polys = issuer.revoke_instant(&0.to_string(), RevocationReason::Unspecified).expect("Non existing element");
         println!(
             "Time to remove one element and compute update polynomials: {:?}",
             t.elapsed()
@@ -331,7 +1910,7 @@ mod tests {
         let num_deletions = ADD_SIZE / 2;
         let deletions: Vec<String> = (0..num_deletions).map(|i| i.to_string()).collect();
         let t = Instant::now();
-        let polys = issuer.revoke_elements_instant(deletions.as_slice());
+        let polys = issuer.revoke_elements_instant(deletions.as_slice(), RevocationReason::Unspecified);
         println!(
             "Time to revoke {num_deletions} witness and compute update polynomials: {:?}",
             t.elapsed()
@@ -372,7 +1951,7 @@ mod tests {
 
         // Delete one of the elements and compute update
         let t = Instant::now();
-        polys.push(issuer.revoke_instant(&1.to_string()).expect("Non existing element"));
+        polys.push(issuer.revoke_instant(&1.to_string(), RevocationReason::Unspecified).expect("Non existing element"));
         println!(
             "Time to remove one element and compute update polynomials: {:?}",
             t.elapsed()
@@ -381,7 +1960,7 @@ mod tests {
         // Delete one of the elements without updating
         let t = Instant::now();
         let revoked_pseudos: Vec<String> =  (2..ADD_SIZE/2).map(|i| i.to_string()).collect();
-        polys.push(issuer.revoke_elements_instant(&revoked_pseudos.as_slice()).expect("Non existing element"));
+        polys.push(issuer.revoke_elements_instant(revoked_pseudos.as_slice(), RevocationReason::Unspecified).expect("Non existing element"));
         println!(
             "Time to remove {} elements and compute update polynomials: {:?}",
             ADD_SIZE/2-2,
@@ -399,7 +1978,7 @@ mod tests {
         // Delete one of the elements without updating
         let t = Instant::now();
         let revoked_pseudos: Vec<String> =  (ADD_SIZE/2+2..ADD_SIZE).map(|i| i.to_string()).collect();
-        issuer.revoke_elements(&revoked_pseudos.as_slice());
+        issuer.revoke_elements(revoked_pseudos.as_slice()).unwrap();
         println!(
             "Time to remove {} elements without computing update: {:?}",
             ADD_SIZE-ADD_SIZE/2-2,
@@ -436,51 +2015,1264 @@ mod tests {
     }
 
     #[test]
-    fn issuer_epoch_update() {
+    fn issuer_update_compressed_bytes() {
+        // Setup issuer and holder
+        let mut issuer = Issuer::new(None);
+        let rh = issuer.add("holder").expect("Cannot add witness");
+        let mut holder = Holder::new("holder", rh, issuer.get_proof_params());
+        let mut copy = Holder::new("holder", rh, issuer.get_proof_params());
+        let revoked: Vec<String> = (0..100).map(|i| i.to_string()).collect();
+        issuer.add_elements(revoked.iter().cloned()).expect("Cannot add elements");
+        let polys = issuer.revoke_elements_instant(&revoked, RevocationReason::Unspecified).expect("Cannot revoke");
+
+        // The compact encoding saves the length prefixes of bincode
+        let compressed = polys.to_compressed_bytes();
+        let serialized = bincode::serialize(&polys).expect("Serialization error");
+        println!("Update of {} deletions: {} bytes compressed, {} bytes with bincode", revoked.len(), compressed.len(), serialized.len());
+        assert_eq!(compressed.len(), 2 + 100 * UpdatePolynomials::DELETION_BYTES);
+        assert_eq!(serialized.len() - compressed.len(), UpdatePolynomials::HEADER_BYTES - 2);
+
+        // The parsed update drives the holder's update identically
+        let parsed = UpdatePolynomials::from_compressed_bytes(&compressed).expect("Cannot parse update");
+        assert_eq!(parsed, polys);
+        assert_eq!(holder.batch_update(&parsed), copy.batch_update(&polys));
+        assert!(holder.test_membership_with(&issuer.get_proof_params()));
+
+        // Empty updates and lengths above 127 round-trip
+        let empty = UpdatePolynomials { deletions: Vec::new(), omegas: Vec::new() };
+        assert_eq!(UpdatePolynomials::from_compressed_bytes(&empty.to_compressed_bytes()), Ok(empty));
+        let long = UpdatePolynomials { deletions: vec![Element::one(); 300], omegas: Vec::new() };
+        assert_eq!(UpdatePolynomials::from_compressed_bytes(&long.to_compressed_bytes()), Ok(long));
+
+        // Malformed inputs are rejected
+        let err = Err(AccumulatorError::BadEncoding);
+        assert_eq!(UpdatePolynomials::from_compressed_bytes(&compressed[..compressed.len() - 1]), err);
+        assert_eq!(UpdatePolynomials::from_compressed_bytes(&[compressed.as_slice(), &[0]].concat()), err);
+        assert_eq!(UpdatePolynomials::from_compressed_bytes(&[0x80, 0x00, 0x00]), err);
+        assert_eq!(UpdatePolynomials::from_compressed_bytes(&[0xff; 12]), err);
+        let mut invalid = compressed.clone();
+        invalid[2 + 100 * Element::BYTES..][..Coefficient::BYTES].copy_from_slice(&[0xff; Coefficient::BYTES]);
+        assert_eq!(UpdatePolynomials::from_compressed_bytes(&invalid), err);
+    }
+
+    #[test]
+    fn issuer_split_update() {
         // Setup issuer
         let mut issuer = Issuer::new(None);
-        
-        // Compute witnesses for ADD_SIZE elements
+
+        // Compute witnesses for ADD_SIZE+1 elements
         let mut elements = Vec::new();
         let mut witness = Vec::new();
-        (0..ADD_SIZE).for_each(|i| {
+        (0..ADD_SIZE+1).for_each(|i| {
             let rh = issuer.add(i.to_string()).expect("Cannot add witness");
             witness.push(rh.get_witness());
             elements.push(rh.get_elem());
         });
 
-        // Simulate we have ADD_SIZE/2 elements to delete
-        let num_deletions = ADD_SIZE / 2;
-        let deletions: Vec<String> = (0..num_deletions).map(|i| i.to_string()).collect();
-        issuer.revoke_elements(deletions.as_slice());
-
-        // Revoke removed elements and update all witnessses for valid elements
+        // Revoke ADD_SIZE elements and split the update in chunks of at most 990 deletions
+        let revoked: Vec<String> = (1..ADD_SIZE+1).map(|i| i.to_string()).collect();
+        let polys = issuer.revoke_elements_instant(revoked.as_slice(), RevocationReason::Unspecified).expect("Deletion list is empty");
+        assert_eq!(polys.omegas.len(), ADD_SIZE);
+        let max_bytes = UpdatePolynomials::HEADER_BYTES + 990 * UpdatePolynomials::DELETION_BYTES;
         let t = Instant::now();
-        issuer.update_periodic();
+        let pieces = polys.split(max_bytes);
         println!(
-            "Time to compute periodic update of {} witness: {:?}",
-            ADD_SIZE-num_deletions,
+            "Time to split update of {} deletions in {} pieces: {:?}",
+            ADD_SIZE,
+            pieces.len(),
             t.elapsed()
         );
+        assert_eq!(pieces.len(), 2);
+        pieces.iter().for_each(|p| assert!(p.size() <= max_bytes));
 
-        let new_wits = issuer.get_witnesses();
+        // Check applying all pieces equals applying the unsplit update
+        let (y, wit) = (elements[0], witness[0]);
+        let whole = wit.batch_update(y, &polys.deletions, &polys.omegas).expect("Error when evaluating poly");
+        let mut split_wit = wit;
+        for p in &pieces{
+            split_wit.batch_update_assign(y, &p.deletions, &p.omegas).expect("Error when evaluating poly");
+        }
+        assert_eq!(whole, split_wit);
+        assert!(split_wit.verify(y, issuer.get_pk(), issuer.get_accumulator()));
+    }
 
-        // Check all witnesses in previous witnesss list are invalid
-        (0..ADD_SIZE).for_each(|i|{
-            assert!(!witness[i].verify(elements[i], issuer.get_pk(), issuer.get_accumulator()));
-        });
+    #[test]
+    fn issuer_scheduled_revocation() {
+        // Setup issuer and holders
+        let mut issuer = Issuer::new(None);
+        let pp = issuer.get_proof_params();
+        let mut valid_hol = Holder::new("valid", issuer.add("valid").unwrap(), pp);
+        let mut revoked_hol = Holder::new("revoked", issuer.add("revoked").unwrap(), pp);
 
-        // Check non-revoked witness are updated
-        (num_deletions..ADD_SIZE).for_each(|i|{
-            let wit = new_wits.get(&i.to_string());
-            let wit = wit.expect("Non-revoked element is not present in witness list!");
-            assert!(wit.verify(elements[i], issuer.get_pk(), issuer.get_accumulator()))
-        });
+        // Schedule revocation two epochs out
+        let effective_epoch = issuer.get_epoch() + 2;
+        assert!(issuer.schedule_revocation(&"revoked".to_string(), effective_epoch, RevocationReason::Expiry).is_some());
+        assert!(issuer.schedule_revocation(&"unknown".to_string(), effective_epoch, RevocationReason::Expiry).is_none());
 
-        // Check revoked witness are not updated
-        (0..num_deletions).for_each(|i|{
-            let wit = new_wits.get(&i.to_string());
-            assert!(wit.is_none());
+        // Check holder is still valid in the next epoch
+        assert!(issuer.roll_epoch().is_none());
+        assert!(revoked_hol.test_membership_with(&issuer.get_proof_params()));
+
+        // Check holder is revoked at the effective epoch
+        let polys = issuer.roll_epoch().expect("No update poly");
+        assert_eq!(issuer.get_epoch(), effective_epoch);
+        let pp = issuer.get_proof_params();
+        assert!(valid_hol.batch_update(&polys).is_ok());
+        assert!(valid_hol.test_membership_with(&pp));
+        assert_eq!(revoked_hol.batch_update(&polys).unwrap_err(), accumulator::AccumulatorError::Revoked);
+        assert!(!revoked_hol.test_membership_with(&pp));
+        assert!(issuer.roll_epoch().is_none());
+    }
+
+    #[test]
+    fn issuer_revoke_streaming() {
+        const NUM_DELETIONS: usize = 10_000;
+        const CHUNK_SIZE: usize = 1_000;
+
+        // Setup issuer and holder
+        let mut issuer = Issuer::new(None);
+        let pp = issuer.get_proof_params();
+        let mut holder = Holder::new("holder", issuer.add("holder").expect("Cannot add witness"), pp);
+        let revoked: Vec<String> = (0..NUM_DELETIONS).map(|i| i.to_string()).collect();
+        revoked.iter().for_each(|pseudo| {issuer.add(pseudo).expect("Cannot add witness");});
+
+        // Revoke all elements streaming update polys in chunks
+        let mut chunks = Vec::new();
+        let t = Instant::now();
+        issuer.revoke_elements_streaming(&revoked, RevocationReason::Compromise, CHUNK_SIZE, |polys| {
+            assert!(polys.deletions.len() <= CHUNK_SIZE);
+            chunks.push(polys);
+        }).expect("Non existing element");
+        println!("Time to revoke {NUM_DELETIONS} elements in chunks of {CHUNK_SIZE}: {:?}", t.elapsed());
+        assert_eq!(chunks.len(), NUM_DELETIONS / CHUNK_SIZE);
+        assert_eq!(issuer.get_audit_log().len(), NUM_DELETIONS);
+
+        // Check holder applies the chunks in order and is valid
+        let pp = issuer.get_proof_params();
+        assert!(!holder.test_membership_with(&pp));
+        holder.batch_updates(&chunks).expect("Cannot update witness");
+        assert!(holder.test_membership_with(&pp));
+    }
+
+    #[test]
+    fn issuer_revoke_lazy() {
+        const NUM_DELETIONS: usize = 1_000;
+
+        // Setup two identical issuers and a holder
+        let (mut issuer, mut holders) = Issuer::with_members(NUM_DELETIONS + 1);
+        let mut eager = issuer.clone();
+        let mut holder = holders.pop().unwrap();
+        let revoked: Vec<String> = (0..NUM_DELETIONS).rev().map(|i| i.to_string()).collect();
+
+        // Collect the streamed coefficients and compare with the eager update polys
+        let (deletions, stream) = issuer.revoke_elements_lazy(&revoked, RevocationReason::Unspecified).expect("Non existing element");
+        assert_eq!(stream.len(), NUM_DELETIONS);
+        let polys = UpdatePolynomials{deletions, omegas: stream.collect()};
+        assert_eq!(polys, eager.revoke_elements_instant(&revoked, RevocationReason::Unspecified).expect("Non existing element"));
+        assert_eq!(issuer.get_accumulator(), eager.get_accumulator());
+        assert_eq!(issuer.get_audit_log(), eager.get_audit_log());
+
+        // Holder updates as with the eager update polys
+        holder.batch_update(&polys).expect("Cannot update witness");
+        assert!(holder.test_membership_with(&issuer.get_proof_params()));
+
+        // The streamed update is not retained in the history
+        assert_eq!(issuer.get_update_epoch(), 1);
+        assert!(issuer.updates_since(0).is_empty());
+        assert_eq!(issuer.revoke_elements_lazy(&revoked[..1], RevocationReason::Unspecified).err(), Some(AccumulatorError::UnknownPseudonym(revoked[0].clone())));
+    }
+
+    #[test]
+    fn issuer_revocation_reasons() {
+        // Setup issuer
+        let mut issuer = Issuer::new(None);
+        let elems: Vec<Element> = (0..4).map(|i| issuer.add(i.to_string()).expect("Cannot add witness").get_elem()).collect();
+
+        // Revoke elements with different reasons, including a scheduled revocation
+        let acc = issuer.get_accumulator();
+        let polys = issuer.revoke_instant(&0.to_string(), RevocationReason::Compromise).expect("Non existing element");
+        assert_eq!(acc.remove(&issuer.acc_sk, elems[0]), issuer.get_accumulator());
+        assert_eq!(polys.deletions, vec![elems[0]]);
+        issuer.revoke_elements_instant(&[1.to_string(), 2.to_string()], RevocationReason::Administrative).expect("Non existing element");
+        issuer.schedule_revocation(&3.to_string(), 1, RevocationReason::Expiry).expect("Non existing element");
+        issuer.roll_epoch().expect("No update poly");
+
+        // Check the log records each revocation with its reason and epoch
+        let log: Vec<(Element, RevocationReason, usize)> = issuer.get_audit_log().iter().map(|r| (r.get_elem(), r.get_reason(), r.get_epoch())).collect();
+        assert_eq!(log, vec![
+            (elems[0], RevocationReason::Compromise, 0),
+            (elems[1], RevocationReason::Administrative, 0),
+            (elems[2], RevocationReason::Administrative, 0),
+            (elems[3], RevocationReason::Expiry, 1),
+        ]);
+
+        // Check non-existing pseudonyms are not logged
+        assert!(issuer.revoke_instant(&0.to_string(), RevocationReason::Compromise).is_none());
+        assert_eq!(issuer.get_audit_log().len(), 4);
+    }
+
+    #[test]
+    fn issuer_update_polys_hash() {
+        use std::hash::{DefaultHasher, Hash, Hasher};
+        let hash = |polys: &UpdatePolynomials| { let mut h = DefaultHasher::new(); polys.hash(&mut h); h.finish() };
+
+        // Setup issuer and compute update polys
+        let mut issuer = Issuer::new(None);
+        (0..3).for_each(|i| {issuer.add(i.to_string()).expect("Cannot add witness");});
+        let polys = issuer.revoke_elements_instant(&["1".to_string(), "2".to_string()], RevocationReason::Unspecified).expect("Non existing element");
+
+        // Check identically built polys are equal, hash equal and serialize equal
+        let same = UpdatePolynomials{deletions: polys.deletions.clone(), omegas: polys.omegas.clone()};
+        assert_eq!(polys, same);
+        assert_eq!(hash(&polys), hash(&same));
+        assert_eq!(bincode::serialize(&polys).unwrap(), bincode::serialize(&same).unwrap());
+
+        // Check perturbed and reordered polys differ
+        let mut perturbed = same.clone();
+        perturbed.omegas[0] = Coefficient(perturbed.omegas[0].0.double());
+        assert_ne!(polys, perturbed);
+        assert_ne!(hash(&polys), hash(&perturbed));
+        let mut reordered = same.clone();
+        reordered.deletions.reverse();
+        assert_ne!(hash(&polys), hash(&reordered));
+    }
+
+    #[test]
+    fn issuer_regenerate_all_witnesses() {
+        // Setup issuer and revoke some elements
+        let mut issuer = Issuer::new(None);
+        let elems: HashMap<String, Element> = (0..ADD_SIZE).map(|i| (i.to_string(), issuer.add(i.to_string()).expect("Cannot add witness").get_elem())).collect();
+        let revoked: Vec<String> = (0..10).map(|i| i.to_string()).collect();
+        issuer.revoke_elements_instant(&revoked, RevocationReason::Unspecified).expect("Non existing element");
+
+        // Regenerate witnesses and check they all verify against current params
+        let t = Instant::now();
+        let wits = issuer.regenerate_all_witnesses();
+        println!("Time to regenerate {} witnesses: {:?}", wits.len(), t.elapsed());
+        let pp = issuer.get_proof_params();
+        assert_eq!(wits.len(), ADD_SIZE - revoked.len());
+        wits.iter().for_each(|(pseudo, wit)| assert!(wit.verify(elems[pseudo], pp.get_public_key(), pp.get_accumulator())));
+    }
+
+    #[test]
+    fn issuer_add_elements() {
+        const NUM_ELEMENTS: usize = 5000;
+
+        // Add elements one by one
+        let mut issuer = Issuer::new(None);
+        let t_add = Instant::now();
+        (0..NUM_ELEMENTS).for_each(|i| { issuer.add(i.to_string()).expect("Cannot add witness"); });
+        let t_add = t_add.elapsed();
+
+        // Add elements in a batch
+        let mut issuer = Issuer::new(None);
+        let t_batch = Instant::now();
+        let rhs = issuer.add_elements((0..NUM_ELEMENTS).map(|i| i.to_string())).expect("Cannot add witnesses");
+        let t_batch = t_batch.elapsed();
+        println!("Time to add {} elements one by one: {:?}", NUM_ELEMENTS, t_add);
+        println!("Time to add {} elements in a batch: {:?}", NUM_ELEMENTS, t_batch);
+        assert!(t_batch < t_add);
+
+        // Check all handles verify
+        let pp = issuer.get_proof_params();
+        assert_eq!(rhs.len(), NUM_ELEMENTS);
+        rhs.iter().for_each(|rh| assert!(rh.get_witness().verify(rh.get_elem(), pp.get_public_key(), pp.get_accumulator())));
+
+        // Check duplicates within the batch or with existing pseudonyms leave the issuer unchanged
+        let acc = issuer.get_accumulator();
+        assert_eq!(issuer.add_elements(["new", "new"]).unwrap_err(), AccumulatorError::DuplicatePseudonym);
+        assert_eq!(issuer.add_elements(["new", "0"]).unwrap_err(), AccumulatorError::DuplicatePseudonym);
+        assert_eq!(issuer.witnesses.len(), NUM_ELEMENTS);
+        assert_eq!(issuer.get_accumulator(), acc);
+    }
+
+    #[test]
+    fn issuer_max_batch() {
+        // Setup issuer with a small limit
+        let mut issuer = Issuer::new(None);
+        assert_eq!(issuer.get_max_batch(), Issuer::DEFAULT_MAX_BATCH);
+        issuer.set_max_batch(4);
+        let pseudos: Vec<String> = (0..12).map(|i| i.to_string()).collect();
+        issuer.add_elements(pseudos.iter().cloned()).unwrap();
+        let acc = issuer.get_accumulator();
+
+        // Above the limit, nothing is revoked
+        let err = Some(AccumulatorError::BatchTooLarge(4));
+        assert_eq!(issuer.revoke_elements_instant(&pseudos[..5], RevocationReason::Unspecified).err(), err);
+        assert_eq!(issuer.revoke_elements(&pseudos[..5]).err(), err);
+        assert_eq!(issuer.get_accumulator(), acc);
+        assert_eq!(issuer.get_witnesses().len(), 12);
+
+        // Just below and at the limit, the revocations succeed
+        let polys = issuer.revoke_elements_instant(&pseudos[..3], RevocationReason::Unspecified).unwrap();
+        assert_eq!(polys.deletions.len(), 3);
+        let polys = issuer.revoke_elements_instant(&pseudos[3..7], RevocationReason::Unspecified).unwrap();
+        assert_eq!(polys.deletions.len(), 4);
+
+        // The deletion list is bounded across calls
+        issuer.revoke_elements(&pseudos[7..10]).unwrap();
+        assert_eq!(issuer.revoke_elements(&pseudos[10..12]).err(), err);
+        issuer.revoke_elements(&pseudos[10..11]).unwrap();
+        assert_eq!(issuer.update().unwrap().deletions.len(), 4);
+    }
+
+    #[test]
+    fn issuer_add_map() {
+        // Add several pseudonyms and look up their handles
+        let mut issuer = Issuer::new(None);
+        let rhs = issuer.add_map(["alice", "bob", "carol"]).expect("Cannot add witnesses");
+        let pp = issuer.get_proof_params();
+        assert_eq!(rhs.len(), 3);
+        for (pseudo, rh) in &rhs {
+            assert!(rh.get_witness().verify(rh.get_elem(), pp.get_public_key(), pp.get_accumulator()));
+            let stored = issuer.handle_for(pseudo).expect("Missing handle");
+            assert_eq!((stored.get_elem(), stored.get_witness()), (rh.get_elem(), rh.get_witness()));
+        }
+        assert!(issuer.handle_for("dave").is_none());
+
+        // Duplicates leave the issuer unchanged
+        assert_eq!(issuer.add_map(["dave", "alice"]).err(), Some(AccumulatorError::DuplicatePseudonym));
+        assert!(issuer.handle_for("dave").is_none());
+
+        // Revoked members have no handle
+        issuer.revoke_instant(&"bob".to_string(), RevocationReason::Unspecified).unwrap();
+        assert!(issuer.handle_for("bob").is_none());
+        assert!(issuer.handle_for("alice").is_some());
+    }
+
+    #[test]
+    fn issuer_revoke_update_forms() {
+        // Setup issuer
+        let mut issuer = Issuer::new(None);
+        let rh = issuer.add("holder").expect("Cannot add witness");
+        (0..10).for_each(|i| { issuer.add(i.to_string()).expect("Cannot add witness"); });
+
+        // Revoke elements and get both update forms
+        let revoked: Vec<String> = (0..10).map(|i| i.to_string()).collect();
+        let update = issuer.revoke_elements_instant_update(&revoked, RevocationReason::Unspecified).expect("Non existing element");
+        assert_eq!(update.deletions.len(), update.polys.deletions.len());
+        assert_eq!(update.deletions.last().unwrap().0, issuer.get_accumulator());
+        let err = issuer.revoke_elements_instant_update(&revoked, RevocationReason::Unspecified).unwrap_err();
+        assert_eq!(err, AccumulatorError::UnknownPseudonym("0".to_string()));
+
+        // Check both forms update the witness to the same valid witness
+        let (y, wit) = (rh.get_elem(), rh.get_witness());
+        let batch = wit.batch_update(y, &update.polys.deletions, &update.polys.omegas).expect("Cannot update witness");
+        let sequential = wit.update(y, &update.deletions).expect("Cannot update witness");
+        assert_eq!(batch, sequential);
+        let pp = issuer.get_proof_params();
+        assert!(batch.verify(y, pp.get_public_key(), pp.get_accumulator()));
+    }
+
+    #[test]
+    fn issuer_revoke_duplicates() {
+        // Setup issuer
+        let mut issuer = Issuer::new(None);
+        (0..5).for_each(|i| { issuer.add(i.to_string()).expect("Cannot add witness"); });
+        let acc = issuer.get_accumulator();
+
+        // Check a batch with an internal duplicate is rejected before any deletion
+        let revoked: Vec<String> = ["0", "1", "0"].iter().map(|s| s.to_string()).collect();
+        let err = issuer.revoke_elements_instant(&revoked, RevocationReason::Unspecified).unwrap_err();
+        assert_eq!(err, AccumulatorError::DuplicateRevocation("0".to_string()));
+        assert!(err.to_string().contains("pseudonym 0"));
+        assert_eq!(issuer.get_accumulator(), acc);
+        assert_eq!(issuer.get_witnesses().len(), 5);
+        assert!(issuer.get_audit_log().is_empty());
+        assert_eq!(issuer.revoke_elements_instant(&[], RevocationReason::Unspecified).unwrap_err(), AccumulatorError::EmptyInput);
+
+        // Check re-revoking a previously revoked element is rejected, also when streaming
+        issuer.revoke_elements_instant(&revoked[..2], RevocationReason::Unspecified).expect("Non existing element");
+        let acc = issuer.get_accumulator();
+        let revoked: Vec<String> = ["2", "1"].iter().map(|s| s.to_string()).collect();
+        let err = issuer.revoke_elements_instant(&revoked, RevocationReason::Unspecified).unwrap_err();
+        assert_eq!(err, AccumulatorError::UnknownPseudonym("1".to_string()));
+        let err = issuer.revoke_elements_streaming(&revoked, RevocationReason::Unspecified, 1, |_| panic!("Batch revoked")).unwrap_err();
+        assert_eq!(err, AccumulatorError::UnknownPseudonym("1".to_string()));
+        assert_eq!(issuer.get_accumulator(), acc);
+        assert_eq!(issuer.get_witnesses().len(), 3);
+        assert_eq!(issuer.get_audit_log().len(), 2);
+    }
+
+    #[test]
+    fn issuer_revoke_final_member() {
+        // Setup issuer with a single member
+        let mut issuer = Issuer::new(None);
+        issuer.add("last").expect("Cannot add witness");
+
+        // Revoke the final member and check the accumulator is not degenerate
+        issuer.revoke_instant(&"last".to_string(), RevocationReason::Unspecified).expect("Non existing element");
+        let pp = issuer.get_proof_params();
+        assert!(!pp.get_accumulator().is_degenerate());
+
+        // Check new members can still be added
+        let rh = issuer.add("new").expect("Cannot add witness");
+        assert!(rh.get_witness().verify(rh.get_elem(), pp.get_public_key(), pp.get_accumulator()));
+    }
+
+    #[test]
+    fn issuer_reinstate() {
+        // Setup issuer
+        let mut issuer = Issuer::new(None);
+        let rh = issuer.add("holder").expect("Cannot add witness");
+        let other = issuer.add("other").expect("Cannot add witness");
+
+        // Present pseudonym or element cannot be reinstated
+        assert!(issuer.reinstate("holder", Element::random()).is_none());
+        assert!(issuer.reinstate("new", rh.get_elem()).is_none());
+
+        // Revoke and reinstate the same element
+        let polys = issuer.revoke_instant(&"holder".to_string(), RevocationReason::Unspecified).expect("Non existing element");
+        let mut other_wit = other.get_witness().batch_update(other.get_elem(), &polys.deletions, &polys.omegas).expect("Cannot update witness");
+        let pp = issuer.get_proof_params();
+        assert!(!rh.get_witness().verify(rh.get_elem(), pp.get_public_key(), pp.get_accumulator()));
+        let new_rh = issuer.reinstate("holder", rh.get_elem()).expect("Cannot reinstate element");
+
+        // Check reinstated and updated witnesses are valid for the new accumulator
+        let old_acc = pp.get_accumulator();
+        let pp = issuer.get_proof_params();
+        assert!(new_rh.get_witness().verify(rh.get_elem(), pp.get_public_key(), pp.get_accumulator()));
+        assert!(!other_wit.verify(other.get_elem(), pp.get_public_key(), pp.get_accumulator()));
+        other_wit.add_update_assign(other.get_elem(), old_acc, rh.get_elem());
+        assert!(other_wit.verify(other.get_elem(), pp.get_public_key(), pp.get_accumulator()));
+    }
+
+    #[test]
+    fn issuer_sub_issuers() {
+        // Setup root issuer and two regional sub-issuers
+        let root = Issuer::new(None);
+        let (mut north, mut south) = (root.create_sub_issuer("north"), root.create_sub_issuer("south"));
+        let (north_del, south_del) = (root.delegate("north"), root.delegate("south"));
+        assert_ne!(north.get_pk(), south.get_pk());
+        assert_eq!(root.create_sub_issuer("north").get_pk(), north.get_pk());
+
+        // Issue under each sub-issuer
+        let mut north_holder = Holder::new("holder", north.add("holder").unwrap(), north.get_proof_params());
+        let mut south_holder = Holder::new("holder", south.add("holder").unwrap(), south.get_proof_params());
+        assert!(Verifier::new(north.get_proof_params()).verify(north_holder.proof_membership_cached()));
+        assert!(Verifier::new(south.get_proof_params()).verify(south_holder.proof_membership_cached()));
+
+        // Both sub-issuers chain to the root, also after their accumulators change
+        north.add("revoked").unwrap();
+        north.revoke_instant(&"revoked".to_string(), RevocationReason::Unspecified).unwrap();
+        assert!(north_del.verify(&root.get_delegation_pk(), &north.get_proof_params()));
+        assert!(south_del.verify(&root.get_delegation_pk(), &south.get_proof_params()));
+        assert!(!north_del.verify(&root.get_delegation_pk(), &south.get_proof_params()));
+        assert!(!north_del.verify(&root.get_pk(), &north.get_proof_params()));
+
+        // An unrelated issuer, or a delegation checked against another root, is rejected
+        let unrelated = Issuer::new(None);
+        assert!(!north_del.verify(&root.get_delegation_pk(), &unrelated.get_proof_params()));
+        assert!(!unrelated.delegate("north").verify(&root.get_delegation_pk(), &unrelated.create_sub_issuer("north").get_proof_params()));
+        assert!(!north_del.verify(&unrelated.get_delegation_pk(), &north.get_proof_params()));
+        let mut forged = north_del.clone();
+        forged.scope = "south".to_string();
+        assert!(!forged.verify(&root.get_delegation_pk(), &north.get_proof_params()));
+    }
+
+    #[test]
+    fn issuer_rotate_key() {
+        // Issue under key A
+        let mut issuer = Issuer::new(Some(b"key A"));
+        let params_a = issuer.get_proof_params();
+        let mut holder = Holder::new("holder", issuer.add("holder").unwrap(), params_a);
+        issuer.add("other").unwrap();
+        issuer.revoke(&"other".to_string());
+        let ver_a = Verifier::new(params_a);
+        assert!(ver_a.verify(holder.proof_membership_cached()));
+
+        // Rotate to key B, the holder applies its new witness
+        let update = issuer.rotate_key(SecretKey::new(Some(b"key B")));
+        assert_ne!(update.params.get_public_key(), params_a.get_public_key());
+        assert_eq!(update.params, issuer.get_proof_params());
+        assert_eq!(update.witnesses.len(), 1);
+        assert_eq!(issuer.update(), None);
+        holder.apply_rekey(&update).expect("Cannot apply re-key");
+
+        // The holder verifies under key B but not under key A
+        let ver_b = Verifier::new(update.params);
+        let proof = holder.proof_membership_cached();
+        assert!(ver_b.verify(proof));
+        assert!(!ver_a.verify(proof));
+        assert!(!holder.test_membership_with(&params_a));
+
+        // Holders missing from the update keep their old state
+        let mut revoked = Holder::new("other", RevocationHandle::new(params_a.get_accumulator(), &SecretKey::new(Some(b"key A"))).unwrap(), params_a);
+        assert_eq!(revoked.apply_rekey(&update), Err(AccumulatorError::NotMember));
+    }
+
+    #[test]
+    fn issuer_epoch_update() {
+        // Setup issuer
+        let mut issuer = Issuer::new(None);
+        
+        // Compute witnesses for ADD_SIZE elements
+        let mut elements = Vec::new();
+        let mut witness = Vec::new();
+        (0..ADD_SIZE).for_each(|i| {
+            let rh = issuer.add(i.to_string()).expect("Cannot add witness");
+            witness.push(rh.get_witness());
+            elements.push(rh.get_elem());
+        });
+
+        // Simulate we have ADD_SIZE/2 elements to delete
+        let num_deletions = ADD_SIZE / 2;
+        let deletions: Vec<String> = (0..num_deletions).map(|i| i.to_string()).collect();
+        issuer.revoke_elements(deletions.as_slice()).unwrap();
+
+        // Revoke removed elements and update all witnessses for valid elements
+        let t = Instant::now();
+        issuer.update_periodic();
+        println!(
+            "Time to compute periodic update of {} witness: {:?}",
+            ADD_SIZE-num_deletions,
+            t.elapsed()
+        );
+
+        let new_wits = issuer.get_witnesses();
+
+        // Check all witnesses in previous witnesss list are invalid
+        (0..ADD_SIZE).for_each(|i|{
+            assert!(!witness[i].verify(elements[i], issuer.get_pk(), issuer.get_accumulator()));
+        });
+
+        // Check non-revoked witness are updated
+        (num_deletions..ADD_SIZE).for_each(|i|{
+            let wit = new_wits.get(&i.to_string());
+            let wit = wit.expect("Non-revoked element is not present in witness list!");
+            assert!(wit.verify(elements[i], issuer.get_pk(), issuer.get_accumulator()))
+        });
+
+        // Check revoked witness are not updated
+        (0..num_deletions).for_each(|i|{
+            let wit = new_wits.get(&i.to_string());
+            assert!(wit.is_none());
+        });
+    }
+
+    #[test]
+    fn issuer_prove_transition() {
+        // Setup issuer
+        let mut issuer = Issuer::new(None);
+        (0..10).for_each(|i| { issuer.add(i.to_string()).expect("Cannot add witness"); });
+
+        // Revoke elements and prove the transition
+        let old = issuer.get_accumulator();
+        let revoked: Vec<String> = (0..5).map(|i| i.to_string()).collect();
+        let polys = issuer.revoke_elements_instant(&revoked, RevocationReason::Unspecified).expect("Non existing element");
+        let new = issuer.get_accumulator();
+        let proof = issuer.prove_transition(old, new, &polys.deletions).expect("Cannot prove transition");
+
+        // Check light client accepts the transition and rejects a forged new accumulator
+        let commitment = DeletionCommitment::new(&polys.deletions);
+        assert!(proof.verify(old, new, issuer.get_transition_pk(), &commitment));
+        assert!(!proof.verify(old, new, issuer.get_pk(), &commitment));
+        let forged = Accumulator::random(rand_core::OsRng {});
+        assert!(!proof.verify(old, forged, issuer.get_transition_pk(), &commitment));
+        assert!(issuer.prove_transition(old, forged, &polys.deletions).is_err());
+    }
+
+    #[test]
+    fn issuer_non_membership_proof() {
+        // Setup issuer
+        let mut issuer = Issuer::new(None);
+        let revoked = issuer.add("revoked").expect("Cannot add witness");
+        let current = issuer.add("current").expect("Cannot add witness");
+        (0..5).for_each(|i| { issuer.add(i.to_string()).expect("Cannot add witness"); });
+        issuer.revoke_instant(&"revoked".to_string(), RevocationReason::Unspecified).expect("Non existing element");
+
+        // Check the revoked element produces a valid non-membership proof
+        let mut wit = issuer.non_membership_witness(revoked.get_elem()).expect("Cannot create non-membership witness");
+        let verifier = Verifier::new(issuer.get_proof_params());
+        assert!(verifier.verify_non_membership(&NonMembershipProof::new(&issuer.get_proof_params(), revoked.get_elem(), &wit)));
+
+        // Check a current element has no non-membership witness
+        assert_eq!(issuer.non_membership_witness(current.get_elem()), Err(AccumulatorError::ElementIsMember));
+
+        // Check the witness is updated with later revocations
+        let mut deletions = Vec::new();
+        for i in 0..5 {
+            let elem = issuer.witnesses[&i.to_string()].get_elem();
+            issuer.revoke_instant(&i.to_string(), RevocationReason::Unspecified).expect("Non existing element");
+            deletions.push(Deletion(issuer.get_accumulator(), elem));
+        }
+        let pp = issuer.get_proof_params();
+        assert!(!Verifier::new(pp).verify_non_membership(&NonMembershipProof::new(&pp, revoked.get_elem(), &wit)));
+        wit.update_assign(revoked.get_elem(), &deletions).expect("Cannot update witness");
+        assert!(Verifier::new(pp).verify_non_membership(&NonMembershipProof::new(&pp, revoked.get_elem(), &wit)));
+    }
+
+    #[test]
+    fn issuer_revoke_committed() {
+        // Setup issuer
+        let mut issuer = Issuer::new(None);
+        let pseudos: Vec<String> = (0..10).map(|i| i.to_string()).collect();
+        let rhs = issuer.add_elements(pseudos.iter().cloned()).expect("Cannot add elements");
+
+        // The commitment opens to exactly the revoked elements
+        let (polys, commitment, opening) = issuer.revoke_elements_instant_committed(&pseudos[..4], RevocationReason::Unspecified).expect("Cannot revoke");
+        assert!(commitment.verify(&opening));
+        let mut revoked: Vec<Element> = rhs[..4].iter().map(|rh| rh.get_elem()).collect();
+        revoked.sort_unstable_by_key(|y| y.to_bytes());
+        assert_eq!(opening.get_elements(), revoked.as_slice());
+        assert!(polys.deletions.iter().all(|y| revoked.contains(y)));
+
+        // Openings of other batches do not open the commitment
+        let (_, _, other) = issuer.revoke_elements_instant_committed(&pseudos[4..], RevocationReason::Unspecified).expect("Cannot revoke");
+        assert!(!commitment.verify(&other));
+    }
+
+    #[test]
+    fn issuer_reserve() {
+        // A reserved issuer enrolls without growing its maps
+        let mut issuer = Issuer::with_capacity(None, ADD_SIZE);
+        let capacity = (issuer.witnesses.capacity(), issuer.changes.capacity());
+        assert!(capacity.0 >= ADD_SIZE && capacity.1 >= ADD_SIZE);
+        (0..ADD_SIZE / 2).for_each(|i| { issuer.add(i.to_string()).expect("Cannot add witness"); });
+        issuer.add_elements((ADD_SIZE / 2..ADD_SIZE).map(|i| i.to_string())).expect("Cannot add elements");
+        assert_eq!((issuer.witnesses.capacity(), issuer.changes.capacity()), capacity);
+
+        // Bulk additions reserve the room they need at once
+        let mut issuer = Issuer::new(None);
+        issuer.add_elements((0..ADD_SIZE).map(|i| i.to_string())).expect("Cannot add elements");
+        assert!(issuer.witnesses.capacity() >= ADD_SIZE && issuer.changes.capacity() >= ADD_SIZE);
+    }
+
+    #[test]
+    fn issuer_stats() {
+        // Setup issuer and add 100 pseudonyms
+        let mut issuer = Issuer::new(None);
+        let pseudos: Vec<String> = (0..100).map(|i| format!("holder{i}")).collect();
+        issuer.add_elements(pseudos.iter().cloned()).expect("Cannot add elements");
+
+        // Revoke 30 pseudonyms using instant, batch and list revocations
+        issuer.revoke_elements_instant(&pseudos[..20], RevocationReason::Unspecified).expect("Cannot revoke");
+        pseudos[20..25].iter().for_each(|p| { issuer.revoke_instant(p, RevocationReason::Expiry).expect("Cannot revoke"); });
+        issuer.revoke_elements(&pseudos[25..30]).unwrap();
+        let stats = issuer.stats();
+        assert_eq!((stats.added, stats.revoked), (100, 30));
+        assert_eq!(stats.current_id, issuer.get_accumulator_id());
+        assert!(pseudos[..30].iter().all(|p| issuer.is_revoked(p)));
+        assert!(!pseudos[30..].iter().any(|p| issuer.is_revoked(p)));
+        assert!(!issuer.is_revoked("unknown"));
+
+        // Revoking an unknown pseudonym does not change the counts, while re-enrolling clears the revocation
+        issuer.revoke_elements(&["unknown".to_string()]).unwrap();
+        issuer.add(pseudos[0].clone()).expect("Cannot add element");
+        assert!(!issuer.is_revoked(&pseudos[0]));
+        assert_eq!((issuer.stats().added, issuer.stats().revoked), (101, 30));
+    }
+
+    #[test]
+    fn issuer_export_state() {
+        // Setup issuer and holder
+        let mut issuer = Issuer::new(None);
+        let pp = issuer.get_proof_params();
+        let mut holder = Holder::new("holder", issuer.add("holder").expect("Cannot add witness"), pp);
+        (0..10).for_each(|i| { issuer.add(i.to_string()).expect("Cannot add witness"); });
+        let revoked: Vec<String> = (0..5).map(|i| i.to_string()).collect();
+        let mut polys = vec![issuer.revoke_elements_instant(&revoked, RevocationReason::Unspecified).expect("Non existing element")];
+        issuer.schedule_revocation(&"9".to_string(), 2, RevocationReason::Expiry).expect("Non existing element");
+
+        // Export state, storing secret and public parts separately, and restore it
+        let state = issuer.export_state();
+        let secret = bincode::serialize(&state.secret_key).expect("Serialization error");
+        let public = bincode::serialize(&state.public).expect("Serialization error");
+        let state = IssuerState {
+            secret_key: bincode::deserialize(&secret).expect("Deserialization error"),
+            public: bincode::deserialize(&public).expect("Deserialization error"),
+        };
+        let mut restored = Issuer::from_state(state).expect("Cannot restore issuer");
+        assert_eq!(restored.get_proof_params(), issuer.get_proof_params());
+        assert_eq!(restored.get_epoch(), issuer.get_epoch());
+        assert_eq!(restored.get_audit_log(), issuer.get_audit_log());
+
+        // Check restored issuer keeps revoking, including scheduled revocations, and the holder updates across the restart
+        let revoked: Vec<String> = (5..9).map(|i| i.to_string()).collect();
+        polys.push(restored.revoke_elements_instant(&revoked, RevocationReason::Unspecified).expect("Non existing element"));
+        restored.roll_epoch();
+        polys.push(restored.roll_epoch().expect("Scheduled revocation is lost"));
+        assert_eq!(restored.get_audit_log().len(), 10);
+        holder.batch_updates(&polys).expect("Cannot update witness");
+        assert!(holder.test_membership_with(&restored.get_proof_params()));
+
+        // Check a state with a different secret key is rejected
+        let mut state = restored.export_state();
+        state.secret_key = SecretKey::new(None);
+        assert_eq!(Issuer::from_state(state).unwrap_err(), AccumulatorError::PublicKeyMismatch);
+    }
+
+    #[test]
+    fn issuer_snapshot_delta() {
+        // Setup issuer with 200 members and take a base snapshot
+        let mut issuer = Issuer::new(None);
+        let pseudos: Vec<String> = (0..200).map(|i| i.to_string()).collect();
+        issuer.add_elements(pseudos.iter().cloned()).expect("Cannot add elements");
+        issuer.revoke_instant(&pseudos[0], RevocationReason::Unspecified).expect("Cannot revoke");
+        let mut base = issuer.export_state();
+        let checkpoint = issuer.checkpoint();
+
+        // Perform all kinds of operations
+        issuer.add_elements(["a", "b", "c"]).expect("Cannot add elements");
+        issuer.add(pseudos[0].clone()).expect("Cannot add witness");
+        issuer.revoke_elements_instant(&[pseudos[1].clone(), "a".to_string()], RevocationReason::Compromise).expect("Cannot revoke");
+        issuer.revoke(&pseudos[2]).expect("Cannot revoke");
+        issuer.schedule_revocation(&pseudos[3], 1, RevocationReason::Expiry).expect("Cannot schedule");
+        issuer.roll_epoch().expect("Scheduled revocation is lost");
+        issuer.revoke_deferred(&[pseudos[4].clone()], RevocationReason::Unspecified).expect("Cannot revoke");
+
+        // The delta only contains the changed members, and reconstructs the full state from the base
+        let delta = issuer.snapshot_delta(&checkpoint);
+        assert_eq!(delta.num_members(), 8);
+        let delta_bytes = bincode::serialize(&delta).expect("Serialization error").len();
+        let full_bytes = bincode::serialize(&issuer.export_state().public).expect("Serialization error").len();
+        println!("Delta snapshot: {delta_bytes} bytes, full snapshot: {full_bytes} bytes");
+        assert!(delta_bytes * 10 < full_bytes);
+        base.restore_delta(&delta).expect("Cannot restore delta");
+        let restored = Issuer::from_state(base.clone()).expect("Cannot restore issuer");
+        let check = |restored: &Issuer, issuer: &Issuer| {
+            assert_eq!(restored.get_witnesses(), issuer.get_witnesses());
+            assert_eq!(restored.get_proof_params(), issuer.get_proof_params());
+            assert_eq!(restored.get_audit_log(), issuer.get_audit_log());
+            assert_eq!(restored.get_epoch(), issuer.get_epoch());
+            assert_eq!(restored.stats(), issuer.stats());
+            assert_eq!((&restored.deletions, &restored.deferred, &restored.scheduled), (&issuer.deletions, &issuer.deferred, &issuer.scheduled));
+            assert_eq!(restored.revoked_pseudos, issuer.revoked_pseudos);
+            assert_eq!(restored.checkpoint(), issuer.checkpoint());
+        };
+        check(&restored, &issuer);
+
+        // Deltas chain, and a rewrite of all the witnesses gives a delta with all the members
+        issuer.update_periodic();
+        let next = issuer.snapshot_delta(&delta.get_checkpoint());
+        assert_eq!(next.num_members(), issuer.get_witnesses().len());
+        base.restore_delta(&next).expect("Cannot restore delta");
+        check(&Issuer::from_state(base.clone()).expect("Cannot restore issuer"), &issuer);
+
+        // Deltas must be applied to the snapshot of their checkpoint, and cannot cross a key rotation
+        let unchanged = base.public.seq;
+        assert_eq!(base.restore_delta(&delta).unwrap_err(), AccumulatorError::ConflictingUpdates);
+        let cp = issuer.checkpoint();
+        issuer.rotate_key(SecretKey::new(None));
+        assert_eq!(base.restore_delta(&issuer.snapshot_delta(&cp)).unwrap_err(), AccumulatorError::PublicKeyMismatch);
+        assert_eq!(base.public.seq, unchanged);
+    }
+
+    #[test]
+    fn issuer_revocation_handle_bytes() {
+        // Setup issuer
+        let mut issuer = Issuer::new(None);
+        let rh = issuer.add("holder").expect("Cannot add witness");
+
+        // Round trip through bincode and through the raw byte API
+        let from_bincode: RevocationHandle = bincode::deserialize(&bincode::serialize(&rh).expect("Serialization error"))
+            .expect("Deserialization error");
+        let bytes = rh.to_bytes();
+        let from_bytes = RevocationHandle::try_from_bytes(&bytes).expect("Cannot parse handle");
+        for rh_received in [from_bincode, from_bytes] {
+            assert_eq!(rh_received.get_elem(), rh.get_elem());
+            assert_eq!(rh_received.get_witness(), rh.get_witness());
+
+            // Check holder built from the received handle produces working proofs
+            let mut holder = Holder::new("holder", rh_received, issuer.get_proof_params());
+            let proof = holder.proof_membership_cached();
+            assert!(Verifier::new(issuer.get_proof_params()).verify(proof));
+        }
+
+        // Check malformed bytes are rejected
+        assert!(RevocationHandle::try_from_bytes(&bytes[1..]).is_err());
+        let mut invalid = bytes;
+        invalid[..Element::BYTES].fill(0xff);
+        assert_eq!(RevocationHandle::try_from_bytes(&invalid).unwrap_err(), AccumulatorError::BadEncoding);
+        let mut invalid = bytes;
+        invalid[Element::BYTES..].copy_from_slice(&MembershipWitness(G1Projective::IDENTITY).to_bytes());
+        assert_eq!(RevocationHandle::try_from_bytes(&invalid).unwrap_err(), AccumulatorError::BadEncoding);
+    }
+
+    #[test]
+    fn issuer_proof_params_freshness() {
+        // Setup issuer
+        let mut issuer = Issuer::new(None);
+        (0..3).for_each(|i| { issuer.add(i.to_string()).expect("Cannot add witness"); });
+
+        // Check consecutive calls without mutation return the same params
+        let pp = issuer.get_proof_params();
+        assert_eq!(issuer.get_proof_params(), pp);
+
+        // Check revocation, re-insertion and key rotation refresh the params
+        issuer.revoke_instant(&0.to_string(), RevocationReason::Unspecified).expect("Non existing element");
+        let revoked = issuer.get_proof_params();
+        assert_ne!(revoked, pp);
+        assert_eq!(revoked.get_accumulator(), issuer.get_accumulator());
+        issuer.reinstate("3", Element::random()).expect("Cannot reinstate element");
+        assert_ne!(issuer.get_proof_params(), revoked);
+        let rotated = issuer.rotate_key(SecretKey::new(None)).params;
+        assert_eq!(issuer.get_proof_params(), rotated);
+        assert_eq!(rotated.get_public_key(), issuer.get_pk());
+    }
+
+    #[test]
+    fn issuer_update_polys_merge() {
+        // Setup issuer and holder
+        let mut issuer = Issuer::new(None);
+        (0..8).for_each(|i| { issuer.add(i.to_string()).expect("Cannot add witness"); });
+        let (rh, pp) = (issuer.witnesses["0"], issuer.get_proof_params());
+        let mut revoke = |range: core::ops::Range<usize>| {
+            let pseudos: Vec<String> = range.map(|i| i.to_string()).collect();
+            issuer.revoke_elements_instant(&pseudos, RevocationReason::Unspecified).expect("Non existing element")
+        };
+        let (p1, p2, p3) = (revoke(1..3), revoke(3..5), revoke(5..7));
+
+        // Disjoint consecutive updates bring the holder to the last accumulator
+        let p12 = p1.merge(&p2).expect("Cannot merge updates");
+        let p123 = p12.merge(&p3).expect("Cannot merge updates");
+        assert_eq!(p123.deletions.len(), 6);
+        let mut holder = Holder::new("0", rh, pp);
+        holder.batch_update(&p123).expect("Cannot update witness");
+        assert!(holder.test_membership_with(&issuer.get_proof_params()));
+
+        // Overlapping consistent updates apply the common deletions once
+        let p23 = p2.merge(&p3).expect("Cannot merge updates");
+        assert_eq!(p12.merge(&p23).expect("Cannot merge updates"), p123);
+        assert_eq!(p1.merge(&p12).expect("Cannot merge updates"), p12);
+        assert_eq!(p12.merge(&p2).expect("Cannot merge updates"), p12);
+
+        // Overlapping conflicting updates are rejected
+        let mut forged = p2.clone();
+        forged.omegas[0] = Coefficient(forged.omegas[0].0 + G1Projective::GENERATOR);
+        assert_eq!(p12.merge(&forged), Err(AccumulatorError::ConflictingUpdates));
+        assert_eq!(p12.merge(&p1), Err(AccumulatorError::ConflictingUpdates));
+    }
+
+    #[test]
+    fn issuer_update_polys_aggregate() {
+        // Setup issuer and holders
+        let mut issuer = Issuer::new(None);
+        (0..16).for_each(|i| { issuer.add(i.to_string()).expect("Cannot add witness"); });
+        let (rh, pp) = (issuer.witnesses["0"], issuer.get_proof_params());
+
+        // Revoke three elements per epoch over five epochs
+        let epochs: Vec<UpdatePolynomials> = (0..5).map(|e| {
+            let pseudos: Vec<String> = (1 + 3*e..4 + 3*e).map(|i| i.to_string()).collect();
+            issuer.revoke_elements_instant(&pseudos, RevocationReason::Unspecified).expect("Non existing element")
+        }).collect();
+        let aggregate = UpdatePolynomials::aggregate(&epochs).expect("Cannot aggregate updates");
+        assert_eq!(aggregate.deletions.len(), 15);
+
+        // Applying the aggregate matches applying the epochs in sequence
+        let (mut sequential, mut aggregated) = (Holder::new("0", rh, pp), Holder::new("0", rh, pp));
+        let wit = sequential.batch_updates(&epochs).expect("Cannot update witness");
+        assert_eq!(aggregated.batch_update(&aggregate).expect("Cannot update witness"), wit);
+        assert!(aggregated.test_membership_with(&issuer.get_proof_params()));
+        assert_eq!(UpdatePolynomials::aggregate(&epochs[..1]), Ok(epochs[0].clone()));
+
+        // Empty and malformed inputs are rejected
+        assert_eq!(UpdatePolynomials::aggregate(&[]), Err(AccumulatorError::EmptyInput));
+        let mut malformed = epochs.clone();
+        malformed[2].omegas.pop();
+        assert_eq!(UpdatePolynomials::aggregate(&malformed), Err(AccumulatorError::LengthMismatch));
+    }
+
+    #[test]
+    fn issuer_handle_verify_with_secret() {
+        // Setup issuer
+        let mut issuer = Issuer::new(None);
+        let rh = issuer.add("holder").unwrap();
+
+        // A correctly issued handle passes, until the accumulator changes and the stored witnesses are recomputed
+        assert!(rh.verify_with_secret(&issuer.acc_sk, &issuer.acc));
+        issuer.add("other").unwrap();
+        issuer.revoke(&"other".to_string());
+        issuer.update_periodic();
+        assert!(!rh.verify_with_secret(&issuer.acc_sk, &issuer.acc));
+        assert!(issuer.witnesses["holder"].verify_with_secret(&issuer.acc_sk, &issuer.acc));
+
+        // Corrupted handles fail
+        let rh = issuer.witnesses["holder"];
+        let wrong_elem = RevocationHandle { elem: Element::random(), wit: rh.wit };
+        let wrong_wit = RevocationHandle { elem: rh.elem, wit: MembershipWitness(rh.wit.0.double()) };
+        assert!(!wrong_elem.verify_with_secret(&issuer.acc_sk, &issuer.acc));
+        assert!(!wrong_wit.verify_with_secret(&issuer.acc_sk, &issuer.acc));
+        assert!(!rh.verify_with_secret(&SecretKey::new(None), &issuer.acc));
+    }
+
+    #[test]
+    fn issuer_revoke_elements_by_value() {
+        // Setup issuer and holder
+        let mut issuer = Issuer::new(None);
+        let elems: Vec<Element> = (0..4).map(|i| issuer.add(i.to_string()).unwrap().get_elem()).collect();
+        let mut holder = Holder::new("holder", issuer.add("holder").unwrap(), issuer.get_proof_params());
+        let (acc, stats) = (issuer.get_accumulator(), issuer.stats());
+
+        // An unknown element among known ones fails without revoking anything
+        let mixed = [elems[0], Element::random(), elems[1]];
+        assert_eq!(issuer.revoke_elements_by_value(&mixed, RevocationReason::Unspecified), Err(AccumulatorError::UnknownElement));
+        assert_eq!(issuer.revoke_elements_by_value(&[elems[0], elems[0]], RevocationReason::Unspecified), Err(AccumulatorError::DuplicateRevocation("0".to_string())));
+        assert_eq!(issuer.get_accumulator(), acc);
+        assert_eq!(issuer.stats(), stats);
+        assert!(issuer.get_audit_log().is_empty());
+
+        // Known elements are revoked as with their pseudonyms
+        let polys = issuer.revoke_elements_by_value(&elems[..2], RevocationReason::Unspecified).expect("Cannot revoke elements");
+        assert!(issuer.is_revoked("0") && issuer.is_revoked("1") && !issuer.is_revoked("2"));
+        holder.batch_update(&polys).unwrap();
+        assert!(holder.test_membership_with(&issuer.get_proof_params()));
+
+        // Revoked elements are no longer known
+        assert_eq!(issuer.revoke_elements_by_value(&elems[..1], RevocationReason::Unspecified), Err(AccumulatorError::UnknownElement));
+    }
+
+    #[test]
+    fn issuer_export_audit_log() {
+        // Setup issuer
+        let mut issuer = Issuer::new(None);
+        (0..10).for_each(|i| {issuer.add(i.to_string()).unwrap();});
+        let (initial, pk) = (issuer.get_accumulator(), issuer.get_pk());
+
+        // Revoke elements one by one, in a batch and deferred
+        issuer.revoke_instant(&"0".to_string(), RevocationReason::Compromise).expect("Non existing element");
+        let batch: Vec<String> = (1..5).map(|i| i.to_string()).collect();
+        issuer.revoke_elements_instant(&batch, RevocationReason::Administrative).expect("Non existing element");
+        assert!(issuer.roll_epoch().is_none());
+        let batch: Vec<String> = (5..8).map(|i| i.to_string()).collect();
+        issuer.revoke_deferred(&batch, RevocationReason::Unspecified).expect("Non existing element");
+        issuer.flush_update_polynomials().expect("No pending deletions");
+
+        // Export the log and replay it with the public key only
+        let serialized = bincode::serialize(&issuer.export_audit_log()).expect("Serialization error");
+        let log: Vec<RevocationRecord> = bincode::deserialize(&serialized).expect("Deserialization error");
+        assert_eq!(log.len(), 8);
+        assert_eq!(log.iter().map(|r| r.get_epoch()).collect::<Vec<_>>(), [0, 0, 0, 0, 0, 1, 1, 1]);
+        assert_eq!(log.last().unwrap().get_accumulator_id(), issuer.get_accumulator_id());
+        let last = RevocationRecord::replay(initial, &log, pk).expect("Invalid transition");
+        assert_eq!(last.get_id(), issuer.get_accumulator_id());
+
+        // A tampered log is rejected at the first invalid transition
+        let mut tampered = log.clone();
+        tampered.swap(2, 3);
+        assert_eq!(RevocationRecord::replay(initial, &tampered, pk), Err(AccumulatorError::InvalidTransition(2)));
+        assert_eq!(RevocationRecord::replay(Accumulator::random(rand_core::OsRng {}), &log, pk), Err(AccumulatorError::InvalidTransition(0)));
+    }
+
+    #[test]
+    fn issuer_with_members() {
+        // Setup issuer with 1000 members
+        let (mut issuer, mut holders) = Issuer::with_members(1000);
+        assert_eq!(holders.len(), 1000);
+        assert_eq!(issuer.get_witnesses().len(), 1000);
+        assert!(holders.iter().all(|h| h.test_membership_with(&issuer.get_proof_params())));
+
+        // Revoke one member
+        let polys = issuer.revoke_instant(&"42".to_string(), RevocationReason::Unspecified).expect("Non existing element");
+        assert!(holders[42].batch_update(&polys).is_err());
+        holders[7].batch_update(&polys).expect("Cannot update witness");
+        assert!(holders[7].test_membership_with(&issuer.get_proof_params()));
+    }
+
+    #[test]
+    fn issuer_try_new() {
+        // Degenerate seeds are rejected
+        assert_eq!(Issuer::try_new(Some(&[0u8; 32])).err(), Some(AccumulatorError::InvalidSecretKey));
+        assert_eq!(Issuer::try_new(Some(&[])).err(), Some(AccumulatorError::InvalidSecretKey));
+        assert_eq!(SecretKey(Scalar::ZERO).validate(), Err(AccumulatorError::InvalidSecretKey));
+
+        // Normal and random seeds give usable issuers, the same seed giving the same key
+        let seed = b"1234567890";
+        let mut issuer = Issuer::try_new(Some(seed)).expect("Cannot create issuer");
+        assert_eq!(issuer.get_pk(), Issuer::new(Some(seed)).get_pk());
+        assert!(Issuer::try_new(None).is_ok());
+        let rh = issuer.add("holder").unwrap();
+        assert!(Holder::new("holder", rh, issuer.get_proof_params()).test_membership_cached());
+    }
+
+    #[test]
+    fn issuer_updates_since() {
+        // Setup issuer and holder, recording the update epoch of the issued witness
+        let mut issuer = Issuer::new(None);
+        (0..12).for_each(|i| { issuer.add(i.to_string()).expect("Cannot add witness"); });
+        let mut holder = Holder::new("0", issuer.witnesses["0"], issuer.get_proof_params());
+        let (epoch, acc) = (issuer.get_update_epoch(), issuer.get_accumulator());
+        let checkpoint = issuer.checkpoint();
+        let mut snapshot = issuer.export_state();
+
+        // Publish updates in several ways, each moving to the next epoch
+        issuer.revoke_instant(&"1".to_string(), RevocationReason::Unspecified).unwrap();
+        issuer.revoke_elements_instant(&["2".to_string(), "3".to_string()], RevocationReason::Unspecified).unwrap();
+        issuer.revoke_deferred(&["4".to_string()], RevocationReason::Unspecified).unwrap();
+        issuer.flush_update_polynomials().unwrap();
+        issuer.revoke_elements(&["5".to_string()]).unwrap();
+        issuer.update().unwrap();
+        assert_eq!(issuer.get_update_epoch(), epoch + 4);
+
+        // The accumulator of the holder is reported 4 epochs behind
+        let diff = issuer.diff_accumulator(&acc, epoch);
+        assert!(diff.changed);
+        assert_eq!((diff.epochs, diff.epochs_apart()), (Some((epoch, epoch + 4)), Some(4)));
+        assert!(!issuer.diff_accumulator(&issuer.get_accumulator(), epoch + 4).changed);
+
+        // The holder several epochs behind catches up with exactly the missing epochs
+        let missing = issuer.updates_since(epoch);
+        assert_eq!(missing.len(), 4);
+        assert_eq!(issuer.updates_since(epoch + 3), missing[3..]);
+        holder.batch_updates(&missing).expect("Cannot update witness");
+        assert!(holder.test_membership_with(&issuer.get_proof_params()));
+
+        // Current and future epochs have no updates
+        assert!(issuer.updates_since(issuer.get_update_epoch()).is_empty());
+        assert!(issuer.updates_since(issuer.get_update_epoch() + 5).is_empty());
+
+        // The history is restored from snapshot deltas
+        snapshot.restore_delta(&issuer.snapshot_delta(&checkpoint)).unwrap();
+        let restored = Issuer::from_state(snapshot).unwrap();
+        assert_eq!(restored.get_update_epoch(), issuer.get_update_epoch());
+        assert_eq!(restored.updates_since(epoch), missing);
+
+        // Re-issuing all the witnesses moves to the next epoch and drops the history
+        issuer.update_periodic();
+        assert_eq!(issuer.get_update_epoch(), epoch + 5);
+        assert!(issuer.updates_since(epoch).is_empty());
+    }
+
+    #[test]
+    fn issuer_update_polys_validate() {
+        // Setup issuer and holder
+        let mut issuer = Issuer::new(None);
+        (0..8).for_each(|i| { issuer.add(i.to_string()).expect("Cannot add witness"); });
+        let (rh, pp) = (issuer.witnesses["0"], issuer.get_proof_params());
+        let pseudos: Vec<String> = (1..4).map(|i| i.to_string()).collect();
+        let polys = issuer.revoke_elements_instant(&pseudos, RevocationReason::Unspecified).expect("Non existing element");
+
+        // Craft a point on the curve outside the prime-order subgroup
+        let mut bytes = [0u8; 48];
+        bytes[0] = 0x80;
+        let forged = (1u8..).find_map(|x| {
+            bytes[47] = x;
+            Option::<G1Affine>::from(G1Affine::from_compressed_unchecked(&bytes))
+                .filter(|p| !bool::from(p.is_torsion_free()))
+        }).unwrap();
+        assert!(Coefficient::try_from(&bytes).is_err());
+
+        // Its encoding is refused on deserialization
+        let mut serialized = bincode::serialize(&polys).unwrap();
+        let first = polys.omegas[0].to_bytes();
+        let i = serialized.windows(48).position(|w| w == first).unwrap();
+        serialized[i..i + 48].copy_from_slice(&bytes);
+        assert!(bincode::deserialize::<UpdatePolynomials>(&serialized).is_err());
+
+        // A coefficient built from the point is rejected, leaving the witness unmodified
+        let mut holder = Holder::new("0", rh, pp);
+        let mut forged_polys = polys.clone();
+        forged_polys.omegas[0] = Coefficient::from(G1Projective::from(forged));
+        assert_eq!(forged_polys.omegas[0].validate(), Err(AccumulatorError::InvalidCoefficient));
+        assert_eq!(forged_polys.validate(), Err(AccumulatorError::InvalidCoefficient));
+        assert_eq!(holder.batch_update_validated(&forged_polys), Err(AccumulatorError::InvalidCoefficient));
+        assert!(holder.test_membership_with(&pp));
+
+        // The issued update passes
+        assert_eq!(polys.validate(), Ok(()));
+        holder.batch_update_validated(&polys).expect("Cannot update witness");
+        assert!(holder.test_membership_with(&issuer.get_proof_params()));
+    }
+
+    #[test]
+    fn issuer_add_elements_witness_preserving() {
+        // Setup issuer with existing holders
+        let mut issuer = Issuer::new(None);
+        let existing = issuer.add_elements((0..5).map(|i| i.to_string())).expect("Cannot add witness");
+        let acc = issuer.get_accumulator();
+
+        // Batch add new holders, the accumulator is unchanged
+        let added = issuer.add_elements((5..10).map(|i| i.to_string())).expect("Cannot add witness");
+        assert_eq!(issuer.get_accumulator(), acc);
+
+        // Existing and new holders are valid without any update
+        for rh in existing.iter().chain(added.iter()) {
+            assert!(rh.get_witness().verify(rh.get_elem(), issuer.get_pk(), issuer.get_accumulator()));
+        }
+    }
+
+    #[test]
+    fn issuer_update_polys_canonicalize() {
+        // Setup two identical issuers revoking the same elements in different orders
+        let mut issuer1 = Issuer::new(None);
+        (0..6).for_each(|i| { issuer1.add(i.to_string()).expect("Cannot add witness"); });
+        let mut issuer2 = issuer1.clone();
+        let rh = issuer1.witnesses["0"];
+        let revoked: Vec<String> = (1..6).map(|i| i.to_string()).collect();
+        let reversed: Vec<String> = revoked.iter().rev().cloned().collect();
+        let polys1 = issuer1.revoke_elements_instant(&revoked, RevocationReason::Unspecified).expect("Non existing element");
+        let polys2 = issuer2.revoke_elements_instant(&reversed, RevocationReason::Unspecified).expect("Non existing element");
+
+        // Issued polynomials are already canonical, hence equal and serialize and commit identically
+        assert_eq!(polys1, polys2);
+        assert_eq!(bincode::serialize(&polys1).unwrap(), bincode::serialize(&polys2).unwrap());
+        assert_eq!(DeletionCommitment::new(&polys1.deletions), DeletionCommitment::new(&polys2.deletions));
+        let mut canonical = polys1.clone();
+        canonical.canonicalize();
+        assert_eq!(canonical, polys1);
+
+        // Reordering the deletions gives an equivalent update
+        let mut shuffled = polys1.clone();
+        shuffled.deletions.reverse();
+        assert_ne!(shuffled, polys1);
+        let wit = rh.get_witness().batch_update(rh.get_elem(), &shuffled.deletions, &shuffled.omegas).expect("Cannot update witness");
+        assert!(wit.verify(rh.get_elem(), issuer1.get_pk(), issuer1.get_accumulator()));
+
+        // Canonical update brings the witness to the new accumulator
+        let wit = rh.get_witness().batch_update(rh.get_elem(), &polys1.deletions, &polys1.omegas).expect("Cannot update witness");
+        assert!(wit.verify(rh.get_elem(), issuer1.get_pk(), issuer1.get_accumulator()));
+    }
+
+    #[test]
+    fn issuer_revoke_deferred() {
+        // Setup two identical issuers with the same holders
+        let mut deferred = Issuer::new(None);
+        (0..20).for_each(|i| { deferred.add(i.to_string()).expect("Cannot add witness"); });
+        let mut batched = deferred.clone();
+        let rh = deferred.witnesses["0"];
+        let (mut wit_deferred, mut wit_batched) = (rh.get_witness(), rh.get_witness());
+
+        // Revoke in small batches, deferring the update polynomials
+        let batches: Vec<Vec<String>> = (0..3).map(|b| (1 + 3 * b..4 + 3 * b).map(|i| i.to_string()).collect()).collect();
+        for batch in &batches {
+            deferred.revoke_deferred(batch, RevocationReason::Unspecified).expect("Non existing element");
+            let polys = batched.revoke_elements_instant(batch, RevocationReason::Unspecified).expect("Non existing element");
+            wit_batched.batch_update_assign(rh.get_elem(), &polys.deletions, &polys.omegas).expect("Cannot update witness");
+        }
+        assert_eq!(deferred.get_accumulator(), batched.get_accumulator());
+        assert!(deferred.revoke_deferred(&batches[0], RevocationReason::Unspecified).is_err());
+
+        // A single flushed update reaches the same witness
+        let polys = deferred.flush_update_polynomials().expect("No pending deletions");
+        assert_eq!(polys.deletions.len(), 9);
+        wit_deferred.batch_update_assign(rh.get_elem(), &polys.deletions, &polys.omegas).expect("Cannot update witness");
+        assert_eq!(wit_deferred, wit_batched);
+        assert!(wit_deferred.verify(rh.get_elem(), deferred.get_pk(), deferred.get_accumulator()));
+        assert!(deferred.flush_update_polynomials().is_none());
+        assert_eq!(deferred.get_audit_log().len(), 9);
+    }
+
+    #[test]
+    fn issuer_sharded() {
+        // Setup 4 shards and add 40 holders across them
+        let mut issuer = ShardedIssuer::new(4, Some(b"sharded")).expect("Cannot create shards");
+        assert_eq!(ShardedIssuer::new(0, None).unwrap_err(), AccumulatorError::EmptyInput);
+        let mut holders: Vec<(usize, Holder)> = (0..40)
+            .map(|i| {
+                let pseudo = format!("holder{i}");
+                let (shard, rh) = issuer.add(pseudo.clone()).expect("Cannot add witness");
+                assert_eq!(shard, issuer.shard_of(&pseudo));
+                (shard, Holder::new(pseudo, rh, issuer.get_proof_params(shard).unwrap()))
+            })
+            .collect();
+        assert_eq!(issuer.add("holder0").err(), Some(AccumulatorError::DuplicatePseudonym));
+        let mut sizes = vec![0; issuer.num_shards()];
+        holders.iter().for_each(|(shard, _)| sizes[*shard] += 1);
+        assert!(sizes.iter().all(|size| *size > 0));
+        assert_eq!(sizes.iter().sum::<usize>(), 40);
+
+        // Shards have distinct keys and derive the same ones from the same seed
+        let params = issuer.get_all_proof_params();
+        assert_ne!(params[0].get_public_key(), params[1].get_public_key());
+        assert_eq!(ShardedIssuer::new(4, Some(b"sharded")).unwrap().get_proof_params(3).unwrap().get_public_key(), params[3].get_public_key());
+        assert!(issuer.get_proof_params(4).is_none());
+
+        // Revoke one holder: only its shard changes
+        let (revoked_shard, revoked) = holders.remove(0);
+        let (shard, polys) = issuer.revoke_instant(&revoked.get_pseudo(), RevocationReason::Unspecified).expect("Cannot revoke");
+        assert_eq!(shard, revoked_shard);
+        assert!(issuer.update().iter().all(Option::is_none));
+        let new_params = issuer.get_all_proof_params();
+        for (shard, holder) in holders.iter_mut() {
+            let pp = new_params[*shard];
+            if *shard == revoked_shard {
+                assert!(!holder.test_membership_with(&pp));
+                holder.batch_update(&polys).expect("Cannot update witness");
+                holder.update_public_params(pp);
+            } else {
+                assert_eq!(pp, params[*shard]);
+            }
+            assert!(holder.test_membership_with(&pp));
+        }
+
+        // A federated verifier accepts the holders of all shards, but not the revoked one
+        let mut ver = FederatedVerifier::new();
+        new_params.iter().for_each(|pp| ver.update_public_params(*pp));
+        for (_, holder) in holders.iter_mut().take(5) {
+            let id = holder.get_accumulator_id();
+            assert!(ver.verify(&id, holder.proof_membership_cached()));
+        }
+        let mut revoked = revoked;
+        assert!(!ver.verify(&new_params[revoked_shard].get_accumulator().get_id(), revoked.proof_membership_with(&new_params[revoked_shard])));
+    }
+
+    #[test]
+    fn issuer_sync_concurrent_add() {
+        const THREADS: usize = 4;
+
+        // Setup shared issuer
+        let issuer = SyncIssuer::new(None);
+
+        // Threads concurrently add the same pseudonym, exactly one of them succeeds
+        let handles: Vec<_> = std::thread::scope(|scope| {
+            let threads: Vec<_> = (0..THREADS).map(|_| scope.spawn(|| issuer.add("holder"))).collect();
+            threads.into_iter().map(|t| t.join().expect("Thread panicked")).collect()
+        });
+        let added: Vec<_> = handles.into_iter().flatten().collect();
+        assert_eq!(added.len(), 1);
+
+        // The pseudonym is associated to the element of the successful add
+        let witnesses = issuer.get_witnesses();
+        assert_eq!(witnesses.len(), 1);
+        assert_eq!(witnesses["holder"], added[0].get_witness());
+        assert_eq!(issuer.read(|iss| iss.witnesses["holder"].get_elem()), added[0].get_elem());
+    }
+
+    #[test]
+    fn issuer_sync_readers_writers() {
+        const TOTAL: usize = 20;
+        const READERS: usize = 3;
+
+        // Setup shared issuer
+        let issuer = SyncIssuer::new(None);
+        issuer.add_elements((0..TOTAL).map(|i| i.to_string())).expect("Cannot add witness");
+        let initial = issuer.get_accumulator();
+
+        std::thread::scope(|scope| {
+            // Writer revokes one element at a time, recording each accumulator value
+            let writer = scope.spawn(|| {
+                let mut accs = vec![initial];
+                for i in 0..TOTAL {
+                    issuer.revoke_instant(&i.to_string(), RevocationReason::Unspecified).expect("Non existing element");
+                    accs.push(issuer.get_accumulator());
+                }
+                accs
+            });
+
+            // Readers check every snapshot is consistent
+            let readers: Vec<_> = (0..READERS).map(|_| scope.spawn(|| {
+                let mut observed = Vec::new();
+                loop {
+                    let (pp, acc, revoked) = issuer.read(|iss| {
+                        assert_eq!(iss.get_witnesses().len() + iss.get_audit_log().len(), TOTAL);
+                        (iss.get_proof_params(), iss.get_accumulator(), iss.get_audit_log().len())
+                    });
+                    assert_eq!(pp.get_accumulator(), acc);
+                    observed.push(acc);
+                    if revoked == TOTAL {
+                        return observed;
+                    }
+                    std::thread::yield_now();
+                }
+            })).collect();
+
+            // Check readers observed only accumulator values produced by complete revocations
+            let accs = writer.join().expect("Writer panicked");
+            for reader in readers {
+                let observed = reader.join().expect("Reader panicked");
+                assert!(observed.iter().all(|acc| accs.contains(acc)));
+                assert_eq!(observed.last(), accs.last());
+            }
         });
     }
 }
@@ -1,6 +1,7 @@
 pub mod issuer;
 pub mod holder;
 pub mod verifier;
+pub mod prelude;
 
 
 pub use holder::*;
@@ -13,3 +14,8 @@ pub trait Updatable {
     fn update_accumulator(&mut self, acc: accumulator::Accumulator);
     fn update_public_params(&mut self, new_pp: accumulator::ProofParamsPublic);
 }
+
+/// Pushes the new public parameters `pp` to each of the heterogeneous `entities`, e.g., holders and verifiers after a revocation.
+pub fn propagate_params(entities: &mut [&mut dyn Updatable], pp: accumulator::ProofParamsPublic) {
+    entities.iter_mut().for_each(|entity| entity.update_public_params(pp));
+}
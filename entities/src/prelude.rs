@@ -0,0 +1,26 @@
+//! Re-exports of the entities and of the accumulator types they exchange, to import with `use entities::prelude::*`.
+//! 
+//! A full issue, revoke, update and prove flow only needs the prelude:
+//! ```
+//! use entities::prelude::*;
+//! 
+//! let mut issuer = Issuer::new(None);
+//! let rh = issuer.add("holder").unwrap();
+//! issuer.add("revoked").unwrap();
+//! let mut holder = Holder::new("holder", rh, issuer.get_proof_params());
+//! 
+//! let polys: UpdatePolynomials = issuer.revoke_instant(&"revoked".to_string(), RevocationReason::Unspecified).unwrap();
+//! let params: ProofParamsPublic = issuer.get_proof_params();
+//! let witness: MembershipWitness = holder.batch_update(&polys).unwrap();
+//! let element: Element = rh.get_elem();
+//! assert!(witness.verify(element, params.get_public_key(), params.get_accumulator()));
+//! 
+//! let proof: Proof = holder.proof_membership_with(&params);
+//! assert!(Verifier::new(params).verify(proof));
+//! ```
+
+pub use accumulator::prelude::*;
+pub use crate::holder::Holder;
+pub use crate::issuer::{Issuer, RevocationHandle, RevocationReason, UpdatePolynomials};
+pub use crate::verifier::Verifier;
+pub use crate::Updatable;
@@ -1,13 +1,41 @@
 use merlin::Transcript;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 use accumulator::{
-    accumulator::Accumulator, proof::{self, Proof, ProofParamsPublic}
+    accumulator::{Accumulator, Element}, hash_to_g1, pairing_check, proof::{self, DesignatedProof, NonMembershipProof, Proof, ProofParamsPrepared, ProofParamsPublic}, witness::MembershipWitness, AccumulatorError, PublicKey, SecretKey
 };
+use bls12_381_plus::{G1Affine, G1Projective, G2Affine, G2Projective, Scalar};
 use crate::Updatable;
 
+/// Limits enforced by `Verifier::verify_bytes` on untrusted proof payloads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerifierConfig {
+    /// Maximum length in bytes of a proof payload.
+    pub max_proof_bytes: usize,
+    /// Maximum number of verifications per source, until the counters are reset. No limit if `None`.
+    pub max_requests_per_source: Option<usize>,
+    /// Maximum number of sources tracked by the request counters, which bounds the memory used by the counters.
+    /// When a new source arrives with the table full, the counter of the least recently seen source is evicted.
+    pub max_tracked_sources: usize,
+}
+
+impl Default for VerifierConfig {
+    fn default() -> Self {
+        Self { max_proof_bytes: Proof::BYTES, max_requests_per_source: None, max_tracked_sources: 1 << 16 }
+    }
+}
+
 #[derive(Debug)]
 pub struct Verifier {
-    params: ProofParamsPublic
+    params: ProofParamsPublic,
+    config: VerifierConfig,
+    /// Number of requests and logical time of the last request of each source
+    requests: HashMap<String, (usize, u64)>,
+    /// Sources by logical time of their last request, to evict the least recently seen one
+    recency: BTreeMap<u64, String>,
+    clock: u64,
+    synced_at: Option<u64>,
 } 
 
 
@@ -15,19 +43,177 @@ impl Verifier {
 
     /// Creates a new `Verifier` instance, associated with the input proof parameters `params`.
     pub fn new(params: ProofParamsPublic) -> Self {
+        Self::with_config(params, VerifierConfig::default())
+    }  
+
+    /// Creates a new `Verifier` instance, associated with the input proof parameters `params`,
+    /// which enforces the limits `config` on the payloads passed to `verify_bytes`.
+    pub fn with_config(params: ProofParamsPublic, config: VerifierConfig) -> Self {
         Self {
-            params
+            params,
+            config,
+            requests: HashMap::new(),
+            recency: BTreeMap::new(),
+            clock: 0,
+            synced_at: None,
         }
-    }  
+    }
+
+    /// Creates a new `Verifier` instance for offline verification, pinned to the accumulator snapshot in `params`
+    /// synced at time `synced_at` (e.g., seconds since the UNIX epoch).
+    /// 
+    /// Use `verify_offline` to obtain the staleness of the snapshot together with the validity of a proof.
+    pub fn from_snapshot(params: ProofParamsPublic, synced_at: u64) -> Self {
+        let mut verifier = Self::new(params);
+        verifier.synced_at = Some(synced_at);
+        verifier
+    }
+
+    /// Pins the verifier to the new accumulator snapshot in `params`, synced at time `synced_at`.
+    pub fn sync_snapshot(&mut self, params: ProofParamsPublic, synced_at: u64) {
+        self.params = params;
+        self.synced_at = Some(synced_at);
+    }
+
+    /// Returns the time at which the pinned snapshot was synced, or `None` if the verifier was not created from a snapshot.
+    pub fn get_synced_at(&self) -> Option<u64> {
+        self.synced_at
+    }
+
+    /// Verifies the untrusted proof payload `bytes` received from `source`, as encoded by `Proof::to_bytes`.
+    /// 
+    /// Before evaluating any pairing, returns `AccumulatorError::RateLimited` if `source` exceeded its number of requests
+    /// (see `max_tracked_sources` for the eviction of the counters),
+    /// `AccumulatorError::ProofTooLarge` if the payload exceeds `max_proof_bytes`,
+    /// or `AccumulatorError::BadEncoding` if the proof is malformed (see `Proof::validate_structure`).
+    /// Otherwise, returns whether the proof verifies.
+    pub fn verify_bytes(&mut self, source: &str, bytes: &[u8]) -> Result<bool, AccumulatorError> {
+        if let Some(max) = self.config.max_requests_per_source {
+            self.count_request(source, max)?;
+        }
+        if bytes.len() > self.config.max_proof_bytes {
+            return Err(AccumulatorError::ProofTooLarge);
+        }
+        let proof = Proof::try_from_bytes(bytes)?;
+        proof.validate_structure()?;
+        Ok(self.verify(proof))
+    }
+
+    /// Resets the per-source request counters, e.g., at the start of each rate limiting window.
+    pub fn reset_rate_counters(&mut self) {
+        self.requests.clear();
+        self.recency.clear();
+    }
+
+    /// Counts a request from `source`, marking it as the most recently seen source, and returns `AccumulatorError::RateLimited` 
+    /// if it already made `max` requests. A new source arriving with `max_tracked_sources` sources tracked evicts the least recently seen one,
+    /// hence sources cycling through new identifiers cannot lock out other new sources, but only push out idle counters.
+    fn count_request(&mut self, source: &str, max: usize) -> Result<(), AccumulatorError> {
+        self.clock += 1;
+        match self.requests.get(source) {
+            Some((_, seen)) => { self.recency.remove(seen); },
+            None if self.requests.len() >= self.config.max_tracked_sources => {
+                if let Some((_, evicted)) = self.recency.pop_first() {
+                    self.requests.remove(&evicted);
+                }
+            },
+            None => {},
+        }
+        self.recency.insert(self.clock, source.to_string());
+        let (count, seen) = self.requests.entry(source.to_string()).or_default();
+        *seen = self.clock;
+        if *count >= max {
+            return Err(AccumulatorError::RateLimited);
+        }
+        *count += 1;
+        Ok(())
+    }
     
     /// Verifies the input membership proof `mem_proof` against the stored proof parameters.
     pub fn verify(&self, mem_proof: Proof)->bool{
-        let mut transcript = Transcript::new(proof::PROOF_LABEL);
+        self.verify_labeled(mem_proof, proof::PROOF_LABEL)
+    }
+
+    /// Verifies the input membership proof `mem_proof`, created with `Holder::proof_membership_labeled`,
+    /// against the stored proof parameters and the protocol domain `label` of this deployment.
+    pub fn verify_labeled(&self, mem_proof: Proof, label: &'static [u8]) -> bool {
+        let mut transcript = Transcript::new(label);
         self.params.add_to_transcript(&mut transcript);
 
         let final_proof = mem_proof.finalize(&self.params);
         return final_proof.verify(&mut transcript);
     }
+
+    /// Same as `verify`, but first compares the id `acc_id` of the accumulator the proof was created for (see `Holder::get_accumulator_id`) 
+    /// with the id of the stored accumulator.
+    /// 
+    /// Returns `AccumulatorError::AccumulatorIdMismatch` if the ids differ, i.e., the holder must refresh its parameters,
+    /// so that an outdated proof is distinguished from an invalid one (e.g., of a revoked holder), for which `Ok(false)` is returned.
+    pub fn verify_for_accumulator(&self, acc_id: &Scalar, mem_proof: Proof) -> Result<bool, AccumulatorError> {
+        if *acc_id != self.params.get_accumulator().get_id() {
+            return Err(AccumulatorError::AccumulatorIdMismatch);
+        }
+        Ok(self.verify(mem_proof))
+    }
+
+    /// Verifies the input membership proof `mem_proof` against the pinned snapshot, returning its validity
+    /// and the staleness of the snapshot at time `now`, i.e., `now - synced_at`, in the same unit as `synced_at`.
+    /// 
+    /// The staleness is `None` if the verifier was not created from a snapshot, and 0 if `now` precedes the sync.
+    /// It is up to the caller to apply a freshness policy, e.g., to reject proofs verified against a snapshot older than 24h.
+    pub fn verify_offline(&self, mem_proof: Proof, now: u64) -> (bool, Option<u64>) {
+        let staleness = self.synced_at.map(|synced_at| now.saturating_sub(synced_at));
+        (self.verify(mem_proof), staleness)
+    }
+
+    /// Verifies the input membership proof `mem_proof`, created with `Holder::proof_membership_with_context`,
+    /// against the stored proof parameters and the `context` supplied by this verifier (e.g., a fresh nonce).
+    pub fn verify_with_context(&self, mem_proof: Proof, context: &[u8]) -> bool {
+        let mut transcript = Transcript::new(proof::PROOF_LABEL);
+        self.params.add_to_transcript(&mut transcript);
+        transcript.append_message(proof::CONTEXT_LABEL, context);
+
+        mem_proof.finalize(&self.params).verify(&mut transcript)
+    }
+
+    /// Verifies the input membership proofs `proofs` against the stored proof parameters, returning the validity of each proof.
+    /// 
+    /// The pairing checks are folded in a single Miller loop and final exponentiation when all proofs are valid (see `proof::batch_verify_each`).
+    pub fn verify_batch(&self, proofs: &[Proof]) -> Vec<bool> {
+        proof::batch_verify_each(&self.params, proofs)
+    }
+
+    /// Verifies the input designated membership proof `mem_proof` against the stored proof parameters,
+    /// using the secret key `verifier_secret` of the designated verifier.
+    pub fn verify_designated(&self, mem_proof: DesignatedProof, verifier_secret: &SecretKey) -> bool {
+        mem_proof.verify(&self.params, verifier_secret)
+    }
+
+    /// Verifies the membership of the element `elem`, disclosed by the holder together with its witness `wit`, 
+    /// against the stored proof parameters, rejecting it if `elem` is in `blocklist` (see `Issuer::recent_revocations`).
+    /// 
+    /// The blocklist catches holders whose element was revoked but whose witness still verifies, e.g., 
+    /// because the accumulator in the stored parameters predates the deletion.
+    /// NOTE: unlike `verify`, this check is not zero-knowledge, since the verifier learns the element and can link its presentations.
+    pub fn verify_disclosed(&self, elem: Element, wit: MembershipWitness, blocklist: &[Element]) -> bool {
+        !blocklist.contains(&elem) && wit.verify(elem, self.params.get_public_key(), self.params.get_accumulator())
+    }
+
+    /// Verifies the input non-membership proof `proof` against the stored proof parameters.
+    pub fn verify_non_membership(&self, proof: &NonMembershipProof) -> bool {
+        proof.verify(&self.params)
+    }
+
+    /// Verifies the input membership proof `mem_proof` and, if valid, acknowledges it as the member `index` 
+    /// of a `ThresholdVerifier` committee, signing it with the committee signing key `signing_key`.
+    /// 
+    /// Returns `None` if the proof is malformed (see `Proof::validate_structure`) or does not verify.
+    pub fn acknowledge(&self, mem_proof: Proof, index: usize, signing_key: &SecretKey) -> Option<VerificationAck> {
+        if mem_proof.validate_structure().is_err() || !self.verify(mem_proof) {
+            return None;
+        }
+        Some(VerificationAck { index, sig: ack_message(&self.params, &mem_proof) * signing_key.0 })
+    }
 }
 
 impl Updatable for Verifier{
@@ -43,11 +229,378 @@ impl Updatable for Verifier{
     }
 }
 
+/// A verifier accepting proofs from multiple issuers.
+/// 
+/// Proof parameters are prepared once per issuer and cached by accumulator id,
+/// so that repeated verifications for the same issuer skip the preparation of the G2 points.
+#[derive(Debug, Default)]
+pub struct FederatedVerifier {
+    params: HashMap<Scalar, ProofParamsPrepared>
+}
+
+impl FederatedVerifier {
+
+    /// Creates a new `FederatedVerifier` instance with no associated issuer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the input proof parameters `new_pp` to the cache.
+    /// Any cached parameters of the same issuer (i.e., with the same public key) are invalidated.
+    pub fn update_public_params(&mut self, new_pp: ProofParamsPublic) {
+        self.params.retain(|_, pp| pp.get_params().get_public_key() != new_pp.get_public_key());
+        self.params.insert(new_pp.get_accumulator().get_id(), ProofParamsPrepared::new(new_pp));
+    }
+
+    /// Verifies the input membership proof `mem_proof` against the cached parameters with accumulator id `acc_id`.
+    /// 
+    /// Returns `false` if no parameters are associated to `acc_id`.
+    pub fn verify(&self, acc_id: &Scalar, mem_proof: Proof)->bool{
+        match self.params.get(acc_id) {
+            Some(prepared) => {
+                let mut transcript = Transcript::new(proof::PROOF_LABEL);
+                prepared.get_params().add_to_transcript(&mut transcript);
+
+                let final_proof = mem_proof.finalize_prepared(prepared);
+                final_proof.verify(&mut transcript)
+            }
+            None => false
+        }
+    }
+}
+
+/// Acknowledgement of a membership proof by the member `index` of a `ThresholdVerifier` committee,
+/// i.e., its BLS signature on the proof and on the accumulator the proof was checked against.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerificationAck {
+    pub index: usize,
+    sig: G1Projective,
+}
+
+/// Attestation that a threshold of the verifiers of a committee accepted the same membership proof,
+/// aggregating their acknowledgements in a single signature.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThresholdAttestation {
+    signers: Vec<usize>,
+    sig: G1Projective,
+}
+
+impl ThresholdAttestation {
+    /// Returns the sorted indices of the committee members whose acknowledgements are aggregated.
+    pub fn get_signers(&self) -> &[usize] {
+        &self.signers
+    }
+}
+
+/// Combines the acknowledgements of `threshold`-of-`n` independent verifiers, each checking the proof with `Verifier::acknowledge`,
+/// into a `ThresholdAttestation` that anybody can check against the committee public keys.
+/// 
+/// NOTE: the aggregated signature is checked against the sum of the signers' public keys, 
+/// hence the committee keys must be honestly generated (or registered with a proof of possession) to prevent rogue-key attacks.
+#[derive(Debug, Clone)]
+pub struct ThresholdVerifier {
+    params: ProofParamsPublic,
+    threshold: usize,
+    committee: Vec<PublicKey>,
+}
+
+impl ThresholdVerifier {
+
+    /// Creates a new `ThresholdVerifier` instance requiring `threshold` acknowledgements 
+    /// from the verifiers with public keys `committee`, indexed by their position, for proofs against `params`.
+    /// 
+    /// Returns `AccumulatorError::ThresholdNotMet` if `threshold` is zero or greater than the size of the committee.
+    pub fn new(params: ProofParamsPublic, threshold: usize, committee: Vec<PublicKey>) -> Result<Self, AccumulatorError> {
+        if threshold == 0 || threshold > committee.len() {
+            return Err(AccumulatorError::ThresholdNotMet);
+        }
+        Ok(Self { params, threshold, committee })
+    }
+
+    /// Checks the acknowledgements `acks` of the proof `mem_proof` and aggregates the first `threshold` valid ones 
+    /// from distinct committee members, ignoring invalid, unknown or repeated ones.
+    /// 
+    /// Returns `AccumulatorError::ThresholdNotMet` if fewer than `threshold` acknowledgements are valid.
+    pub fn combine(&self, mem_proof: Proof, acks: &[VerificationAck]) -> Result<ThresholdAttestation, AccumulatorError> {
+        let msg = ack_message(&self.params, &mem_proof);
+        let mut signers = BTreeSet::new();
+        let mut sig = G1Projective::IDENTITY;
+        for ack in acks {
+            if signers.len() == self.threshold {
+                break;
+            }
+            let valid = match self.committee.get(ack.index) {
+                Some(pk) => !signers.contains(&ack.index) && self.check_signature(ack.sig, msg, pk.0),
+                None => false,
+            };
+            if valid {
+                signers.insert(ack.index);
+                sig += ack.sig;
+            }
+        }
+        if signers.len() < self.threshold {
+            return Err(AccumulatorError::ThresholdNotMet);
+        }
+        Ok(ThresholdAttestation { signers: signers.into_iter().collect(), sig })
+    }
+
+    /// Verifies that the attestation `attestation` aggregates the acknowledgements of the proof `mem_proof` 
+    /// by at least `threshold` distinct committee members.
+    pub fn verify_attestation(&self, mem_proof: Proof, attestation: &ThresholdAttestation) -> bool {
+        let signers: BTreeSet<_> = attestation.signers.iter().collect();
+        if signers.len() != attestation.signers.len() || signers.len() < self.threshold {
+            return false;
+        }
+        let pk = signers.into_iter().try_fold(G2Projective::IDENTITY, |pk, &i| self.committee.get(i).map(|k| pk + k.0));
+        match pk {
+            Some(pk) => self.check_signature(attestation.sig, ack_message(&self.params, &mem_proof), pk),
+            None => false,
+        }
+    }
+
+    /// Checks that `sig` is a BLS signature on the message `msg` for the public key `pk`.
+    fn check_signature(&self, sig: G1Projective, msg: G1Projective, pk: G2Projective) -> bool {
+        // e(sig, g_2) - e(msg, pk) == 0_{G_t}
+        pairing_check(&[(G1Affine::from(sig), -G2Affine::from(self.params.g_2)), (G1Affine::from(msg), G2Affine::from(pk))])
+    }
+}
+
+/// Hashes the proof `mem_proof` and the accumulator of `params` to the message signed by `VerificationAck`.
+fn ack_message(params: &ProofParamsPublic, mem_proof: &Proof) -> G1Projective {
+    let mut data = b"Verification Ack".to_vec();
+    data.extend_from_slice(&mem_proof.to_bytes());
+    data.extend_from_slice(&params.get_accumulator().to_bytes());
+    hash_to_g1(data)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Holder, Issuer, Verifier};
+    use crate::{FederatedVerifier, Holder, Issuer, RevocationHandle, RevocationReason, ThresholdAttestation, ThresholdVerifier, Updatable, UpdatePolynomials, Verifier, VerifierConfig};
+    use accumulator::{proof, AccumulatorError, Element, Proof, ProofParamsPublic, PublicKey, SecretKey};
+    use merlin::Transcript;
     use std::time::Instant; 
 
+    // Create a proof needing no witness, A_bar = B_bar = 0 and U = s*V, with its challenge derived from `transcript`
+    fn forge_identity_proof(params: &ProofParamsPublic, mut transcript: Transcript) -> Proof {
+        let mut bytes = [0u8; Proof::BYTES];
+        bytes[0] = 0xc0;
+        bytes[48] = 0xc0;
+        bytes[96..128].copy_from_slice(&Element::random().to_bytes());
+        bytes[128..160].copy_from_slice(&Element::random().to_bytes());
+
+        // U = s*V does not depend on the challenge
+        Proof::try_from_bytes(&bytes).unwrap().finalize(params).get_bytes_for_challenge(&mut transcript);
+        bytes[160..].copy_from_slice(&Element::from_transcript(proof::PROOF_LABEL, &mut transcript).to_bytes());
+        Proof::try_from_bytes(&bytes).unwrap()
+    }
+
+    // Transcript of the verifier with proof parameters `params`, under the protocol domain `label`
+    fn transcript(params: &ProofParamsPublic, label: &'static [u8]) -> Transcript {
+        let mut transcript = Transcript::new(label);
+        params.add_to_transcript(&mut transcript);
+        transcript
+    }
+
+
+    #[test]
+    fn verifier_verify_disclosed_blocklist() {
+        // Setup issuer and holders
+        let mut issuer = Issuer::new(None);
+        let rh = issuer.add("holder").unwrap();
+        let old = issuer.add("old").unwrap();
+        let recent = issuer.add("recent").unwrap();
+        let pending = issuer.add("pending").unwrap();
+
+        // Revoke "old" in epoch 0, then "recent" and "pending" in epoch 1, leaving "pending" in the deletion list
+        let first = issuer.revoke_instant(&"old".to_string(), RevocationReason::Unspecified).unwrap();
+        issuer.roll_epoch();
+        let stale_params = issuer.get_proof_params();
+        let update = issuer.revoke_instant(&"recent".to_string(), RevocationReason::Compromise).unwrap();
+        issuer.revoke(&"pending".to_string()).unwrap();
+        let blocklist = issuer.recent_revocations(1);
+        assert_eq!(blocklist, vec![recent.get_elem(), pending.get_elem()]);
+        assert_eq!(issuer.recent_revocations(0).len(), 3);
+        assert!(issuer.recent_revocations(2).is_empty());
+
+        // The blocklist flags "pending", whose witness still verifies since the accumulator is not updated
+        let update_wit = |rh: &RevocationHandle, updates: &[&UpdatePolynomials]| {
+            let mut wit = rh.get_witness();
+            for polys in updates {
+                wit.batch_update_assign(rh.get_elem(), &polys.deletions, &polys.omegas).unwrap();
+            }
+            wit
+        };
+        let ver = Verifier::new(issuer.get_proof_params());
+        assert!(ver.verify_disclosed(pending.get_elem(), update_wit(&pending, &[&first, &update]), &[]));
+        assert!(!ver.verify_disclosed(pending.get_elem(), update_wit(&pending, &[&first, &update]), &blocklist));
+
+        // A verifier with a stale accumulator still flags "recent", while up-to-date members are accepted
+        let stale = Verifier::new(stale_params);
+        assert!(stale.verify_disclosed(recent.get_elem(), update_wit(&recent, &[&first]), &[]));
+        assert!(!stale.verify_disclosed(recent.get_elem(), update_wit(&recent, &[&first]), &blocklist));
+        assert!(ver.verify_disclosed(rh.get_elem(), update_wit(&rh, &[&first, &update]), &blocklist));
+        assert!(!ver.verify_disclosed(old.get_elem(), old.get_witness(), &blocklist));
+    }
+
+    #[test]
+    fn verifier_accumulator_mismatch() {
+        // Setup issuer and holders
+        let mut issuer = Issuer::new(None);
+        let mut holder = Holder::new("holder", issuer.add("holder").unwrap(), issuer.get_proof_params());
+        let mut revoked = Holder::new("revoked", issuer.add("revoked").unwrap(), issuer.get_proof_params());
+        issuer.add("other").unwrap();
+
+        // Revoke an element and update the verifier only
+        let polys = issuer.revoke_instant(&"other".to_string(), RevocationReason::Unspecified).unwrap();
+        let ver = Verifier::new(issuer.get_proof_params());
+
+        // A proof against the old accumulator is reported as outdated, not just invalid
+        let (old_id, proof) = (holder.get_accumulator_id(), holder.proof_membership_cached());
+        assert!(!ver.verify(proof));
+        assert_eq!(ver.verify_for_accumulator(&old_id, proof), Err(AccumulatorError::AccumulatorIdMismatch));
+        assert_eq!(holder.check_membership(&issuer.get_proof_params()), Err(AccumulatorError::AccumulatorIdMismatch));
+
+        // Once updated, the proof verifies
+        holder.batch_update(&polys).unwrap();
+        holder.update_public_params(issuer.get_proof_params());
+        assert_eq!(holder.check_membership(&issuer.get_proof_params()), Ok(()));
+        assert_eq!(ver.verify_for_accumulator(&holder.get_accumulator_id(), holder.proof_membership_cached()), Ok(true));
+
+        // A revoked holder with current parameters is invalid, not outdated
+        let polys = issuer.revoke_instant(&"revoked".to_string(), RevocationReason::Unspecified).unwrap();
+        let ver = Verifier::new(issuer.get_proof_params());
+        assert!(revoked.batch_update(&polys).is_err());
+        revoked.update_public_params(issuer.get_proof_params());
+        assert_eq!(revoked.check_membership(&issuer.get_proof_params()), Err(AccumulatorError::NotMember));
+        assert_eq!(ver.verify_for_accumulator(&revoked.get_accumulator_id(), revoked.proof_membership_cached()), Ok(false));
+    }
+
+    #[test]
+    fn verifier_propagate_params() {
+        // Setup issuer, holder and verifier
+        let mut issuer = Issuer::new(None);
+        let mut holder = Holder::new("holder", issuer.add("holder").unwrap(), issuer.get_proof_params());
+        issuer.add("revoked").unwrap();
+        let mut ver = Verifier::new(issuer.get_proof_params());
+
+        // Revoke an element and push the new params to all entities through the trait object
+        let polys = issuer.revoke_instant(&"revoked".to_string(), RevocationReason::Unspecified).unwrap();
+        let pp = issuer.get_proof_params();
+        holder.batch_update(&polys).unwrap();
+        crate::propagate_params(&mut [&mut holder, &mut ver, &mut issuer], pp);
+
+        // The holder's cached params and the verifier are current, the issuer is unchanged
+        assert!(holder.is_current(&pp));
+        assert!(ver.verify(holder.proof_membership_cached()));
+        assert_eq!(issuer.get_proof_params(), pp);
+        issuer.update_accumulator(accumulator::Accumulator::random(rand_core::OsRng {}));
+        assert_eq!(issuer.get_proof_params(), pp);
+    }
+
+    #[test]
+    fn verifier_threshold_attestation() {
+        // Setup issuer, holder and a committee of 3 verifiers
+        let mut issuer = Issuer::new(None);
+        let params = issuer.get_proof_params();
+        let mut holder = Holder::new("holder", issuer.add("holder").unwrap(), params);
+        let keys: Vec<_> = (0..3).map(|_| SecretKey::new(None)).collect();
+        let committee = ThresholdVerifier::new(params, 2, keys.iter().map(PublicKey::from).collect()).expect("Cannot create committee");
+        let verifiers: Vec<_> = (0..3).map(|_| Verifier::new(params)).collect();
+        let proof = holder.proof_membership_cached();
+
+        // 2-of-3 acknowledgements produce a valid attestation
+        let acks: Vec<_> = [0, 2].into_iter()
+            .map(|i| verifiers[i].acknowledge(proof, i, &keys[i]).expect("Proof not acknowledged"))
+            .collect();
+        let attestation = committee.combine(proof, &acks).expect("Cannot combine acknowledgements");
+        assert_eq!(attestation.get_signers(), &[0, 2]);
+        assert!(committee.verify_attestation(proof, &attestation));
+        assert!(!committee.verify_attestation(holder.proof_membership_cached(), &attestation));
+
+        // 1-of-3 acknowledgements, even if repeated or with a wrong index, are not enough
+        assert_eq!(committee.combine(proof, &acks[..1]), Err(AccumulatorError::ThresholdNotMet));
+        assert_eq!(committee.combine(proof, &[acks[0], acks[0]]), Err(AccumulatorError::ThresholdNotMet));
+        let wrong_index = verifiers[1].acknowledge(proof, 1, &keys[0]).expect("Proof not acknowledged");
+        assert_eq!(committee.combine(proof, &[acks[0], wrong_index]), Err(AccumulatorError::ThresholdNotMet));
+        let forged = ThresholdAttestation { signers: vec![0, 0], ..attestation.clone() };
+        assert!(!committee.verify_attestation(proof, &forged));
+
+        // Invalid proofs are not acknowledged
+        let other_params = Issuer::new(None).get_proof_params();
+        assert!(Verifier::new(other_params).acknowledge(proof, 0, &keys[0]).is_none());
+        let forged = forge_identity_proof(&params, transcript(&params, proof::PROOF_LABEL));
+        assert!(verifiers.iter().enumerate().all(|(i, ver)| ver.acknowledge(forged, i, &keys[i]).is_none()));
+        assert!(ThresholdVerifier::new(params, 4, vec![PublicKey::from(&keys[0]); 3]).is_err());
+    }
+
+    #[test]
+    fn verifier_offline_snapshot() {
+        // Setup issuer and holder
+        let mut issuer = Issuer::new(None);
+        let params = issuer.get_proof_params();
+        let mut holder = Holder::new("holder", issuer.add("holder").unwrap(), params);
+        issuer.add("other").unwrap();
+
+        // Verify against the pinned snapshot and report its age
+        let mut ver = Verifier::from_snapshot(params, 1_000);
+        assert_eq!(ver.get_synced_at(), Some(1_000));
+        let proof = holder.proof_membership_cached();
+        assert_eq!(ver.verify_offline(proof, 1_000 + 3_600), (true, Some(3_600)));
+        assert_eq!(ver.verify_offline(proof, 0), (true, Some(0)));
+
+        // Proofs against a newer accumulator fail on the stale snapshot until it is re-synced
+        let polys = issuer.revoke_instant(&"other".to_string(), RevocationReason::Unspecified).expect("Cannot revoke");
+        let params = issuer.get_proof_params();
+        holder.batch_update(&polys).expect("Cannot update witness");
+        let proof = holder.proof_membership_with(&params);
+        assert_eq!(ver.verify_offline(proof, 2_000), (false, Some(1_000)));
+        ver.sync_snapshot(params, 2_000);
+        assert_eq!(ver.verify_offline(proof, 2_500), (true, Some(500)));
+
+        // Verifiers not created from a snapshot report no staleness
+        assert_eq!(Verifier::new(params).verify_offline(proof, 2_500), (true, None));
+    }
+
+    #[test]
+    fn verifier_proof_with_context() {
+        // Setup issuer and holder
+        let mut issuer = Issuer::new(None);
+        let params = issuer.get_proof_params();
+        let mut holder = Holder::new("holder", issuer.add("holder").unwrap(), params);
+        let ver = Verifier::new(params);
+
+        // Proof bound to the verifier nonce only verifies under the same context
+        let proof = holder.proof_membership_with_context(None, b"nonce 1");
+        assert!(ver.verify_with_context(proof, b"nonce 1"));
+        assert!(!ver.verify_with_context(proof, b"nonce 2"));
+        assert!(!ver.verify(proof));
+
+        // Proofs without context cannot be accepted as bound to a context
+        let proof = holder.proof_membership_cached();
+        assert!(ver.verify(proof));
+        assert!(!ver.verify_with_context(proof, b""));
+    }
+
+    #[test]
+    fn verifier_proof_labeled() {
+        // Setup issuer and holder
+        let mut issuer = Issuer::new(None);
+        let params = issuer.get_proof_params();
+        let mut holder = Holder::new("holder", issuer.add("holder").unwrap(), params);
+        let ver = Verifier::new(params);
+
+        // Proof made under a label only verifies under the same label
+        let proof = holder.proof_membership_labeled(&params, b"Deployment A");
+        assert!(ver.verify_labeled(proof, b"Deployment A"));
+        assert!(!ver.verify_labeled(proof, b"Deployment B"));
+        assert!(!ver.verify(proof));
+
+        // The default label is unchanged
+        let proof = holder.proof_membership_labeled(&params, proof::PROOF_LABEL);
+        assert!(ver.verify(proof));
+        assert!(ver.verify_labeled(holder.proof_membership_cached(), proof::PROOF_LABEL));
+        assert!(!ver.verify_labeled(holder.proof_membership_cached(), b"Deployment A"));
+    }
 
     #[test]
     fn verifier_proof_succeed() {
@@ -56,13 +609,13 @@ mod tests {
         // Init Holder
         let rh = issuer.add("holder1").unwrap();
         let params = issuer.get_proof_params();
-        let holder = Holder::new("holder1", rh, params);
+        let mut holder = Holder::new("holder1", rh, params);
         
         // Init Verifier
         let ver = Verifier::new(params);
         
         // Compute proof
-        let proof = holder.proof_membership(None);
+        let proof = holder.proof_membership_cached();
         
         // Verify proof
         let t = Instant::now();
@@ -74,6 +627,121 @@ mod tests {
         )
     }
 
+    #[test]
+    fn verifier_batch_proofs() {
+        const HOLDERS: usize = 8;
+        let mut issuer = Issuer::new(None);
+        let params = issuer.get_proof_params();
+        let mut holders: Vec<Holder> = (0..HOLDERS)
+            .map(|i| Holder::new(i.to_string(), issuer.add(i.to_string()).unwrap(), params))
+            .collect();
+
+        // Revoke two holders and update the others
+        let revoked = [2.to_string(), 5.to_string()];
+        let polys = issuer.revoke_elements_instant(&revoked, RevocationReason::Unspecified).unwrap();
+        let params = issuer.get_proof_params();
+        holders.iter_mut()
+            .filter(|h| !revoked.contains(&h.get_pseudo()))
+            .for_each(|h| { h.batch_update(&polys).unwrap(); });
+
+        // Mix valid proofs, proofs with stale witnesses, a proof with a tampered challenge and a proof needing no witness
+        let mut proofs: Vec<Proof> = holders.iter_mut().map(|h| h.proof_membership_with(&params)).collect();
+        let mut bytes = proofs[0].to_bytes();
+        bytes[Proof::BYTES - 1] ^= 1;
+        proofs.push(Proof::try_from_bytes(&bytes).unwrap());
+        proofs.push(forge_identity_proof(&params, transcript(&params, proof::PROOF_LABEL)));
+
+        // Check batch result matches per-proof verification
+        let ver = Verifier::new(params);
+        let t = Instant::now();
+        let batch = ver.verify_batch(&proofs);
+        println!("Time to batch verify {} proofs: {:?}", proofs.len(), t.elapsed());
+        let expected: Vec<bool> = proofs.iter().map(|proof| ver.verify(*proof)).collect();
+        assert_eq!(batch, expected);
+        assert_eq!(batch.iter().filter(|v| !**v).count(), 4);
+        assert!(ver.verify_batch(&proofs[..2]).iter().all(|v| *v));
+        assert!(ver.verify_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn verifier_guard_untrusted_payloads() {
+        let mut issuer = Issuer::new(None);
+        let params = issuer.get_proof_params();
+        let mut holder = Holder::new("holder1", issuer.add("holder1").unwrap(), params);
+        let config = VerifierConfig { max_requests_per_source: Some(3), max_tracked_sources: 2, ..Default::default() };
+        let mut ver = Verifier::with_config(params, config);
+
+        // Valid proof passes
+        let bytes = holder.proof_membership_cached().to_bytes();
+        assert_eq!(ver.verify_bytes("a", &bytes), Ok(true));
+
+        // Oversized payload is rejected before parsing
+        let oversized = vec![0u8; 1 << 20];
+        let t = Instant::now();
+        assert_eq!(ver.verify_bytes("a", &oversized), Err(AccumulatorError::ProofTooLarge));
+        println!("Time to reject oversized proof: {:?}", t.elapsed());
+
+        // Proof with identity points is rejected before any pairing
+        let mut malformed = [0u8; Proof::BYTES];
+        malformed.copy_from_slice(&bytes);
+        malformed[..96].fill(0);
+        malformed[0] = 0xc0;
+        malformed[48] = 0xc0;
+        assert_eq!(ver.verify_bytes("a", &malformed), Err(AccumulatorError::BadEncoding));
+
+        // Source exceeding the limit is rejected until counters are reset
+        assert_eq!(ver.verify_bytes("a", &bytes), Err(AccumulatorError::RateLimited));
+        assert_eq!(ver.verify_bytes("b", &bytes), Ok(true));
+
+        ver.reset_rate_counters();
+        assert_eq!(ver.verify_bytes("a", &bytes), Ok(true));
+    }
+
+    #[test]
+    fn verifier_rate_counters_eviction() {
+        let mut issuer = Issuer::new(None);
+        let params = issuer.get_proof_params();
+        let mut holder = Holder::new("holder1", issuer.add("holder1").unwrap(), params);
+        let config = VerifierConfig { max_requests_per_source: Some(2), max_tracked_sources: 2, ..Default::default() };
+        let mut ver = Verifier::with_config(params, config);
+        let bytes = holder.proof_membership_cached().to_bytes();
+
+        // A client cycling through many source ids does not lock out new legitimate sources
+        for i in 0..100 {
+            assert_eq!(ver.verify_bytes(&format!("spam{i}"), &bytes), Ok(true));
+        }
+        assert_eq!(ver.verify_bytes("a", &bytes), Ok(true));
+        assert_eq!(ver.verify_bytes("b", &bytes), Ok(true));
+        assert!(ver.requests.len() <= 2 && ver.recency.len() == ver.requests.len());
+
+        // The least recently seen source is evicted, while the active one keeps its counter
+        assert_eq!(ver.verify_bytes("a", &bytes), Ok(true));
+        assert_eq!(ver.verify_bytes("c", &bytes), Ok(true));
+        assert!(!ver.requests.contains_key("b"));
+        assert_eq!(ver.verify_bytes("a", &bytes), Err(AccumulatorError::RateLimited));
+    }
+
+    #[test]
+    fn verifier_rejects_identity_forgery() {
+        let mut issuer = Issuer::new(None);
+        issuer.add("holder1").unwrap();
+        let params = issuer.get_proof_params();
+        let mut ver = Verifier::new(params);
+        let forged = forge_identity_proof(&params, transcript(&params, proof::PROOF_LABEL));
+        let labeled = forge_identity_proof(&params, transcript(&params, b"Deployment A"));
+        let mut with_context = transcript(&params, proof::PROOF_LABEL);
+        with_context.append_message(proof::CONTEXT_LABEL, b"nonce");
+        let with_context = forge_identity_proof(&params, with_context);
+
+        // The forged proofs are rejected by every entry point
+        assert!(!ver.verify(forged));
+        assert!(!ver.verify_labeled(labeled, b"Deployment A"));
+        assert!(!ver.verify_with_context(with_context, b"nonce"));
+        assert_eq!(ver.verify_for_accumulator(&params.get_accumulator().get_id(), forged), Ok(false));
+        assert!(!ver.verify_offline(forged, 0).0);
+        assert_eq!(ver.verify_bytes("a", &forged.to_bytes()), Err(AccumulatorError::BadEncoding));
+    }
+
     #[test]
     fn verifier_proof_fails() {
         let mut issuer = Issuer::new(None);
@@ -81,21 +749,21 @@ mod tests {
         // Init Holder
         let rh = issuer.add("holder1").unwrap();
         let params = issuer.get_proof_params();
-        let holder = Holder::new("holder1", rh, params);
+        let mut holder = Holder::new("holder1", rh, params);
         
         // Init Verifier
         let mut ver = Verifier::new(params);
 
 
         // Delete holder
-        issuer.revoke_instant(&String::from("holder1"));
+        issuer.revoke_instant(&String::from("holder1"), RevocationReason::Unspecified);
 
         // Update verifier
         let new_acc = issuer.get_accumulator();
-        ver.update_acc(new_acc);
+        ver.update_accumulator(new_acc);
 
         // Compute proof
-        let proof = holder.proof_membership(None);
+        let proof = holder.proof_membership_cached();
         
         // Verify proof is not valid
         let t = Instant::now();
@@ -106,4 +774,88 @@ mod tests {
             t
         )
     }
+
+    #[test]
+    fn verifier_issue_revoke_prove() {
+        let mut issuer = Issuer::new(None);
+
+        // Init Holders
+        let params = issuer.get_proof_params();
+        let mut valid_hol = Holder::new("valid", issuer.add("valid").unwrap(), params);
+        let mut revoked_hol = Holder::new("revoked", issuer.add("revoked").unwrap(), params);
+
+        // Revoke holder and update Verifier
+        let mut ver = Verifier::new(params);
+        let polys = issuer.revoke_instant(&"revoked".to_string(), RevocationReason::Unspecified).unwrap();
+        let params = issuer.get_proof_params();
+        ver.update_public_params(params);
+
+        // Check updated holder's proof verifies and revoked holder's proof fails
+        valid_hol.batch_update(&polys).unwrap();
+        assert!(ver.verify(valid_hol.proof_membership_with(&params)));
+        assert!(!ver.verify(revoked_hol.proof_membership_cached()));
+    }
+
+    #[test]
+    fn verifier_designated() {
+        let mut issuer = Issuer::new(None);
+
+        // Init Holder
+        let rh = issuer.add("holder1").unwrap();
+        let params = issuer.get_proof_params();
+        let mut holder = Holder::new("holder1", rh, params);
+
+        // Init designated and other Verifiers
+        let ver = Verifier::new(params);
+        let (designated_sk, other_sk) = (SecretKey::new(None), SecretKey::new(None));
+
+        // Compute proof for the designated verifier
+        let proof = holder.proof_membership_designated(None, &PublicKey::from(&designated_sk));
+
+        // Check only the designated verifier accepts the proof
+        let t = Instant::now();
+        assert!(ver.verify_designated(proof, &designated_sk));
+        println!("Designated proof - verification time: {:?}", t.elapsed());
+        assert!(!ver.verify_designated(proof, &other_sk));
+    }
+
+    #[test]
+    fn verifier_federated() {
+        let (mut issuer1, mut issuer2) = (Issuer::new(None), Issuer::new(None));
+
+        // Init Holders
+        let rh = issuer1.add("holder1").unwrap();
+        let mut holder1 = Holder::new("holder1", rh, issuer1.get_proof_params());
+        let rh = issuer2.add("holder2").unwrap();
+        let mut holder2 = Holder::new("holder2", rh, issuer2.get_proof_params());
+        issuer1.add("revoked").unwrap();
+
+        // Init Verifier
+        let mut ver = FederatedVerifier::new();
+        ver.update_public_params(issuer1.get_proof_params());
+        ver.update_public_params(issuer2.get_proof_params());
+
+        // Verify interleaved proofs
+        let (id1, id2) = (holder1.get_accumulator_id(), holder2.get_accumulator_id());
+        for _ in 0..2 {
+            assert!(ver.verify(&id1, holder1.proof_membership_cached()));
+            assert!(ver.verify(&id2, holder2.proof_membership_cached()));
+        }
+        assert!(!ver.verify(&id2, holder1.proof_membership_cached()));
+
+        // A proof needing no witness is rejected
+        let params1 = issuer1.get_proof_params();
+        assert!(!ver.verify(&id1, forge_identity_proof(&params1, transcript(&params1, proof::PROOF_LABEL))));
+
+        // Revoke element and update parameters of first issuer
+        let polys = issuer1.revoke_instant(&"revoked".to_string(), RevocationReason::Unspecified).unwrap();
+        ver.update_public_params(issuer1.get_proof_params());
+
+        // Check old parameters are invalidated and updated holder verifies with new parameters
+        assert!(!ver.verify(&id1, holder1.proof_membership_cached()));
+        holder1.batch_update(&polys).unwrap();
+        holder1.update_public_params(issuer1.get_proof_params());
+        assert!(ver.verify(&holder1.get_accumulator_id(), holder1.proof_membership_cached()));
+        assert!(ver.verify(&id2, holder2.proof_membership_cached()));
+    }
 }
\ No newline at end of file
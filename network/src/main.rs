@@ -12,8 +12,8 @@ async fn main() {
     let non_revoked_index = NUM_CLIENTS-1;
 
     // Initialise Servers
-    let reg = thread::spawn(|| base_registry::run());
-    let serv = thread::spawn(|| server::run());
+    let reg = thread::spawn(base_registry::run);
+    let serv = thread::spawn(server::run);
     Controller::send_public_params().await.unwrap();
 
     // Get Public Parameters
@@ -31,8 +31,8 @@ async fn main() {
     let mut ver = Verifier::new(pp);
 
     // Verify two proofs
-    assert!(ver.verify(holders[0].proof_membership(None)));
-    assert!(ver.verify(holders[non_revoked_index].proof_membership(None)));
+    assert!(ver.verify(holders[0].proof_membership_cached()));
+    assert!(ver.verify(holders[non_revoked_index].proof_membership_cached()));
 
     // Instantly revoke first client
     assert!(Controller::revoke_now(&holders[0].get_pseudo()).await.unwrap().status().is_success());
@@ -42,17 +42,17 @@ async fn main() {
 
 
     // Check revocation status of first client
-    assert!(!holders[0].test_membership(Some(pp)));
+    assert!(!holders[0].test_membership_with(&pp));
 
 
     // Try updating first client and test revocation
     assert!(Client::poly_upd(&mut holders[0]).await.is_err());
-    assert!(!holders[0].test_membership(Some(pp)));
+    assert!(!holders[0].test_membership_with(&pp));
 
 
     // Update non-revoked client and test memebership
     assert!(Client::poly_upd(&mut holders[non_revoked_index]).await.unwrap());
-    assert!(holders[non_revoked_index].test_membership(None));
+    assert!(holders[non_revoked_index].test_membership_cached());
 
 
     // Batch revoke half of the clients
@@ -62,7 +62,7 @@ async fn main() {
 
     // Batch update non-revoked client and test memebership
     assert!(Client::poly_upd(&mut holders[non_revoked_index]).await.unwrap());
-    assert!(holders[non_revoked_index].test_membership(None));
+    assert!(holders[non_revoked_index].test_membership_cached());
 
     
     // Simulate we have all other clients to revoke except for last client
@@ -80,7 +80,7 @@ async fn main() {
     let pp = Client::ask_pp().await.unwrap();
     for hol in holders[0..non_revoked_index].iter_mut() {
         assert!(!Client::wit_upd(hol).await.expect("Cannot get updated witness"));
-        assert!(!hol.test_membership(Some(pp)));
+        assert!(!hol.test_membership_with(&pp));
     }
 
     //Finally non revoked client proofs membership to verifier
@@ -88,7 +88,7 @@ async fn main() {
     log_with_time!(
         "Creating and verifying membership proof for non-revoked client ...",
     );
-    assert!(ver.verify(holders[non_revoked_index].proof_membership(None)));
+    assert!(ver.verify(holders[non_revoked_index].proof_membership_cached()));
     log_with_time_ln!("Done.");
 
     log_with_time!("Finished.");
@@ -2,9 +2,9 @@ use accumulator::Accumulator;
 use axum::{
     body::Bytes, extract::State, http::StatusCode, response::IntoResponse, routing::{get, delete, post, put}, Router
 };
-use entities::{issuer::Issuer, UpdatePolynomials};
+use entities::{issuer::SyncIssuer, UpdatePolynomials};
 use serde::{Deserialize, Serialize};
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 use crate::{base_registry::{BASE_REGISTRY_BACK, PARAMS_URL, POLYS_URL, WIT_URL}, log_with_time, log_with_time_ln};
 
 pub const WEBSERVER: &str = "127.0.0.1:1234";
@@ -21,24 +21,22 @@ pub struct Update(pub Accumulator, pub UpdatePolynomials);
 
 #[derive(Clone)]
 struct AppState {
-    iss: Arc<Mutex<Issuer>>,
+    iss: Arc<SyncIssuer>,
 }
 
 
 async fn issue(State(state): State<AppState>, pseudo: String) -> axum::response::Response{
-    let mut iss = state.iss.lock().unwrap();
     //check validity of pseudo against some Issuer's policy
 
-    let rh = iss.add(pseudo);
+    let rh = state.iss.add(pseudo);
     match rh {    
-        Some(rh) => (StatusCode::CREATED, bincode::serialize(&rh).expect("Serialization error")).into_response(),
-        None => (StatusCode::BAD_REQUEST, "Cannot issue already accumulated element").into_response()
+        Ok(rh) => (StatusCode::CREATED, bincode::serialize(&rh).expect("Serialization error")).into_response(),
+        Err(_) => (StatusCode::BAD_REQUEST, "Cannot issue already accumulated element").into_response()
     }  
 }   
 
 async fn revoke(State(state): State<AppState>, pseudo: String) -> axum::response::Response{
-    let mut iss = state.iss.lock().unwrap();
-    match iss.revoke(&pseudo){
+    match state.iss.revoke(&pseudo){
         Some(_) => (StatusCode::OK, "ok").into_response(), 
         None => (StatusCode::NOT_FOUND, "Cannot revoke non accumulated element").into_response()
     }
@@ -47,11 +45,10 @@ async fn revoke(State(state): State<AppState>, pseudo: String) -> axum::response
 async fn revoke_batch(State(state): State<AppState>, payload: Bytes)-> axum::response::Response{
     let pseudos = bincode::deserialize::<Vec<String>>(&payload);
     match pseudos{
-        Ok(pseudos) =>{
-            let mut iss = state.iss.lock().unwrap();
-            iss.revoke_elements(pseudos.as_slice());
-            StatusCode::OK.into_response()
-        }
+        Ok(pseudos) => match state.iss.revoke_elements(pseudos.as_slice()) {
+            Ok(()) => StatusCode::OK.into_response(),
+            Err(err) => (StatusCode::PAYLOAD_TOO_LARGE, err.to_string()).into_response(),
+        },
         Err(_) => {
             (StatusCode::BAD_REQUEST, "Cannot parse request").into_response()
         }
@@ -61,18 +58,11 @@ async fn revoke_batch(State(state): State<AppState>, payload: Bytes)-> axum::res
 
 async fn update(State(state): State<AppState>)->impl IntoResponse{
 
-    let upd_poly;
-    let new_acc;
-    
     log_with_time!(
         "Server starts computation of update poly...",
     );
 
-    {
-        let mut iss = state.iss.lock().expect("Posoned mutex");
-        upd_poly = iss.update();
-        new_acc = iss.get_accumulator();
-    }
+    let (upd_poly, new_acc) = state.iss.write(|iss| (iss.update(), iss.get_accumulator()));
     
     match upd_poly{
         Some(upd_poly) => {  
@@ -94,26 +84,23 @@ async fn update(State(state): State<AppState>)->impl IntoResponse{
 }
 
 async fn update_periodic(State(state): State<AppState>)->impl IntoResponse{
-    let mut iss = state.iss.lock().expect("Posoned mutex");
-    log_with_time!(
-        "Server starts computing updated witnesses for {} elemets.",
-        iss.get_witnesses().len()
-    );
-    iss.update_periodic();
-    log_with_time_ln!(
-        "Done.",
-    );
+    state.iss.write(|iss| {
+        log_with_time!(
+            "Server starts computing updated witnesses for {} elemets.",
+            iss.get_witnesses().len()
+        );
+        iss.update_periodic();
+        log_with_time_ln!(
+            "Done.",
+        );
+    });
 
     (StatusCode::OK, "ok".to_string())
 }
 
 
 async fn send_witnesses(State(state): State<AppState>)->impl IntoResponse{
-    let updates;
-    {
-        let iss = state.iss.lock().expect("Poisoned mutex");
-        updates = iss.get_witnesses();
-    }
+    let updates = state.iss.get_witnesses();
     let url = format!("http://{}{}", BASE_REGISTRY_BACK, WIT_URL);
     let body = bincode::serialize(&updates).unwrap();
     let _resp = reqwest::Client::new().put(url).body(body).send().await;
@@ -121,8 +108,7 @@ async fn send_witnesses(State(state): State<AppState>)->impl IntoResponse{
 }
 
 async fn send_params(State(state): State<AppState>)->impl IntoResponse{
-    let iss = state.iss.lock().unwrap().to_owned();
-    let pp = iss.get_proof_params();
+    let pp = state.iss.get_proof_params();
     let url = format!("http://{BASE_REGISTRY_BACK}{PARAMS_URL}");
     match bincode::serialize(&pp){
         Ok(payload) => {
@@ -141,8 +127,8 @@ async fn send_params(State(state): State<AppState>)->impl IntoResponse{
 
 #[tokio::main]
 pub async fn run() {
-    let iss = Issuer::new(Some(b"test"));
-    let shared_state = AppState{iss: Arc::new(Mutex::new(iss))};
+    let iss = SyncIssuer::new(Some(b"test"));
+    let shared_state = AppState{iss: Arc::new(iss)};
 
     // build our application with a single route
     let app = Router::new()